@@ -0,0 +1,51 @@
+//! Compares the memory used by, and the time taken to sum, amounts stored as
+//! [`Amount`]s and as [`CompactAmount`]s. Run with
+//! `cargo bench --features compact-amount`.
+
+use oxydized_money::{Amount, AmountResult, CompactAmount, Currency::EUR, Decimal};
+use std::{
+    hint::black_box,
+    mem::size_of,
+    time::{Duration, Instant},
+};
+
+const COUNT: i64 = 1_000_000;
+const ROUNDS: u32 = 10;
+
+fn measure<T>(name: &str, mut f: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    let elapsed = start.elapsed() / ROUNDS;
+    println!("{name:<14} {elapsed:?}");
+    elapsed
+}
+
+fn main() {
+    let amounts: Vec<Amount> = (0..COUNT)
+        .map(|i| Amount(Decimal::new(i, 2), EUR))
+        .collect();
+    let compact_amounts: Vec<CompactAmount> = amounts.iter().map(|&a| a.into()).collect();
+
+    println!(
+        "memory         {} MB vs {} MB",
+        COUNT as usize * size_of::<Amount>() / 1_000_000,
+        COUNT as usize * size_of::<CompactAmount>() / 1_000_000
+    );
+
+    let amount = measure("Amount", || {
+        black_box(&amounts).iter().sum::<AmountResult>()
+    });
+    let compact = measure("CompactAmount", || {
+        black_box(&compact_amounts)
+            .iter()
+            .map(|&amount| Amount::from(amount))
+            .sum::<AmountResult>()
+    });
+
+    println!(
+        "ratio          {:.1}x",
+        amount.as_secs_f64() / compact.as_secs_f64()
+    );
+}
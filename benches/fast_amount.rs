@@ -0,0 +1,42 @@
+//! Compares the time taken to sum amounts stored as [`Amount`]s and as
+//! [`FastAmount`]s. Run with `cargo bench --features fast-amount`.
+
+use oxydized_money::{Amount, AmountResult, Currency::EUR, Decimal, FastAmount};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const COUNT: i64 = 1_000_000;
+const ROUNDS: u32 = 10;
+
+fn measure<T>(name: &str, mut f: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    let elapsed = start.elapsed() / ROUNDS;
+    println!("{name:<12} {elapsed:?}");
+    elapsed
+}
+
+fn main() {
+    let amounts: Vec<Amount> = (0..COUNT)
+        .map(|i| Amount(Decimal::new(i, 2), EUR))
+        .collect();
+    let fast_amounts: Vec<FastAmount> = (0..COUNT).map(|i| FastAmount::new(i, EUR)).collect();
+
+    let decimal = measure("Amount", || {
+        black_box(&amounts).iter().sum::<AmountResult>()
+    });
+    let fast = measure("FastAmount", || {
+        black_box(&fast_amounts)
+            .iter()
+            .try_fold(FastAmount::new(0, EUR), |a, b| a + *b)
+    });
+
+    println!(
+        "speedup      {:.1}x",
+        decimal.as_secs_f64() / fast.as_secs_f64()
+    );
+}
@@ -0,0 +1,9 @@
+//! Depends on `oxydized-money` alone, without any dev-dependency, to check
+//! that the marker types re-exported by the core crate are available.
+
+use oxydized_money::{markers::EUR, Amount, Decimal, TypedAmount};
+
+fn main() {
+    let price = TypedAmount::<EUR>::new(Decimal::new(1050, 2));
+    println!("{}", Amount::from(price));
+}
@@ -6,6 +6,61 @@ use std::{
     path::Path,
 };
 
+/// Currencies for which a macro is generated with the `currencies-major` feature.
+const MAJOR_CURRENCIES: &[Currency] = &[
+    Currency::AUD,
+    Currency::BRL,
+    Currency::CAD,
+    Currency::CHF,
+    Currency::CNY,
+    Currency::CZK,
+    Currency::DKK,
+    Currency::EUR,
+    Currency::GBP,
+    Currency::HKD,
+    Currency::HUF,
+    Currency::INR,
+    Currency::JPY,
+    Currency::KRW,
+    Currency::MXN,
+    Currency::NOK,
+    Currency::NZD,
+    Currency::PLN,
+    Currency::SEK,
+    Currency::SGD,
+    Currency::TRY,
+    Currency::USD,
+    Currency::ZAR,
+];
+
+/// Environment variable holding a comma separated list of currency codes
+/// (e.g. `EUR,USD`) for which a macro should be generated. Takes precedence
+/// over the `currencies-*` features.
+const CURRENCIES_ENV: &str = "OXYDIZED_MONEY_CURRENCIES";
+
+fn selected_currencies() -> Vec<Currency> {
+    if let Ok(codes) = env::var(CURRENCIES_ENV) {
+        let wanted: Vec<Currency> = codes
+            .split(',')
+            .map(str::trim)
+            .filter(|code| !code.is_empty())
+            .map(|code| {
+                Currency::from_code(&code.to_uppercase())
+                    .unwrap_or_else(|| panic!("{CURRENCIES_ENV}: unknown currency code '{code}'"))
+            })
+            .collect();
+        Currency::iter()
+            .filter(|currency| wanted.contains(currency))
+            .collect()
+    } else if env::var_os("CARGO_FEATURE_CURRENCIES_ALL").is_some() {
+        Currency::iter().collect()
+    } else if env::var_os("CARGO_FEATURE_CURRENCIES_MAJOR").is_some() {
+        MAJOR_CURRENCIES.to_vec()
+    } else {
+        Vec::new()
+    }
+}
+
 fn generate_currency_macro(file: &mut impl Write, currency: Currency) {
     let mut code_lower = currency.code().to_lowercase();
     let code_upper = code_lower.to_uppercase();
@@ -18,7 +73,8 @@ fn generate_currency_macro(file: &mut impl Write, currency: Currency) {
     writeln!(
         file,
         r#"
-        /// Convenience macro to construct amounts of money in "{name}".
+        /// Convenience macro to construct amounts of money in "{name}", which can
+        /// be used to initialize `const` and `static` items.
         #[macro_export]
         macro_rules! {code_lower} {{
             ($amount:expr) => {{
@@ -30,13 +86,42 @@ fn generate_currency_macro(file: &mut impl Write, currency: Currency) {
     .unwrap();
 }
 
+fn generate_currency_marker(file: &mut impl Write, currency: Currency) {
+    let code = currency.code();
+    let name = currency.name();
+    let exponent = currency.exponent();
+    let symbol = currency.symbol().to_string();
+
+    writeln!(
+        file,
+        r#"
+        /// Marker type standing for "{name}" at the type level.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct {code};
+
+        impl crate::CurrencyMarker for {code} {{
+            const CURRENCY: iso_currency::Currency = iso_currency::Currency::{code};
+            const CODE: &'static str = {code:?};
+            const EXPONENT: Option<u16> = {exponent:?};
+            const SYMBOL: &'static str = {symbol:?};
+        }}
+    "#
+    )
+    .unwrap();
+}
+
 fn main() {
-    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("currency_macros.rs");
-    let mut file = BufWriter::new(File::create(out_path).unwrap());
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let macros_path = Path::new(&out_dir).join("currency_macros.rs");
+    let mut macros_file = BufWriter::new(File::create(macros_path).unwrap());
+    let markers_path = Path::new(&out_dir).join("currency_markers.rs");
+    let mut markers_file = BufWriter::new(File::create(markers_path).unwrap());
 
-    for currency in Currency::iter() {
-        generate_currency_macro(&mut file, currency);
+    for currency in selected_currencies() {
+        generate_currency_macro(&mut macros_file, currency);
+        generate_currency_marker(&mut markers_file, currency);
     }
 
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={CURRENCIES_ENV}");
 }
@@ -1,4 +1,177 @@
+#![no_std]
+
+use core::{fmt::Debug, hash::Hash};
+use iso_currency::Currency;
+
 include!(concat!(env!("OUT_DIR"), "/currency_macros.rs"));
 
+pub use oxydized_money_proc_macros::{money, MoneyNewtype, SameCurrency};
 pub use rust_decimal_macros::dec;
 
+/// Constructs an amount of money from a decimal literal and a currency,
+/// given either as the code of an ISO 4217 currency or as any expression
+/// evaluating to a `Currency`, e.g. a variable or a constant. Unlike the
+/// per-currency macros (e.g. `eur!`), it is always available whatever the
+/// currencies selected with the `currencies-*` features.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Currency, Decimal};
+/// use oxydized_money_macros::amount;
+///
+/// assert_eq!(amount!(10.50, EUR), Amount(Decimal::new(1050, 2), Currency::EUR));
+///
+/// let currency = Currency::USD;
+/// assert_eq!(amount!(10.50, currency), Amount(Decimal::new(1050, 2), currency));
+/// ```
+#[macro_export]
+macro_rules! amount {
+    ($amount:expr, $currency:expr) => {{
+        #[allow(unused_imports)]
+        use oxydized_money::Currency::*;
+        oxydized_money::Amount(rust_decimal_macros::dec!($amount), $currency)
+    }};
+}
+
+/// Constructs an `AmountResult` in the default `Currency` from anything it
+/// can be converted from, e.g. an amount or a `CurrencyError`, or from a
+/// decimal literal and a currency like [`amount!`]. The currencies and the
+/// variants of `CurrencyError` can be named without importing them.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, AmountResult, Currency, CurrencyError, Decimal};
+/// use oxydized_money_macros::{amount_result, eur};
+///
+/// assert_eq!(amount_result!(10.50, EUR), AmountResult::from(eur!(10.50)));
+/// assert_eq!(amount_result!(eur!(10.50)), AmountResult::from(eur!(10.50)));
+/// assert_eq!(amount_result!(Unknown), AmountResult::unknown());
+/// assert_eq!(
+///     amount_result!(MissingRate(EUR, USD)),
+///     AmountResult::missing_rate(Currency::EUR, Currency::USD)
+/// );
+/// ```
+#[macro_export]
+macro_rules! amount_result {
+    ($amount:expr, $currency:expr) => {
+        oxydized_money::AmountResult::new($crate::amount!($amount, $currency))
+    };
+    ($value:expr) => {{
+        #[allow(unused_imports)]
+        use oxydized_money::{Currency::*, CurrencyError::*};
+        oxydized_money::AmountResult::new($value)
+    }};
+}
+
+/// Constructs an `AmountResult` holding a `CurrencyError::Mismatch` between
+/// two currencies, given either as ISO 4217 codes or as any expression
+/// evaluating to a currency.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{AmountResult, Currency, CurrencyError, Decimal};
+/// use oxydized_money_macros::{eur, mismatch, usd};
+///
+/// assert_eq!(eur!(1) + usd!(1), mismatch!(EUR, USD));
+/// assert_eq!(
+///     mismatch!(EUR, USD).unwrap_err(),
+///     CurrencyError::Mismatch(Currency::EUR, Currency::USD)
+/// );
+/// ```
+#[macro_export]
+macro_rules! mismatch {
+    ($left:expr, $right:expr) => {{
+        #[allow(unused_imports)]
+        use oxydized_money::Currency::*;
+        oxydized_money::AmountResult::mismatch($left, $right)
+    }};
+}
+
+/// `CurrencyMarker` is implemented by zero-sized types standing for a
+/// specific [`Currency`] at the type level, such as the ones found in
+/// [`markers`]. It allows writing functions that are generic over the
+/// currency while knowing its properties at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money_macros::{markers::{EUR, JPY}, CurrencyMarker};
+///
+/// fn describe<C: CurrencyMarker>() -> String {
+///     format!("{} ({} decimals)", C::CODE, C::EXPONENT.unwrap_or_default())
+/// }
+///
+/// assert_eq!(describe::<EUR>(), "EUR (2 decimals)");
+/// assert_eq!(describe::<JPY>(), "JPY (0 decimals)");
+/// ```
+pub trait CurrencyMarker:
+    Copy + Default + Debug + PartialEq + Eq + Hash + PartialOrd + Ord + 'static
+{
+    /// The currency represented by the marker type.
+    const CURRENCY: Currency;
+    /// The ISO 4217 code of the currency, e.g. `"EUR"`.
+    const CODE: &'static str;
+    /// The number of decimal places used by the currency, if any.
+    const EXPONENT: Option<u16>;
+    /// The symbol of the currency, e.g. `"€"`.
+    const SYMBOL: &'static str;
+}
+
+/// Marker types for each of the selected ISO 4217 currencies (see
+/// [`CurrencyMarker`]).
+#[cfg_attr(
+    all(feature = "currencies-major", not(feature = "currencies-all")),
+    doc = r#"
+With the `currencies-major` feature only, the other currencies have neither
+a marker type nor a macro:
+
+```compile_fail
+use oxydized_money_macros::markers::BHD;
+```
+"#
+)]
+pub mod markers {
+    include!(concat!(env!("OUT_DIR"), "/currency_markers.rs"));
+}
+
+#[cfg(test)]
+mod test {
+    use oxydized_money::{AmountResult, Currency::*, CurrencyError::*, Decimal};
+
+    #[test]
+    fn test_amount_result() {
+        assert_eq!(amount_result!(10.50, EUR), AmountResult::from(eur!(10.50)));
+        assert_eq!(amount_result!(-1, USD), AmountResult::from(usd!(-1)));
+        assert_eq!(amount_result!(eur!(1)), AmountResult::from(eur!(1)));
+        assert_eq!(amount_result!(Unknown), AmountResult::unknown());
+        assert_eq!(
+            amount_result!(MissingRate(EUR, USD)),
+            AmountResult::missing_rate(EUR, USD)
+        );
+    }
+
+    #[test]
+    fn test_mismatch() {
+        assert_eq!(mismatch!(EUR, USD), AmountResult::mismatch(EUR, USD));
+        let currency = USD;
+        assert_eq!(mismatch!(EUR, currency).unwrap_err(), Mismatch(EUR, USD));
+        assert_eq!(eur!(1) + usd!(1), mismatch!(EUR, USD));
+    }
+}
+
+#[cfg(all(test, feature = "currencies-major", not(feature = "currencies-all")))]
+mod test_major {
+    use super::{markers, CurrencyMarker};
+    use oxydized_money::{Amount, Currency, Decimal};
+
+    #[test]
+    fn test_major_currencies() {
+        assert_eq!(eur!(10.50), Amount(Decimal::new(1050, 2), Currency::EUR));
+        assert_eq!(usd!(1), Amount(Decimal::ONE, Currency::USD));
+        assert_eq!(markers::JPY::CURRENCY, Currency::JPY);
+        assert_eq!(amount!(1, BHD), Amount(Decimal::ONE, Currency::BHD));
+    }
+}
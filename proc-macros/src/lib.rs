@@ -0,0 +1,364 @@
+//! Procedural macros of `oxydized-money`, re-exported by
+//! `oxydized-money-macros`, which is the crate to depend on.
+
+use iso_currency::Currency;
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use rust_decimal::Decimal;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericArgument, Ident, Index,
+    LitStr, PathArguments, Type,
+};
+
+/// Constructs an amount of money from a literal in its canonical string
+/// form, i.e. its value and its currency code separated by a space (e.g.
+/// `"10.50 EUR"`). The literal is parsed at compile time, so that an invalid
+/// value or an unknown currency code is reported as a compilation error,
+/// and the macro can be used to initialize constants.
+///
+/// # Examples
+///
+/// ```ignore
+/// use oxydized_money::{Amount, Currency, Decimal};
+/// use oxydized_money_macros::money;
+///
+/// const FEE: Amount = money!("2.50 EUR");
+///
+/// assert_eq!(FEE, Amount(Decimal::new(250, 2), Currency::EUR));
+/// assert_eq!(money!("-1200 JPY"), Amount(Decimal::new(-1200, 0), Currency::JPY));
+/// ```
+#[proc_macro]
+pub fn money(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+    match parse(&literal.value()) {
+        Ok((value, currency)) => {
+            let mantissa = value.mantissa().unsigned_abs();
+            let lo = mantissa as u32;
+            let mid = (mantissa >> 32) as u32;
+            let hi = (mantissa >> 64) as u32;
+            let negative = value.is_sign_negative();
+            let scale = value.scale();
+            let currency = Ident::new(currency.code(), Span::call_site());
+            quote! {
+                oxydized_money::Amount(
+                    oxydized_money::Decimal::from_parts(#lo, #mid, #hi, #negative, #scale),
+                    oxydized_money::Currency::#currency,
+                )
+            }
+            .into()
+        }
+        Err(message) => syn::Error::new(literal.span(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+/// Derives `oxydized_money::SameCurrency` for a structure, taking all its
+/// fields whose type is named `Amount` (e.g. `Amount` or `Amount<C>`) into
+/// account, in declaration order. The structure must have at least one such
+/// field.
+///
+/// # Examples
+///
+/// ```ignore
+/// use oxydized_money::{Amount, Currency::EUR, SameCurrency};
+/// use oxydized_money_macros::{eur, SameCurrency};
+///
+/// #[derive(SameCurrency)]
+/// struct Invoice {
+///     subtotal: Amount,
+///     total: Amount,
+/// }
+///
+/// let invoice = Invoice { subtotal: eur!(10), total: eur!(12.10) };
+/// assert_eq!(invoice.currency(), Ok(EUR));
+/// ```
+#[proc_macro_derive(SameCurrency)]
+pub fn derive_same_currency(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "SameCurrency can only be derived for structs",
+        )
+        .to_compile_error()
+        .into();
+    };
+    let mut currency = None;
+    let mut amounts = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let Some(field_currency) = amount_currency(&field.ty) else {
+            continue;
+        };
+        currency.get_or_insert(field_currency);
+        amounts.push(match &field.ident {
+            Some(name) => quote!(self.#name),
+            None => {
+                let index = Index::from(index);
+                quote!(self.#index)
+            }
+        });
+    }
+    let Some(currency) = currency else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "SameCurrency requires at least one field of type Amount",
+        )
+        .to_compile_error()
+        .into();
+    };
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        const _: () = {
+            extern crate alloc;
+
+            impl #impl_generics oxydized_money::SameCurrency<#currency> for #name #type_generics
+            #where_clause
+            {
+                fn amounts(&self) -> alloc::vec::Vec<oxydized_money::Amount<#currency>> {
+                    alloc::vec![#(#amounts),*]
+                }
+            }
+        };
+    }
+    .into()
+}
+
+/// Derives `oxydized_money::MoneyNewtype` for a tuple structure wrapping a
+/// single `Amount` (or `Amount<C>`), along with the operations of amounts,
+/// so that the newtype stays distinct from other amounts:
+///
+/// * `Display`, and `From` conversions from and to the wrapped amount,
+/// * `Neg`, and `Mul<Decimal>` returning the newtype,
+/// * `Add` and `Sub` between values of the newtype, and `Div<Decimal>`,
+///   returning an `oxydized_money::Result` of the newtype.
+///
+/// With the `#[money_newtype(serde)]` attribute, the newtype is serialized
+/// and deserialized as the wrapped amount, which requires a dependency on
+/// `serde` and the `with_serde` feature of `oxydized-money`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use oxydized_money::{Amount, Decimal};
+/// use oxydized_money_macros::{dec, eur, MoneyNewtype};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, MoneyNewtype)]
+/// struct Price(Amount);
+///
+/// let price = Price(eur!(10)) * dec!(3);
+/// assert_eq!(price - Price(eur!(5)), Ok(Price(eur!(25))));
+/// assert_eq!(price.to_string(), "€ 30.00");
+/// ```
+#[proc_macro_derive(MoneyNewtype, attributes(money_newtype))]
+pub fn derive_money_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let currency = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                amount_currency(&fields.unnamed[0].ty)
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+    let Some(currency) = currency else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "MoneyNewtype can only be derived for tuple structs wrapping a single Amount",
+        )
+        .to_compile_error()
+        .into();
+    };
+    let mut serde = false;
+    for attribute in &input.attrs {
+        if !attribute.path().is_ident("money_newtype") {
+            continue;
+        }
+        let parsed = attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("serde") {
+                serde = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported money_newtype attribute, expected `serde`"))
+            }
+        });
+        if let Err(error) = parsed {
+            return error.to_compile_error().into();
+        }
+    }
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let amount = quote!(oxydized_money::Amount<#currency>);
+    let result = quote!(oxydized_money::Result<Self, #currency>);
+    let mut expanded = quote! {
+        impl #impl_generics oxydized_money::MoneyNewtype<#currency> for #name #type_generics
+        #where_clause
+        {
+            fn amount(&self) -> #amount {
+                self.0
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#amount> for #name #type_generics #where_clause {
+            fn from(amount: #amount) -> Self {
+                Self(amount)
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #type_generics> for #amount #where_clause {
+            fn from(newtype: #name #type_generics) -> Self {
+                newtype.0
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl #impl_generics ::core::ops::Neg for #name #type_generics #where_clause {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl #impl_generics ::core::ops::Mul<oxydized_money::Decimal> for #name #type_generics
+        #where_clause
+        {
+            type Output = Self;
+
+            fn mul(self, rhs: oxydized_money::Decimal) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl #impl_generics ::core::ops::Add for #name #type_generics #where_clause {
+            type Output = #result;
+
+            fn add(self, rhs: Self) -> #result {
+                (self.0 + rhs.0).into_inner().map(Self)
+            }
+        }
+
+        impl #impl_generics ::core::ops::Sub for #name #type_generics #where_clause {
+            type Output = #result;
+
+            fn sub(self, rhs: Self) -> #result {
+                (self.0 - rhs.0).into_inner().map(Self)
+            }
+        }
+
+        impl #impl_generics ::core::ops::Div<oxydized_money::Decimal> for #name #type_generics
+        #where_clause
+        {
+            type Output = #result;
+
+            fn div(self, rhs: oxydized_money::Decimal) -> #result {
+                (self.0 / rhs).into_inner().map(Self)
+            }
+        }
+    };
+    if serde {
+        let mut generics = input.generics.clone();
+        generics.params.insert(0, parse_quote!('de));
+        let (de_impl_generics, _, _) = generics.split_for_impl();
+        expanded.extend(quote! {
+            impl #impl_generics ::serde::Serialize for #name #type_generics #where_clause {
+                fn serialize<S: ::serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error> {
+                    ::serde::Serialize::serialize(&self.0, serializer)
+                }
+            }
+
+            impl #de_impl_generics ::serde::Deserialize<'de> for #name #type_generics #where_clause {
+                fn deserialize<D: ::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::core::result::Result<Self, D::Error> {
+                    ::serde::Deserialize::deserialize(deserializer).map(Self)
+                }
+            }
+        });
+    }
+    expanded.into()
+}
+
+/// Returns the currency type of a field of type `Amount` or `Amount<C>`, or
+/// `None` if the field is not an amount.
+fn amount_currency(ty: &Type) -> Option<proc_macro2::TokenStream> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Amount" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            GenericArgument::Type(currency) => Some(quote!(#currency)),
+            _ => None,
+        },
+        _ => Some(quote!(oxydized_money::Currency)),
+    }
+}
+
+/// Parses a money literal, returning the reason why it is invalid if any.
+fn parse(literal: &str) -> Result<(Decimal, Currency), String> {
+    let Some((value, code)) = literal.trim().split_once(' ') else {
+        return Err(format!(
+            "invalid money literal '{}', expected a value and a currency code, e.g. \"10.50 EUR\"",
+            literal
+        ));
+    };
+    let value =
+        Decimal::from_str_exact(value).map_err(|_| format!("invalid decimal value '{}'", value))?;
+    let code = code.trim();
+    let currency = match code.parse::<u16>() {
+        Ok(numeric) => Currency::from_numeric(numeric),
+        Err(_) => Currency::from_code(code),
+    }
+    .ok_or_else(|| format!("unknown currency code '{}'", code))?;
+    Ok((value, currency))
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse;
+    use iso_currency::Currency::*;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("10.50 EUR"), Ok((Decimal::new(1050, 2), EUR)));
+        assert_eq!(parse(" -1200 JPY "), Ok((Decimal::new(-1200, 0), JPY)));
+        assert_eq!(parse("0.5 840"), Ok((Decimal::new(5, 1), USD)));
+        assert_eq!(parse("10.50 EUR").unwrap().0.scale(), 2);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("10.50"),
+            Err(
+                "invalid money literal '10.50', expected a value and a currency code, e.g. \"10.50 EUR\""
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            parse("10,50 EUR"),
+            Err("invalid decimal value '10,50'".to_owned())
+        );
+        assert_eq!(
+            parse("10.50 XYZ"),
+            Err("unknown currency code 'XYZ'".to_owned())
+        );
+        assert_eq!(parse("1 1"), Err("unknown currency code '1'".to_owned()));
+    }
+}
@@ -1,17 +1,160 @@
-use crate::{AmountResult, Currency, CurrencyError, Decimal, Result};
-use std::{
+use crate::{
+    AmountResult, Currency, CurrencyError, CurrencyLike, Decimal, Result, RoundingStrategy,
+    Tolerance,
+};
+use core::{
     cmp::Ordering,
     fmt::Display,
     ops::{Deref, DerefMut},
 };
 
 /// `Amount` represents an amount of money in a specific currency.
-/// The quantity part is stored as a 128-bit fixed precision [`Decimal`].
-/// The currency part is stored as a [`Currency`].
+/// The quantity part is always stored as a 128-bit fixed precision
+/// [`Decimal`], i.e. with at most 28 significant digits.
+/// The currency part is stored as a [`Currency`] by default, or as any
+/// other type implementing [`CurrencyLike`] (see [`CurrencyLike`] for
+/// details).
+///
+/// With the `with_serde` feature, amounts are serialized as a structure
+/// with a `value` and a `currency` field, e.g. `{"value":"10.50","currency":"EUR"}`.
+/// The value may also be given as a JSON number, e.g. `{"value":10.5,"currency":"EUR"}`,
+/// in which case it is read from its shortest decimal representation. Numbers with
+/// more significant digits than an `f64` can hold are only read exactly with the
+/// `serde-arbitrary-precision` feature, which enables `serde_json`'s
+/// `arbitrary_precision` feature.
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
-pub struct Amount(pub Decimal, pub Currency);
+#[cfg_attr(
+    feature = "with_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        from = "AmountRepr<C>",
+        into = "AmountRepr<C>",
+        bound(
+            serialize = "C: Clone + serde::Serialize",
+            deserialize = "C: serde::Deserialize<'de>"
+        )
+    )
+)]
+#[cfg_attr(
+    feature = "diesel-postgres",
+    derive(diesel::expression::AsExpression),
+    diesel(sql_type = crate::MoneyAmount)
+)]
+pub struct Amount<C = Currency>(pub Decimal, pub C);
+
+#[cfg(feature = "with_serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Amount")]
+struct AmountRepr<C> {
+    value: Decimal,
+    currency: C,
+}
+
+#[cfg(feature = "with_serde")]
+impl<C> From<AmountRepr<C>> for Amount<C> {
+    fn from(repr: AmountRepr<C>) -> Self {
+        Amount(repr.value, repr.currency)
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl<C> From<Amount<C>> for AmountRepr<C> {
+    fn from(Amount(value, currency): Amount<C>) -> Self {
+        AmountRepr { value, currency }
+    }
+}
+
+impl<C: CurrencyLike> Amount<C> {
+    /// Creates an amount of `value` in `currency`. Unlike the macros, it
+    /// accepts any [`Decimal`], including the constants such as
+    /// [`Decimal::ONE`], and can be used to initialize `const` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Currency::USD, Decimal};
+    /// use oxydized_money_macros::usd;
+    ///
+    /// const UNIT: Amount = Amount::new(Decimal::ONE, USD);
+    ///
+    /// assert_eq!(UNIT, usd!(1));
+    /// ```
+    pub const fn new(value: Decimal, currency: C) -> Self {
+        Amount(value, currency)
+    }
+
+    /// Returns the largest amount that can be represented in `currency`,
+    /// i.e. [`Decimal::MAX`], e.g. as the upper bound of a validation range
+    /// or as a limit meaning "no limit".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Currency::EUR, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// let limit = Amount::max_in(EUR);
+    /// assert_eq!(limit.value(), Decimal::MAX);
+    /// assert!(eur!(1_000_000_000) < limit);
+    /// assert!(eur!(-1_000_000_000) > Amount::min_in(EUR));
+    /// ```
+    pub const fn max_in(currency: C) -> Self {
+        Amount(Decimal::MAX, currency)
+    }
+
+    /// Returns the smallest amount that can be represented in `currency`,
+    /// i.e. [`Decimal::MIN`] (see [`max_in`](Amount::max_in)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Currency::USD, Decimal};
+    /// use oxydized_money_macros::usd;
+    ///
+    /// let floor = Amount::min_in(USD);
+    /// assert_eq!(floor.value(), Decimal::MIN);
+    /// assert_eq!(floor, -Amount::max_in(USD));
+    /// ```
+    pub const fn min_in(currency: C) -> Self {
+        Amount(Decimal::MIN, currency)
+    }
+
+    /// Creates an amount of `mantissa * 10^-scale` in `currency`, e.g.
+    /// `Amount::const_new(1050, 2, EUR)` for `10.50 EUR`. Being a `const fn`,
+    /// it can be used to initialize `const` and `static` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is greater than 28, which fails the compilation
+    /// when evaluated in a constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Currency::EUR, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// const FEE: Amount = Amount::const_new(250, 2, EUR);
+    /// static LIMIT: Amount = eur!(1000.00);
+    ///
+    /// assert_eq!(FEE, eur!(2.50));
+    /// assert_eq!(LIMIT.value().scale(), 2);
+    /// ```
+    pub const fn const_new(mantissa: i64, scale: u32, currency: C) -> Self {
+        if scale > 28 {
+            panic!("scale exceeds the maximum precision allowed");
+        }
+        let magnitude = mantissa.unsigned_abs();
+        let value = Decimal::from_parts(
+            magnitude as u32,
+            (magnitude >> 32) as u32,
+            0,
+            mantissa < 0,
+            scale,
+        );
+        Amount(value, currency)
+    }
 
-impl Amount {
     /// Returns the quantity of money.
     ///
     /// # Examples
@@ -22,7 +165,7 @@ impl Amount {
     ///
     /// assert_eq!(eur!(10.5).value(), dec!(10.5))
     /// ```
-    pub fn value(&self) -> Decimal {
+    pub const fn value(&self) -> Decimal {
         self.0
     }
 
@@ -36,7 +179,7 @@ impl Amount {
     ///
     /// assert_eq!(usd!(10.5).currency(), USD)
     /// ```
-    pub fn currency(&self) -> Currency {
+    pub const fn currency(&self) -> C {
         self.1
     }
 
@@ -52,8 +195,26 @@ impl Amount {
     /// assert_eq!(eur!(10.6).abs(), eur!(10.6));
     /// ```
     ///
-    pub fn abs(&self) -> Self {
-        Amount(self.value().abs(), self.currency())
+    pub const fn abs(&self) -> Self {
+        Amount(with_sign(self.0, false), self.1)
+    }
+
+    /// Returns `self` with the opposite sign. This is equivalent to the
+    /// [`Neg`](core::ops::Neg) operator, but can be evaluated in a constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// const REFUND: Amount = eur!(10.50).negated();
+    ///
+    /// assert_eq!(REFUND, eur!(-10.50));
+    /// assert_eq!(REFUND.negated(), -REFUND);
+    /// ```
+    pub const fn negated(&self) -> Self {
+        Amount(with_sign(self.0, !self.0.is_sign_negative()), self.1)
     }
 
     /// Returns `self` converted in another currency using the provided
@@ -73,13 +234,222 @@ impl Amount {
     /// let exchange_rate = dec!(0.9);
     /// assert_eq!(eur!(10.5).converted_to(USD, exchange_rate), usd!(10.5) * exchange_rate);
     ///
-    pub fn converted_to(&self, target_currency: Currency, exchange_rate: Decimal) -> Self {
+    pub fn converted_to(&self, target_currency: C, exchange_rate: Decimal) -> Self {
         Amount(self.value() * exchange_rate, target_currency)
     }
+
+    /// Returns `self` rounded to the number of decimal places of its
+    /// currency (e.g. 2 for `EUR`, 0 for `JPY`) using the provided rounding
+    /// strategy. Amounts in currencies without a defined number of decimal
+    /// places (e.g. `XAU`) are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Decimal, RoundingStrategy::*};
+    /// use oxydized_money_macros::{eur, jpy};
+    ///
+    /// assert_eq!(eur!(10.125).rounded(MidpointNearestEven), eur!(10.12));
+    /// assert_eq!(eur!(10.125).rounded(MidpointAwayFromZero), eur!(10.13));
+    /// assert_eq!(jpy!(10.5).rounded(ToZero), jpy!(10));
+    /// ```
+    pub fn rounded(&self, strategy: RoundingStrategy) -> Self {
+        match self.currency().exponent() {
+            Some(exponent) => Amount(
+                self.value()
+                    .round_dp_with_strategy(exponent.into(), strategy),
+                self.currency(),
+            ),
+            None => *self,
+        }
+    }
+
+    /// Returns `self` converted in another currency using the provided
+    /// exchange rate and rounded to the number of decimal places of the
+    /// target currency (see [`rounded`](Amount::rounded)), along with the
+    /// rounding residual, i.e. the exact converted amount minus the rounded
+    /// one. Keeping track of the residual allows books to balance to the
+    /// cent.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_currency` - the the resulting currency.
+    /// * `exchange_rate` - the exchange rate to be used during the conversion
+    /// * `strategy` - the rounding strategy to be used
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Currency::USD, Decimal, RoundingStrategy};
+    /// use oxydized_money_macros::{usd, eur, dec};
+    ///
+    /// let (converted, residual) = eur!(10.01).converted_to_rounded(
+    ///     USD,
+    ///     dec!(1.0876),
+    ///     RoundingStrategy::MidpointNearestEven,
+    /// );
+    /// assert_eq!(converted, usd!(10.89));
+    /// assert_eq!(residual, usd!(-0.003124));
+    /// assert_eq!(converted + residual, eur!(10.01).converted_to(USD, dec!(1.0876)));
+    /// ```
+    pub fn converted_to_rounded(
+        &self,
+        target_currency: C,
+        exchange_rate: Decimal,
+        strategy: RoundingStrategy,
+    ) -> (Self, Self) {
+        let exact = self.converted_to(target_currency, exchange_rate);
+        let rounded = exact.rounded(strategy);
+        let residual = Amount(exact.value() - rounded.value(), target_currency);
+        (rounded, residual)
+    }
+
+    /// Returns `true` if `self` and `other` are in the same currency and
+    /// their values are equal within the provided [`Tolerance`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Tolerance, Decimal, Currency::USD};
+    /// use oxydized_money_macros::{usd, eur, dec};
+    ///
+    /// let converted = eur!(10.01).converted_to(USD, dec!(1.0876));
+    /// assert!(converted.approx_eq(&usd!(10.89), Tolerance::Absolute(dec!(0.01))));
+    /// assert!(!converted.approx_eq(&eur!(10.89), Tolerance::Absolute(dec!(0.01))));
+    /// ```
+    pub fn approx_eq(&self, other: &Amount<C>, tolerance: Tolerance) -> bool {
+        self.currency() == other.currency() && tolerance.accepts(self.value(), other.value())
+    }
+
+    /// Returns `self` if it is in the `expected` currency, or a
+    /// [`CurrencyError::Mismatch`] between `expected` and the currency of
+    /// `self` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{CurrencyError, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// assert_eq!(eur!(10).expect_currency(EUR), Ok(eur!(10)));
+    /// assert_eq!(usd!(10).expect_currency(EUR), Err(CurrencyError::Mismatch(EUR, USD)));
+    /// ```
+    pub fn expect_currency(&self, expected: C) -> Result<Self, C> {
+        if self.currency() == expected {
+            Ok(*self)
+        } else {
+            Err(CurrencyError::Mismatch(expected, self.currency()))
+        }
+    }
+
+    /// Compares `self` and `other` by currency code, then by value, so that
+    /// amounts in any currencies can be sorted or used as keys of a
+    /// [`BTreeMap`](std::collections::BTreeMap) (see [`OrderedAmount`](crate::OrderedAmount)).
+    ///
+    /// This is not a monetary comparison: `1 USD` is ordered after
+    /// `100 EUR` whatever the exchange rate, because `"EUR"` comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(eur!(1).total_cmp(&eur!(2)), Ordering::Less);
+    /// assert_eq!(usd!(1).total_cmp(&eur!(100)), Ordering::Greater);
+    ///
+    /// let mut amounts = vec![usd!(1), eur!(5), eur!(-2)];
+    /// amounts.sort_by(Amount::total_cmp);
+    /// assert_eq!(amounts, [eur!(-2), eur!(5), usd!(1)]);
+    /// ```
+    pub fn total_cmp(&self, other: &Amount<C>) -> Ordering {
+        self.currency()
+            .code()
+            .cmp(other.currency().code())
+            .then_with(|| self.value().cmp(&other.value()))
+    }
+}
+
+/// Returns `value` with the given sign, in a constant context, where the
+/// methods of `Decimal` are not available.
+const fn with_sign(value: Decimal, negative: bool) -> Decimal {
+    let parts = value.unpack();
+    Decimal::from_parts(parts.lo, parts.mid, parts.hi, negative, parts.scale)
+}
+
+#[cfg(any(
+    feature = "fast-amount",
+    feature = "google-money",
+    feature = "rand",
+    feature = "rusqlite",
+    feature = "with_serde",
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+impl Amount {
+    /// Returns the number of decimal places of the minor units of
+    /// `currency`, e.g. `2` for the cents of `EUR`.
+    #[cfg(any(
+        feature = "fast-amount",
+        feature = "rand",
+        feature = "rusqlite",
+        feature = "with_serde",
+        feature = "sqlx-postgres",
+        feature = "sqlx-mysql",
+        feature = "sqlx-sqlite"
+    ))]
+    pub(crate) fn minor_scale(currency: Currency) -> u32 {
+        currency.exponent().unwrap_or_default().into()
+    }
+
+    /// Returns the value of the amount as an integer number of units with
+    /// `scale` decimal places, e.g. of cents for `2`. Fails with
+    /// [`CurrencyError::InvalidPrecision`] if the value has more decimal
+    /// places, or with [`CurrencyError::Overflow`] if it does not fit in a
+    /// [`Decimal`] with `scale` decimal places.
+    pub(crate) fn scaled_units(&self, scale: u32) -> Result<i128> {
+        let mut value = self.value();
+        value.rescale(scale);
+        if value != self.value() {
+            Err(CurrencyError::InvalidPrecision(self.currency()))
+        } else if value.scale() != scale {
+            Err(CurrencyError::Overflow)
+        } else {
+            Ok(value.mantissa())
+        }
+    }
 }
 
-impl Display for Amount {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(any(
+    feature = "with_serde",
+    feature = "async-graphql",
+    feature = "ffi",
+    feature = "juniper",
+    feature = "rusqlite",
+    feature = "sea-query",
+    feature = "sqlx-sqlite"
+))]
+impl Amount {
+    /// Parses an amount from its canonical string form, i.e. its value and
+    /// its currency code separated by a space, e.g. `"10.50 EUR"`. Numeric
+    /// currency codes (e.g. `"10.50 978"`) are accepted as well.
+    pub(crate) fn parse_canonical(string: &str) -> Option<Self> {
+        let (value, code) = string.trim().split_once(' ')?;
+        let value = value.parse::<Decimal>().ok()?;
+        let currency = crate::currency::parse_code(code.trim())?;
+        Some(Amount(value, currency))
+    }
+
+    /// Formats the amount in its canonical string form, e.g. `"10.50 EUR"`.
+    pub(crate) fn canonical(&self) -> String {
+        format!("{} {}", self.value(), self.currency().code())
+    }
+}
+
+impl<C: CurrencyLike> Display for Amount<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let precision = f.precision().unwrap_or(2);
         write!(
             f,
@@ -91,10 +461,10 @@ impl Display for Amount {
     }
 }
 
-impl TryFrom<AmountResult> for Amount {
-    type Error = CurrencyError;
+impl<C: CurrencyLike> TryFrom<AmountResult<C>> for Amount<C> {
+    type Error = CurrencyError<C>;
 
-    fn try_from(res: AmountResult) -> Result<Self> {
+    fn try_from(res: AmountResult<C>) -> Result<Self, C> {
         if res.is_ok() {
             Ok(res.unwrap())
         } else {
@@ -103,8 +473,8 @@ impl TryFrom<AmountResult> for Amount {
     }
 }
 
-impl PartialOrd<Amount> for Amount {
-    fn partial_cmp(&self, other: &Amount) -> Option<Ordering> {
+impl<C: CurrencyLike> PartialOrd<Amount<C>> for Amount<C> {
+    fn partial_cmp(&self, other: &Amount<C>) -> Option<Ordering> {
         if self.currency() == other.currency() {
             self.value().partial_cmp(&other.value())
         } else {
@@ -113,7 +483,7 @@ impl PartialOrd<Amount> for Amount {
     }
 }
 
-impl Deref for Amount {
+impl<C> Deref for Amount<C> {
     type Target = Decimal;
 
     fn deref(&self) -> &Self::Target {
@@ -121,7 +491,7 @@ impl Deref for Amount {
     }
 }
 
-impl DerefMut for Amount {
+impl<C> DerefMut for Amount<C> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
@@ -133,9 +503,117 @@ mod test {
     use assert_matches::assert_matches;
     use oxydized_money::Decimal;
     use oxydized_money::{Currency::*, CurrencyError::*};
-    use oxydized_money_macros::{dec, eur, usd};
+    use oxydized_money_macros::{bhd, dec, eur, jpy, usd, xau};
     use std::cmp::Ordering::*;
 
+    #[test]
+    fn test_amount_macro() {
+        use oxydized_money::{Amount, Currency};
+        use oxydized_money_macros::amount;
+
+        assert_eq!(amount!(10.50, EUR), eur!(10.50));
+        assert_eq!(amount!(-3, JPY), jpy!(-3));
+        let currency = USD;
+        assert_eq!(amount!(1.5, currency), usd!(1.5));
+        const FEE_CURRENCY: Currency = BHD;
+        assert_eq!(amount!(0.125, FEE_CURRENCY), bhd!(0.125));
+        let currencies = [XAU];
+        assert_eq!(amount!(1, currencies[0]), xau!(1));
+        assert_eq!(
+            amount!(2, Currency::from_code("EUR").unwrap()),
+            Amount(dec!(2), EUR)
+        );
+    }
+
+    #[test]
+    fn test_const_new() {
+        use oxydized_money::{Amount, Currency};
+        use oxydized_money_macros::amount;
+
+        const FEE: Amount = Amount::const_new(250, 2, EUR);
+        const LIMITS: [Amount; 3] = [eur!(1000.00), jpy!(100000), amount!(0.125, BHD)];
+        static CURRENCY: Currency = USD;
+        static MINIMUM: Amount = amount!(0.01, CURRENCY);
+        assert_eq!(FEE, eur!(2.50));
+        assert_eq!(FEE.value().scale(), 2);
+        assert_eq!(LIMITS, [eur!(1000), jpy!(100000), bhd!(0.125)]);
+        assert_eq!(MINIMUM, usd!(0.01));
+        assert_eq!(Amount::const_new(-1, 28, EUR).value().scale(), 28);
+        assert_eq!(
+            Amount::const_new(i64::MIN, 0, EUR),
+            Amount(i64::MIN.into(), EUR)
+        );
+        assert_eq!(
+            Amount::const_new(i64::MAX, 3, EUR),
+            Amount(Decimal::new(i64::MAX, 3), EUR)
+        );
+        assert_eq!(Amount::const_new(0, 2, EUR).value().scale(), 2);
+    }
+
+    #[test]
+    fn test_bounds() {
+        use oxydized_money::Amount;
+
+        const CEILING: Amount = Amount::max_in(JPY);
+        assert_eq!(CEILING, Amount(Decimal::MAX, JPY));
+        assert_eq!(Amount::min_in(BHD), Amount(Decimal::MIN, BHD));
+        assert_eq!(Amount::min_in(EUR).negated(), Amount::max_in(EUR));
+        assert_eq!(CEILING + jpy!(1), Overflow);
+        assert_eq!(Amount::min_in(USD) - usd!(1), Overflow);
+        assert!(Amount::min_in(EUR) < eur!(0) && eur!(0) < Amount::max_in(EUR));
+        assert_eq!(Amount::max_in(EUR).partial_cmp(&Amount::max_in(USD)), None);
+    }
+
+    #[test]
+    fn test_const_fns() {
+        use oxydized_money::{Amount, Currency};
+
+        const PRICE: Amount = Amount::new(Decimal::ONE_HUNDRED, EUR);
+        const DISCOUNT: Amount = eur!(-12.50).abs();
+        const REFUND: Amount = PRICE.negated();
+        const CURRENCY: Currency = PRICE.currency();
+        const VALUE: Decimal = DISCOUNT.value();
+        assert_eq!(PRICE, eur!(100));
+        assert_eq!(DISCOUNT, eur!(12.50));
+        assert_eq!(REFUND, eur!(-100));
+        assert_eq!(CURRENCY, EUR);
+        assert_eq!(VALUE, dec!(12.50));
+        assert_eq!(VALUE.scale(), 2);
+        assert_eq!(eur!(0).negated(), eur!(0));
+        assert_eq!(jpy!(-3).negated().negated(), -jpy!(3));
+        assert_eq!(usd!(-0.001).abs().value().scale(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "scale exceeds the maximum precision allowed")]
+    fn test_const_new_invalid_scale() {
+        oxydized_money::Amount::const_new(1, 29, EUR);
+    }
+
+    #[test]
+    fn test_money_macro() {
+        use oxydized_money::Amount;
+        use oxydized_money_macros::money;
+
+        const FEE: Amount = money!("2.50 EUR");
+        assert_eq!(FEE, eur!(2.50));
+        assert_eq!(FEE.value().scale(), 2);
+        assert_eq!(money!("-1200 JPY"), jpy!(-1200));
+        assert_eq!(money!(" 0.125 BHD "), bhd!(0.125));
+        assert_eq!(money!("1.5 840"), usd!(1.5));
+        assert_eq!(
+            money!("79228162514264337593543950335 EUR"),
+            Amount(Decimal::MAX, EUR)
+        );
+    }
+
+    #[test]
+    fn test_expect_currency() {
+        assert_eq!(eur!(1).expect_currency(EUR), Ok(eur!(1)));
+        assert_eq!(jpy!(1).expect_currency(JPY), Ok(jpy!(1)));
+        assert_eq!(eur!(1).expect_currency(USD), Err(Mismatch(USD, EUR)));
+    }
+
     #[test]
     fn test_precision() {
         assert_eq!(eur!(1.2) * dec!(1), eur!(1.2));
@@ -170,6 +648,78 @@ mod test {
         assert_matches!(eur!(3).partial_cmp(&usd!(2)), None);
     }
 
+    #[test]
+    fn test_rounded() {
+        use oxydized_money::RoundingStrategy::*;
+        assert_eq!(eur!(1.005).rounded(MidpointAwayFromZero), eur!(1.01));
+        assert_eq!(eur!(1.005).rounded(MidpointNearestEven), eur!(1.00));
+        assert_eq!(eur!(-1.005).rounded(ToNegativeInfinity), eur!(-1.01));
+        assert_eq!(jpy!(99.9).rounded(ToZero), jpy!(99));
+        assert_eq!(bhd!(1.23456).rounded(ToZero), bhd!(1.234));
+        assert_eq!(xau!(1.23456).rounded(ToZero), xau!(1.23456));
+    }
+
+    #[test]
+    fn test_converted_to_rounded() {
+        use oxydized_money::RoundingStrategy::*;
+        let (converted, residual) = usd!(100).converted_to_rounded(JPY, dec!(144.6895), ToZero);
+        assert_eq!(converted, jpy!(14468));
+        assert_eq!(residual, jpy!(0.95));
+
+        let (converted, residual) = eur!(1).converted_to_rounded(USD, dec!(0.333), AwayFromZero);
+        assert_eq!(converted, usd!(0.34));
+        assert_eq!(residual, usd!(-0.007));
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&eur!(10.50)).unwrap();
+        assert_eq!(json, r#"{"value":"10.50","currency":"EUR"}"#);
+        let amount: oxydized_money::Amount = serde_json::from_str(&json).unwrap();
+        assert_eq!(amount, eur!(10.50));
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn test_serde_number() {
+        let from_json = |json| serde_json::from_str::<oxydized_money::Amount>(json).unwrap();
+        assert_eq!(from_json(r#"{"value":10.5,"currency":"EUR"}"#), eur!(10.5));
+        assert_eq!(from_json(r#"{"value":-3,"currency":"JPY"}"#), jpy!(-3));
+        assert_eq!(from_json(r#"{"value":0.1,"currency":"USD"}"#), usd!(0.1));
+    }
+
+    #[cfg(feature = "serde-arbitrary-precision")]
+    #[test]
+    fn test_serde_number_arbitrary_precision() {
+        let json = r#"{"value":0.1234567890123456789,"currency":"EUR"}"#;
+        let amount: oxydized_money::Amount = serde_json::from_str(json).unwrap();
+        assert_eq!(amount, eur!(0.1234567890123456789));
+    }
+
+    #[cfg(any(
+        feature = "with_serde",
+        feature = "async-graphql",
+        feature = "ffi",
+        feature = "juniper",
+        feature = "rusqlite",
+        feature = "sea-query",
+        feature = "sqlx-sqlite"
+    ))]
+    #[test]
+    fn test_canonical() {
+        use oxydized_money::Amount;
+
+        assert_eq!(eur!(10.50).canonical(), "10.50 EUR");
+        assert_eq!(jpy!(-3).canonical(), "-3 JPY");
+        assert_eq!(Amount::parse_canonical("10.50 EUR"), Some(eur!(10.50)));
+        assert_eq!(Amount::parse_canonical(" -3  JPY "), Some(jpy!(-3)));
+        assert_eq!(Amount::parse_canonical("10.50 978"), Some(eur!(10.50)));
+        assert_eq!(Amount::parse_canonical("10.50"), None);
+        assert_eq!(Amount::parse_canonical("EUR 10.50"), None);
+        assert_eq!(Amount::parse_canonical("10.50 XYZ"), None);
+    }
+
     #[test]
     fn test_as_ref_decimal() {
         assert!(eur!(-1).is_sign_negative());
@@ -0,0 +1,96 @@
+use crate::{Amount, AmountResult, Currency, CurrencyError, Decimal};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use iso_currency::IntoEnumIterator;
+
+/// Picks any ISO 4217 currency.
+fn currency(u: &mut Unstructured<'_>) -> Result<Currency> {
+    let index = u.choose_index(Currency::iter().count())?;
+    Ok(Currency::iter().nth(index).expect("the index is in bounds"))
+}
+
+/// With the `arbitrary` feature, arbitrary amounts have any decimal value,
+/// in any ISO 4217 currency, so that fuzz targets can generate them
+/// directly.
+impl<'a> Arbitrary<'a> for Amount {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Amount(Decimal::arbitrary(u)?, currency(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for CurrencyError {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.choose_index(6)? {
+            0 => CurrencyError::Mismatch(currency(u)?, currency(u)?),
+            1 => CurrencyError::DivideByZero,
+            2 => CurrencyError::Unknown,
+            3 => CurrencyError::MissingRate(currency(u)?, currency(u)?),
+            4 => CurrencyError::Overflow,
+            _ => CurrencyError::InvalidPrecision(currency(u)?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AmountResult {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            Ok(AmountResult::from(Amount::arbitrary(u)?))
+        } else {
+            Ok(AmountResult::from(CurrencyError::arbitrary(u)?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use arbitrary::{Arbitrary, Unstructured};
+    use oxydized_money::{Amount, AmountResult, Currency, CurrencyError};
+    use std::collections::HashSet;
+
+    fn data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 7919 % 251) as u8).collect()
+    }
+
+    #[test]
+    fn test_amount() {
+        let data = data(4096);
+        let mut u = Unstructured::new(&data);
+        let mut currencies = HashSet::new();
+        while let Ok(amount) = Amount::arbitrary(&mut u) {
+            assert!(amount.value().scale() <= 28);
+            currencies.insert(amount.currency());
+            if u.is_empty() {
+                break;
+            }
+        }
+        assert!(currencies.len() > 1);
+        let mut u = Unstructured::new(&[]);
+        assert!(Amount::arbitrary(&mut u).is_ok());
+    }
+
+    #[test]
+    fn test_currency_error() {
+        let data = data(4096);
+        let mut u = Unstructured::new(&data);
+        let mut variants = HashSet::new();
+        while !u.is_empty() {
+            let error = CurrencyError::<Currency>::arbitrary(&mut u).unwrap();
+            variants.insert(std::mem::discriminant(&error));
+        }
+        assert_eq!(variants.len(), 6);
+    }
+
+    #[test]
+    fn test_amount_result() {
+        let data = data(4096);
+        let mut u = Unstructured::new(&data);
+        let (mut ok, mut err) = (0, 0);
+        while !u.is_empty() {
+            match AmountResult::arbitrary(&mut u).unwrap().into_inner() {
+                Ok(_) => ok += 1,
+                Err(_) => err += 1,
+            }
+        }
+        assert!(ok > 0 && err > 0);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{
+    Amount, Currency,
+    CurrencyError::{self, InvalidPrecision, Overflow},
+    Decimal,
+};
+use arrow_array::{
+    builder::{Decimal128Builder, StringDictionaryBuilder},
+    cast::AsArray,
+    types::Int16Type,
+    Array, Decimal128Array, DictionaryArray,
+};
+use arrow_schema::ArrowError;
+
+const MAX_SCALE: i8 = 28;
+const MAX_MANTISSA: u128 = (1 << 96) - 1;
+
+/// `AmountArrayBuilder` builds an [`AmountArray`], i.e. a column of
+/// [amounts](Amount) stored as an Arrow `Decimal128` array of values along
+/// with a dictionary encoded array of currency codes.
+///
+/// All the values of a `Decimal128` array share the same scale, which is
+/// chosen when creating the builder. Appending an amount with more decimal
+/// places than that scale fails.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{AmountArrayBuilder, Decimal};
+/// use oxydized_money_macros::{eur, jpy};
+///
+/// let mut builder = AmountArrayBuilder::new(2).unwrap();
+/// builder.append(eur!(10.50)).unwrap();
+/// builder.append_null();
+/// builder.append(jpy!(1200)).unwrap();
+/// let array = builder.finish();
+///
+/// let amounts: Vec<_> = array.iter().collect();
+/// assert_eq!(amounts, [Some(eur!(10.50)), None, Some(jpy!(1200))]);
+/// ```
+#[derive(Debug)]
+pub struct AmountArrayBuilder {
+    scale: i8,
+    values: Decimal128Builder,
+    currencies: StringDictionaryBuilder<Int16Type>,
+}
+
+impl AmountArrayBuilder {
+    /// Creates a builder whose values have the given `scale`, i.e. number
+    /// of decimal places. Fails if `scale` is not between 0 and 28.
+    pub fn new(scale: i8) -> Result<Self, ArrowError> {
+        check_scale(scale)?;
+        Ok(Self {
+            scale,
+            values: Decimal128Builder::new().with_precision_and_scale(38, scale)?,
+            currencies: StringDictionaryBuilder::new(),
+        })
+    }
+
+    /// Appends an amount. Fails with [`CurrencyError::InvalidPrecision`] if
+    /// the amount has more decimal places than the scale of the builder, or
+    /// with [`CurrencyError::Overflow`] if its value cannot be represented
+    /// with that scale.
+    pub fn append(&mut self, amount: Amount) -> Result<(), CurrencyError> {
+        let mut value = amount.value();
+        value.rescale(self.scale as u32);
+        if value != amount.value() {
+            return Err(InvalidPrecision(amount.currency()));
+        }
+        if value.scale() != self.scale as u32 {
+            return Err(Overflow);
+        }
+        self.currencies
+            .append(amount.currency().code())
+            .map_err(|_| Overflow)?;
+        self.values.append_value(value.mantissa());
+        Ok(())
+    }
+
+    /// Appends a missing amount.
+    pub fn append_null(&mut self) {
+        self.values.append_null();
+        self.currencies.append_null();
+    }
+
+    /// Returns the number of amounts appended so far.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no amount has been appended yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Builds the [`AmountArray`] and resets the builder.
+    pub fn finish(&mut self) -> AmountArray {
+        let values = self.values.finish();
+        let currencies = self.currencies.finish();
+        AmountArray::try_new(values, currencies).expect("the builder only appends valid amounts")
+    }
+}
+
+/// `AmountArray` is a column of [amounts](Amount) backed by an Arrow
+/// `Decimal128` array of values and a dictionary encoded array of currency
+/// codes, as built by an [`AmountArrayBuilder`] or read from any Arrow
+/// source (Parquet files, DataFusion or Polars queries, ...).
+///
+/// The arrays are validated once when the `AmountArray` is created, so
+/// that iterating over the amounts reads the underlying Arrow buffers
+/// directly, without copying them nor parsing any currency code again.
+#[derive(Clone, Debug)]
+pub struct AmountArray {
+    values: Decimal128Array,
+    currencies: DictionaryArray<Int16Type>,
+    lookup: Vec<Option<Currency>>,
+}
+
+impl AmountArray {
+    /// Creates an `AmountArray` from an array of values and an array of
+    /// currency codes. Fails if the arrays have different lengths, if the
+    /// scale of the values is not between 0 and 28, if a value does not fit
+    /// in a [`Decimal`] or if a currency code is not a known ISO 4217 code.
+    pub fn try_new(
+        values: Decimal128Array,
+        currencies: DictionaryArray<Int16Type>,
+    ) -> Result<Self, ArrowError> {
+        if values.len() != currencies.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{} values but {} currencies",
+                values.len(),
+                currencies.len()
+            )));
+        }
+        check_scale(values.scale())?;
+        if let Some(value) = values
+            .iter()
+            .flatten()
+            .find(|v| v.unsigned_abs() > MAX_MANTISSA)
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "value {} does not fit in a decimal",
+                value
+            )));
+        }
+        let codes = currencies.values().as_string_opt::<i32>().ok_or_else(|| {
+            ArrowError::InvalidArgumentError("currencies are not strings".to_owned())
+        })?;
+        let lookup = codes
+            .iter()
+            .map(|code| match code {
+                Some(code) => Currency::from_code(code).map(Some).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!("unknown currency '{}'", code))
+                }),
+                None => Ok(None),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            values,
+            currencies,
+            lookup,
+        })
+    }
+
+    /// Returns the number of amounts in the array.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the array contains no amount.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the amount at index `i`, or `None` if either its value or its
+    /// currency is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn value(&self, i: usize) -> Option<Amount> {
+        let keys = self.currencies.keys();
+        if self.values.is_null(i) || keys.is_null(i) {
+            return None;
+        }
+        let currency = self.lookup[keys.value(i) as usize]?;
+        let value = Decimal::from_i128_with_scale(self.values.value(i), self.values.scale() as u32);
+        Some(Amount(value, currency))
+    }
+
+    /// Returns an iterator over the amounts of the array.
+    pub fn iter(&self) -> impl Iterator<Item = Option<Amount>> + '_ {
+        (0..self.len()).map(|i| self.value(i))
+    }
+
+    /// Returns the underlying array of values and array of currency codes.
+    pub fn into_parts(self) -> (Decimal128Array, DictionaryArray<Int16Type>) {
+        (self.values, self.currencies)
+    }
+}
+
+fn check_scale(scale: i8) -> Result<(), ArrowError> {
+    if (0..=MAX_SCALE).contains(&scale) {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "scale {} is not between 0 and {}",
+            scale, MAX_SCALE
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AmountArray, AmountArrayBuilder};
+    use crate as oxydized_money;
+    use arrow_array::{types::Int16Type, Array, Decimal128Array, DictionaryArray};
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, Decimal};
+    use oxydized_money_macros::{bhd, eur, jpy, usd};
+
+    #[test]
+    fn test_builder() {
+        let mut builder = AmountArrayBuilder::new(2).unwrap();
+        assert!(builder.is_empty());
+        builder.append(eur!(10.5)).unwrap();
+        builder.append(usd!(-3)).unwrap();
+        builder.append_null();
+        builder.append(eur!(0.01)).unwrap();
+        assert_eq!(builder.append(bhd!(1.125)), Err(InvalidPrecision(BHD)));
+        assert_eq!(builder.append(Amount(Decimal::MAX, EUR)), Err(Overflow));
+        assert_eq!(builder.len(), 4);
+
+        let (values, currencies) = builder.finish().into_parts();
+        assert_eq!(values.scale(), 2);
+        assert_eq!(values.value(0), 1050);
+        assert_eq!(values.value(1), -300);
+        assert!(values.is_null(2));
+        assert_eq!(currencies.values().len(), 2);
+        assert!(builder.is_empty());
+        assert!(AmountArrayBuilder::new(29).is_err());
+        assert!(AmountArrayBuilder::new(-1).is_err());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut builder = AmountArrayBuilder::new(3).unwrap();
+        for amount in [eur!(1.5), jpy!(-7), bhd!(1.125)] {
+            builder.append(amount).unwrap();
+        }
+        builder.append_null();
+        let array = builder.finish();
+        assert_eq!(array.len(), 4);
+        assert_eq!(
+            array.iter().collect::<Vec<_>>(),
+            [Some(eur!(1.5)), Some(jpy!(-7)), Some(bhd!(1.125)), None]
+        );
+    }
+
+    #[test]
+    fn test_try_new() {
+        let values = Decimal128Array::from(vec![Some(150), None])
+            .with_precision_and_scale(38, 2)
+            .unwrap();
+        let currencies: DictionaryArray<Int16Type> = vec!["EUR", "USD"].into_iter().collect();
+        let array = AmountArray::try_new(values.clone(), currencies).unwrap();
+        assert_eq!(array.value(0), Some(eur!(1.5)));
+        assert_eq!(array.value(1), None);
+
+        let currencies: DictionaryArray<Int16Type> = vec!["EUR", "XYZ"].into_iter().collect();
+        assert!(AmountArray::try_new(values.clone(), currencies).is_err());
+
+        let currencies: DictionaryArray<Int16Type> = vec!["EUR"].into_iter().collect();
+        assert!(AmountArray::try_new(values, currencies).is_err());
+
+        let values = Decimal128Array::from(vec![1i128 << 100]);
+        let currencies: DictionaryArray<Int16Type> = vec!["EUR"].into_iter().collect();
+        assert!(AmountArray::try_new(values, currencies).is_err());
+    }
+}
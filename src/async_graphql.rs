@@ -0,0 +1,85 @@
+use crate::{Amount, CurrencyError, CurrencyLike};
+use ::async_graphql::{
+    Error, ErrorExtensions, InputValueError, InputValueResult, Scalar, ScalarType, Value,
+};
+
+/// With the `async-graphql` feature, amounts are GraphQL scalars in their
+/// canonical string form, e.g. `"10.50 EUR"`.
+#[Scalar(name = "Amount")]
+impl ScalarType for Amount {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        if let Value::String(string) = &value {
+            if let Some(amount) = Amount::parse_canonical(string) {
+                return Ok(amount);
+            }
+        }
+        Err(InputValueError::expected_type(value))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.canonical())
+    }
+}
+
+/// With the `async-graphql` feature, currency errors can be turned into
+/// GraphQL errors with [`ErrorExtensions::extend`], whose extensions hold a
+/// `code` identifying the error along with the currencies involved, e.g.
+/// `{"code":"MISMATCH","left":"EUR","right":"USD"}`.
+impl<C: CurrencyLike> ErrorExtensions for CurrencyError<C> {
+    fn extend(&self) -> Error {
+        use CurrencyError::*;
+        Error::new(self.to_string()).extend_with(|_, extensions| match self {
+            Mismatch(left, right) => {
+                extensions.set("code", "MISMATCH");
+                extensions.set("left", left.code());
+                extensions.set("right", right.code());
+            }
+            DivideByZero => extensions.set("code", "DIVIDE_BY_ZERO"),
+            Unknown => extensions.set("code", "UNKNOWN"),
+            MissingRate(from, to) => {
+                extensions.set("code", "MISSING_RATE");
+                extensions.set("from", from.code());
+                extensions.set("to", to.code());
+            }
+            Overflow => extensions.set("code", "OVERFLOW"),
+            InvalidPrecision(currency) => {
+                extensions.set("code", "INVALID_PRECISION");
+                extensions.set("currency", currency.code());
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use ::async_graphql::{ErrorExtensions, ScalarType, Value};
+    use oxydized_money::{
+        Amount, Currency,
+        Currency::{EUR, USD},
+        CurrencyError::{DivideByZero, Mismatch},
+    };
+    use oxydized_money_macros::eur;
+
+    #[test]
+    fn test_scalar() {
+        let value = eur!(10.50).to_value();
+        assert_eq!(value, Value::String("10.50 EUR".into()));
+        assert_eq!(<Amount as ScalarType>::parse(value).unwrap(), eur!(10.50));
+        assert!(<Amount as ScalarType>::parse(Value::String("10.50".into())).is_err());
+        assert!(<Amount as ScalarType>::parse(Value::Boolean(true)).is_err());
+    }
+
+    #[test]
+    fn test_error_extensions() {
+        let error = Mismatch(EUR, USD).extend();
+        assert_eq!(error.message, "mismatch currency 'EUR' and 'USD'");
+        let extensions = error.extensions.unwrap();
+        assert_eq!(extensions.get("code"), Some(&Value::from("MISMATCH")));
+        assert_eq!(extensions.get("left"), Some(&Value::from("EUR")));
+        assert_eq!(extensions.get("right"), Some(&Value::from("USD")));
+
+        let extensions = DivideByZero::<Currency>.extend().extensions.unwrap();
+        assert_eq!(extensions.get("code"), Some(&Value::from("DIVIDE_BY_ZERO")));
+    }
+}
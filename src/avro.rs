@@ -0,0 +1,225 @@
+use crate::{
+    Amount, Currency,
+    CurrencyError::{self, InvalidPrecision, Overflow},
+    Decimal,
+};
+use apache_avro::{
+    schema::{derive::AvroSchemaComponent, Names, Namespace},
+    types::Value,
+    Schema,
+};
+use std::{error::Error, fmt::Display};
+
+/// Number of decimal places of the values of amounts in Avro records.
+pub const AVRO_SCALE: u32 = 9;
+
+const SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "Amount",
+    "namespace": "oxydized_money",
+    "fields": [
+        {
+            "name": "value",
+            "type": {"type": "bytes", "logicalType": "decimal", "precision": 38, "scale": 9}
+        },
+        {"name": "currency", "type": "string"}
+    ]
+}"#;
+
+/// With the `apache-avro` feature, the canonical Avro schema of an amount
+/// is a record holding its value as a `decimal` (with a precision of 38 and
+/// a scale of [`AVRO_SCALE`]) and its currency as an ISO 4217 code:
+///
+/// ```json
+/// {
+///     "type": "record",
+///     "name": "Amount",
+///     "namespace": "oxydized_money",
+///     "fields": [
+///         {
+///             "name": "value",
+///             "type": {"type": "bytes", "logicalType": "decimal", "precision": 38, "scale": 9}
+///         },
+///         {"name": "currency", "type": "string"}
+///     ]
+/// }
+/// ```
+///
+/// Amounts are converted to and from Avro values with [`TryFrom`].
+impl AvroSchemaComponent for Amount {
+    fn get_schema_in_ctxt(_named_schemas: &mut Names, _enclosing_namespace: &Namespace) -> Schema {
+        Schema::parse_str(SCHEMA).expect("the schema of amounts is valid")
+    }
+}
+
+/// `AvroAmountError` represents the reasons why an Avro value is not a
+/// valid [`Amount`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AvroAmountError {
+    /// The value is not a record with a `decimal` field named `value` and a
+    /// `string` field named `currency`.
+    InvalidRecord,
+
+    /// The currency is not a known ISO 4217 code.
+    UnknownCurrency(String),
+
+    /// The value does not fit in a [`Decimal`].
+    Overflow,
+}
+
+impl Error for AvroAmountError {}
+
+impl Display for AvroAmountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use AvroAmountError::*;
+        match self {
+            InvalidRecord => write!(f, "invalid amount record"),
+            UnknownCurrency(code) => write!(f, "unknown currency code '{}'", code),
+            Overflow => write!(f, "amount value out of range"),
+        }
+    }
+}
+
+impl TryFrom<Amount> for Value {
+    type Error = CurrencyError;
+
+    /// Converts an amount to an Avro record following the schema of
+    /// amounts. Fails with [`CurrencyError::InvalidPrecision`] if the amount
+    /// has more than [`AVRO_SCALE`] decimal places, or with
+    /// [`CurrencyError::Overflow`] if its value cannot be represented with
+    /// that scale.
+    fn try_from(amount: Amount) -> Result<Self, CurrencyError> {
+        let mut value = amount.value();
+        value.rescale(AVRO_SCALE);
+        if value != amount.value() {
+            return Err(InvalidPrecision(amount.currency()));
+        }
+        if value.scale() != AVRO_SCALE {
+            return Err(Overflow);
+        }
+        let bytes = value.mantissa().to_be_bytes().to_vec();
+        Ok(Value::Record(vec![
+            (
+                "value".to_owned(),
+                Value::Decimal(apache_avro::Decimal::from(bytes)),
+            ),
+            (
+                "currency".to_owned(),
+                Value::String(amount.currency().code().to_owned()),
+            ),
+        ]))
+    }
+}
+
+impl TryFrom<Value> for Amount {
+    type Error = AvroAmountError;
+
+    /// Converts an Avro record following the schema of amounts to an amount.
+    fn try_from(record: Value) -> Result<Self, AvroAmountError> {
+        let Value::Record(fields) = record else {
+            return Err(AvroAmountError::InvalidRecord);
+        };
+        let mut value = None;
+        let mut currency = None;
+        for (name, field) in fields {
+            match (name.as_str(), field) {
+                ("value", Value::Decimal(decimal)) => {
+                    let bytes = Vec::<u8>::try_from(&decimal)
+                        .map_err(|_| AvroAmountError::InvalidRecord)?;
+                    value = Some(from_be_bytes(&bytes)?);
+                }
+                ("value", Value::Bytes(bytes)) => value = Some(from_be_bytes(&bytes)?),
+                ("currency", Value::String(code)) => {
+                    currency = Some(
+                        Currency::from_code(&code).ok_or(AvroAmountError::UnknownCurrency(code))?,
+                    )
+                }
+                _ => {}
+            }
+        }
+        match (value, currency) {
+            (Some(value), Some(currency)) => Ok(Amount(value, currency)),
+            _ => Err(AvroAmountError::InvalidRecord),
+        }
+    }
+}
+
+fn from_be_bytes(bytes: &[u8]) -> Result<Decimal, AvroAmountError> {
+    if bytes.len() > 16 {
+        return Err(AvroAmountError::Overflow);
+    }
+    let sign = if bytes.first().is_some_and(|byte| byte & 0x80 != 0) {
+        0xff
+    } else {
+        0x00
+    };
+    let mut buffer = [sign; 16];
+    buffer[16 - bytes.len()..].copy_from_slice(bytes);
+    Decimal::try_from_i128_with_scale(i128::from_be_bytes(buffer), AVRO_SCALE)
+        .map_err(|_| AvroAmountError::Overflow)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_be_bytes, AvroAmountError};
+    use crate as oxydized_money;
+    use apache_avro::{types::Value, AvroSchema, Reader, Schema, Writer};
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy};
+
+    #[test]
+    fn test_schema() {
+        let Schema::Record(record) = Amount::get_schema() else {
+            panic!("the schema of amounts should be a record");
+        };
+        assert_eq!(record.name.fullname(None), "oxydized_money.Amount");
+        assert_eq!(record.fields.len(), 2);
+        assert!(matches!(record.fields[0].schema, Schema::Decimal(_)));
+        assert_eq!(record.fields[1].schema, Schema::String);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let schema = Amount::get_schema();
+        let mut writer = Writer::new(&schema, Vec::new());
+        for amount in [eur!(10.50), jpy!(-1200), eur!(0.000000001)] {
+            writer.append(Value::try_from(amount).unwrap()).unwrap();
+        }
+        let bytes = writer.into_inner().unwrap();
+        let amounts: Vec<Amount> = Reader::with_schema(&schema, &bytes[..])
+            .unwrap()
+            .map(|value| Amount::try_from(value.unwrap()).unwrap())
+            .collect();
+        assert_eq!(amounts, [eur!(10.50), jpy!(-1200), eur!(0.000000001)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Value::try_from(eur!(0.0000000001)),
+            Err(InvalidPrecision(EUR))
+        );
+        assert_eq!(Value::try_from(Amount(Decimal::MAX, EUR)), Err(Overflow));
+        assert_eq!(
+            Amount::try_from(Value::String("EUR".to_owned())),
+            Err(AvroAmountError::InvalidRecord)
+        );
+        let record = Value::Record(vec![
+            ("value".to_owned(), Value::Bytes(vec![1])),
+            ("currency".to_owned(), Value::String("XYZ".to_owned())),
+        ]);
+        assert_eq!(
+            Amount::try_from(record),
+            Err(AvroAmountError::UnknownCurrency("XYZ".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_from_be_bytes() {
+        assert_eq!(from_be_bytes(&[]), Ok(dec!(0)));
+        assert_eq!(from_be_bytes(&[0x01]), Ok(dec!(0.000000001)));
+        assert_eq!(from_be_bytes(&[0xff]), Ok(dec!(-0.000000001)));
+        assert_eq!(from_be_bytes(&[0x00, 0x80]), Ok(dec!(0.000000128)));
+        assert_eq!(from_be_bytes(&[0x01; 17]), Err(AvroAmountError::Overflow));
+    }
+}
@@ -0,0 +1,374 @@
+use crate::{
+    Amount, AmountResult, Converter, Currency, CurrencyError, Decimal, HistoricalRateProvider,
+    RateProvider, Result, RoundingStrategy,
+};
+use std::{collections::HashMap, iter::Sum};
+
+/// `MoneyBag` holds amounts of money in any number of currencies, keeping
+/// a separate total for each currency.
+///
+/// Unlike [`Amount`]s, which can only be added to amounts of the same
+/// currency, any amount can be added to or subtracted from a `MoneyBag`
+/// without a currency mismatch. This makes it suitable for summing
+/// mixed-currency transactions. Bags can also be added to or subtracted
+/// from each other.
+///
+/// # Panics
+///
+/// As for `Amount * Decimal`, the operators and the [`Extend`],
+/// [`FromIterator`] and [`Sum`] implementations panic if a per-currency
+/// total does not fit in a [`Decimal`]. Use [`MoneyBag::try_add`] and
+/// [`MoneyBag::try_sub`] instead to get a [`CurrencyError::Overflow`].
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{MoneyBag, Decimal, Currency::{EUR, USD}};
+/// use oxydized_money_macros::{eur, usd};
+///
+/// let transactions = [eur!(10), usd!(5), eur!(-2.5), usd!(1)];
+/// let bag: MoneyBag = transactions.iter().sum();
+/// assert_eq!(bag.get(EUR), eur!(7.5));
+/// assert_eq!(bag.get(USD), usd!(6));
+///
+/// let bag = bag - eur!(7.5);
+/// assert_eq!(bag, MoneyBag::from(usd!(6)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MoneyBag {
+    pub(crate) totals: HashMap<Currency, Decimal>,
+}
+
+impl MoneyBag {
+    /// Creates a new empty [`MoneyBag`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total amount held in `currency`, which is zero if the
+    /// bag does not hold any amount in that currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{MoneyBag, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let bag = MoneyBag::from(eur!(10));
+    /// assert_eq!(bag.get(EUR), eur!(10));
+    /// assert_eq!(bag.get(USD), usd!(0));
+    /// ```
+    pub fn get(&self, currency: Currency) -> Amount {
+        let value = self.totals.get(&currency).copied().unwrap_or_default();
+        Amount(value, currency)
+    }
+
+    /// Returns `true` if the bag holds a non-zero total in `currency`.
+    pub fn contains(&self, currency: Currency) -> bool {
+        self.totals.contains_key(&currency)
+    }
+
+    /// Returns an iterator over the currencies in which the bag holds a
+    /// non-zero total, in no particular order.
+    pub fn currencies(&self) -> impl Iterator<Item = Currency> + '_ {
+        self.totals.keys().copied()
+    }
+
+    /// Returns an iterator over the non-zero per-currency totals of the
+    /// bag, in no particular order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{MoneyBag, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let bag = MoneyBag::from_iter([eur!(1), usd!(2), eur!(3)]);
+    /// let mut totals: Vec<_> = bag.iter().collect();
+    /// totals.sort_by_key(|amount| amount.currency().code());
+    /// assert_eq!(totals, vec![eur!(4), usd!(2)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Amount> + '_ {
+        self.totals
+            .iter()
+            .map(|(currency, value)| Amount(*value, *currency))
+    }
+
+    /// Returns the number of currencies in which the bag holds a non-zero
+    /// total.
+    pub fn len(&self) -> usize {
+        self.totals.len()
+    }
+
+    /// Returns `true` if the bag does not hold any non-zero total.
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// Converts all the per-currency totals of the bag into the `target`
+    /// currency using the exchange rates of `provider` and returns their sum.
+    /// Rates are resolved as in [`Converter::convert`], i.e. by inverting
+    /// the opposite pair when needed. Each total is converted at full
+    /// precision and the sum is then rounded once to the number of decimal
+    /// places of `target` using banker's rounding
+    /// ([`RoundingStrategy::MidpointNearestEven`]), so that the result does
+    /// not depend on the number of currencies in the bag. Returns a
+    /// [`CurrencyError::MissingRate`](crate::CurrencyError::MissingRate) if
+    /// any of the totals cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{MoneyBag, ExchangeRate, Decimal, Currency::{EUR, USD, GBP}};
+    /// use oxydized_money_macros::{eur, usd, gbp, dec};
+    ///
+    /// let rates = [
+    ///     ExchangeRate::new(EUR, USD, dec!(1.1)),
+    ///     ExchangeRate::new(GBP, USD, dec!(1.3)),
+    /// ];
+    /// let bag = MoneyBag::from_iter([eur!(10), usd!(5), gbp!(2.333)]);
+    /// assert_eq!(bag.consolidate(USD, &rates), usd!(19.03));
+    /// assert!(bag.consolidate(EUR, &rates[..1]).is_missing_rate());
+    /// ```
+    pub fn consolidate<P>(&self, target: Currency, provider: &P) -> AmountResult
+    where
+        P: RateProvider + ?Sized,
+    {
+        let converter = Converter::new(provider);
+        self.consolidate_with(target, |amount| converter.convert(amount, target))
+    }
+
+    /// Same as [`MoneyBag::consolidate`] but using the exchange rates
+    /// applicable at `date` (see [`Converter::convert_at`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{MoneyBag, DatedRates, ExchangeRate, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd, dec};
+    ///
+    /// let mut rates = DatedRates::default();
+    /// rates.insert("2024-01-02", ExchangeRate::new(EUR, USD, dec!(1.1)));
+    /// rates.insert("2024-01-03", ExchangeRate::new(EUR, USD, dec!(1.2)));
+    ///
+    /// let bag = MoneyBag::from_iter([eur!(10), usd!(5)]);
+    /// assert_eq!(bag.consolidate_at(USD, &rates, &"2024-01-02"), usd!(16));
+    /// assert_eq!(bag.consolidate_at(USD, &rates, &"2024-01-03"), usd!(17));
+    /// ```
+    pub fn consolidate_at<P>(&self, target: Currency, provider: &P, date: &P::Date) -> AmountResult
+    where
+        P: HistoricalRateProvider + ?Sized,
+    {
+        let converter = Converter::new(provider);
+        self.consolidate_with(target, |amount| converter.convert_at(amount, target, date))
+    }
+
+    fn consolidate_with<F>(&self, target: Currency, convert: F) -> AmountResult
+    where
+        F: Fn(Amount) -> AmountResult,
+    {
+        let mut totals: Vec<Amount> = self.iter().collect();
+        totals.sort_by_key(|amount| amount.currency().code());
+        let zero = AmountResult::from(Amount(Decimal::ZERO, target));
+        let sum = totals.into_iter().map(convert).fold(zero, |sum, x| sum + x);
+        match sum.0 {
+            Ok(total) => total.rounded(RoundingStrategy::MidpointNearestEven).into(),
+            Err(_) => sum,
+        }
+    }
+
+    /// Adds `amount` to the total of its currency, or returns a
+    /// [`CurrencyError::Overflow`] and leaves the bag untouched if the total
+    /// does not fit in a [`Decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, CurrencyError, MoneyBag, Decimal, Currency::EUR};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// let mut bag = MoneyBag::from(Amount(Decimal::MAX, EUR));
+    /// assert_eq!(bag.try_add(eur!(-1)), Ok(()));
+    /// assert_eq!(bag.try_add(eur!(2)), Err(CurrencyError::Overflow));
+    /// assert_eq!(bag.get(EUR), Amount(Decimal::MAX - Decimal::ONE, EUR));
+    /// ```
+    pub fn try_add(&mut self, amount: Amount) -> Result<()> {
+        self.accumulate(amount.value(), amount.currency())
+    }
+
+    /// Subtracts `amount` from the total of its currency, or returns a
+    /// [`CurrencyError::Overflow`] and leaves the bag untouched if the total
+    /// does not fit in a [`Decimal`].
+    pub fn try_sub(&mut self, amount: Amount) -> Result<()> {
+        self.accumulate(-amount.value(), amount.currency())
+    }
+
+    pub(crate) fn accumulate(&mut self, value: Decimal, currency: Currency) -> Result<()> {
+        let total = self.totals.get(&currency).copied().unwrap_or_default();
+        let total = total.checked_add(value).ok_or(CurrencyError::Overflow)?;
+        if total.is_zero() {
+            self.totals.remove(&currency);
+        } else {
+            self.totals.insert(currency, total);
+        }
+        Ok(())
+    }
+}
+
+impl From<Amount> for MoneyBag {
+    fn from(amount: Amount) -> Self {
+        Self::from_iter([amount])
+    }
+}
+
+impl Extend<Amount> for MoneyBag {
+    fn extend<I: IntoIterator<Item = Amount>>(&mut self, iter: I) {
+        for amount in iter {
+            self.try_add(amount).expect("overflow");
+        }
+    }
+}
+
+impl<'a> Extend<&'a Amount> for MoneyBag {
+    fn extend<I: IntoIterator<Item = &'a Amount>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl FromIterator<Amount> for MoneyBag {
+    fn from_iter<I: IntoIterator<Item = Amount>>(iter: I) -> Self {
+        let mut bag = MoneyBag::new();
+        bag.extend(iter);
+        bag
+    }
+}
+
+impl<'a> FromIterator<&'a Amount> for MoneyBag {
+    fn from_iter<I: IntoIterator<Item = &'a Amount>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl Sum<Amount> for MoneyBag {
+    fn sum<I: Iterator<Item = Amount>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+impl<'a> Sum<&'a Amount> for MoneyBag {
+    fn sum<I: Iterator<Item = &'a Amount>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        Amount, Currency::*, CurrencyError::*, DateFallback, DatedRates, Decimal, ExchangeRate,
+        MoneyBag,
+    };
+    use oxydized_money_macros::{dec, eur, gbp, jpy, usd};
+
+    #[test]
+    fn test_totals() {
+        let bag: MoneyBag = [eur!(1), usd!(2), eur!(3), jpy!(100), jpy!(-100)]
+            .into_iter()
+            .sum();
+        assert_eq!(bag.len(), 2);
+        assert_eq!(bag.get(EUR), eur!(4));
+        assert_eq!(bag.get(USD), usd!(2));
+        assert_eq!(bag.get(JPY), jpy!(0));
+        assert!(bag.contains(EUR));
+        assert!(!bag.contains(JPY));
+
+        let mut currencies: Vec<_> = bag.currencies().collect();
+        currencies.sort_by_key(|currency| currency.code());
+        assert_eq!(currencies, vec![EUR, USD]);
+    }
+
+    #[test]
+    fn test_collect_and_extend() {
+        struct Transaction {
+            amount: Amount,
+        }
+        let transactions = [
+            Transaction { amount: eur!(10) },
+            Transaction { amount: usd!(5) },
+            Transaction { amount: eur!(-2.5) },
+        ];
+        let mut bag = transactions.iter().map(|t| t.amount).collect::<MoneyBag>();
+        assert_eq!(bag.get(EUR), eur!(7.5));
+        assert_eq!(bag.get(USD), usd!(5));
+
+        bag.extend([usd!(-5), jpy!(100)]);
+        bag.extend(&[eur!(0.5)]);
+        assert_eq!(bag, MoneyBag::from_iter([eur!(8), jpy!(100)]));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(MoneyBag::new().is_empty());
+        assert_eq!(MoneyBag::new(), MoneyBag::default());
+        assert_eq!(MoneyBag::from(eur!(0)), MoneyBag::new());
+        assert_eq!(
+            [eur!(1), eur!(-1)].iter().sum::<MoneyBag>(),
+            MoneyBag::new()
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = Amount(Decimal::MAX, EUR);
+        let mut bag = MoneyBag::from_iter([max, usd!(1)]);
+        assert_eq!(bag.try_add(eur!(1)), Err(Overflow));
+        assert_eq!(bag.try_sub(-eur!(1)), Err(Overflow));
+        assert_eq!(bag.get(EUR), max);
+        assert_eq!(bag.try_add(usd!(1)), Ok(()));
+        assert_eq!(bag.try_sub(max), Ok(()));
+        assert_eq!(bag, MoneyBag::from(usd!(2)));
+        assert_eq!(bag.try_sub(usd!(2)), Ok(()));
+        assert!(bag.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_overflow_panics() {
+        let _: MoneyBag = [Amount(Decimal::MAX, EUR), eur!(1)].iter().sum();
+    }
+
+    #[test]
+    fn test_consolidate() {
+        let rates = vec![
+            ExchangeRate::new(EUR, USD, dec!(2)),
+            ExchangeRate::new(USD, JPY, dec!(150.5)),
+        ];
+        let bag = MoneyBag::from_iter([eur!(1.005), usd!(0.003), jpy!(1)]);
+        assert_eq!(bag.consolidate(EUR, &rates), MissingRate(JPY, EUR));
+        assert_eq!(bag.consolidate(JPY, &rates), MissingRate(EUR, JPY));
+        assert_eq!(MoneyBag::from(usd!(1)).consolidate(JPY, &rates), jpy!(150));
+        assert_eq!(
+            MoneyBag::from_iter([usd!(1), jpy!(1)]).consolidate(JPY, &rates),
+            jpy!(152)
+        );
+        assert_eq!(MoneyBag::new().consolidate(GBP, &rates), gbp!(0));
+
+        let bag = MoneyBag::from_iter([eur!(0.0025), usd!(0.015)]);
+        assert_eq!(bag.consolidate(USD, &rates), usd!(0.02));
+        assert_eq!(bag.consolidate(USD, &rates[..]), usd!(0.02));
+        assert_eq!(bag.consolidate(EUR, &rates), eur!(0.01));
+    }
+
+    #[test]
+    fn test_consolidate_at() {
+        let mut rates = DatedRates::new(DateFallback::Exact);
+        rates.insert(1, ExchangeRate::new(EUR, USD, dec!(2)));
+        rates.insert(2, ExchangeRate::new(EUR, USD, dec!(4)));
+
+        let bag = MoneyBag::from_iter([eur!(1), usd!(1)]);
+        assert_eq!(bag.consolidate_at(USD, &rates, &1), usd!(3));
+        assert_eq!(bag.consolidate_at(USD, &rates, &2), usd!(5));
+        assert_eq!(bag.consolidate_at(EUR, &rates, &2), eur!(1.25));
+        assert_eq!(bag.consolidate_at(USD, &rates, &3), MissingRate(EUR, USD));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    Amount, Currency,
+    CurrencyError::{self, InvalidPrecision, Overflow},
+    Decimal,
+};
+use ::bigdecimal::{num_bigint::BigInt, BigDecimal, Zero};
+
+const MAX_SCALE: i64 = 28;
+
+impl Amount {
+    /// Returns the value of the amount as a [`BigDecimal`], with the same
+    /// scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bigdecimal::BigDecimal;
+    /// use oxydized_money::Decimal;
+    /// use oxydized_money_macros::eur;
+    /// use std::str::FromStr;
+    ///
+    /// let value = eur!(10.50).value_bigdecimal();
+    /// assert_eq!(value, BigDecimal::from_str("10.50").unwrap());
+    /// assert_eq!(value.fractional_digit_count(), 2);
+    /// ```
+    pub fn value_bigdecimal(&self) -> BigDecimal {
+        let value = self.value();
+        BigDecimal::new(value.mantissa().into(), value.scale().into())
+    }
+
+    /// Creates an amount of `value` in `currency` from a [`BigDecimal`], as
+    /// returned by several database drivers. Trailing zeros beyond the 28
+    /// decimal places of a [`Decimal`] are dropped, but the conversion never
+    /// rounds: it fails with [`CurrencyError::InvalidPrecision`] if `value`
+    /// has more significant decimal places, or with
+    /// [`CurrencyError::Overflow`] if it is too large for a [`Decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bigdecimal::BigDecimal;
+    /// use oxydized_money::{Amount, Currency::EUR, CurrencyError, Decimal};
+    /// use oxydized_money_macros::eur;
+    /// use std::str::FromStr;
+    ///
+    /// let value = BigDecimal::from_str("10.50").unwrap();
+    /// assert_eq!(Amount::try_from_bigdecimal(&value, EUR), Ok(eur!(10.50)));
+    ///
+    /// let value = BigDecimal::from_str("0.00000000000000000000000000001").unwrap();
+    /// assert_eq!(
+    ///     Amount::try_from_bigdecimal(&value, EUR),
+    ///     Err(CurrencyError::InvalidPrecision(EUR))
+    /// );
+    /// ```
+    pub fn try_from_bigdecimal(
+        value: &BigDecimal,
+        currency: Currency,
+    ) -> Result<Amount, CurrencyError> {
+        let (mut digits, mut scale) = value.as_bigint_and_exponent();
+        if scale > MAX_SCALE {
+            (digits, scale) = value.normalized().into_bigint_and_exponent();
+            if scale > MAX_SCALE {
+                return Err(InvalidPrecision(currency));
+            }
+        }
+        if scale < 0 {
+            if digits.is_zero() {
+                return Ok(Amount(Decimal::ZERO, currency));
+            }
+            if scale < -MAX_SCALE - 1 {
+                return Err(Overflow);
+            }
+            digits *= BigInt::from(10).pow(scale.unsigned_abs() as u32);
+            scale = 0;
+        }
+        let mantissa = i128::try_from(&digits).map_err(|_| Overflow)?;
+        let value =
+            Decimal::try_from_i128_with_scale(mantissa, scale as u32).map_err(|_| Overflow)?;
+        Ok(Amount(value, currency))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use bigdecimal::BigDecimal;
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy};
+    use std::str::FromStr;
+
+    fn big(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_value_bigdecimal() {
+        assert_eq!(eur!(10.50).value_bigdecimal(), big("10.50"));
+        assert_eq!(eur!(10.50).value_bigdecimal().fractional_digit_count(), 2);
+        assert_eq!(jpy!(-1200).value_bigdecimal(), big("-1200"));
+        assert_eq!(
+            Amount(Decimal::MAX, EUR).value_bigdecimal(),
+            big("79228162514264337593543950335")
+        );
+    }
+
+    #[test]
+    fn test_try_from_bigdecimal() {
+        for amount in [
+            eur!(10.50),
+            jpy!(-1200),
+            eur!(0.000),
+            Amount(Decimal::MIN, EUR),
+        ] {
+            let converted =
+                Amount::try_from_bigdecimal(&amount.value_bigdecimal(), amount.currency());
+            assert_eq!(converted, Ok(amount));
+            assert_eq!(converted.unwrap().value().scale(), amount.value().scale());
+        }
+        assert_eq!(
+            Amount::try_from_bigdecimal(&big("1e3"), JPY),
+            Ok(jpy!(1000))
+        );
+        assert_eq!(Amount::try_from_bigdecimal(&big("0e-40"), EUR), Ok(eur!(0)));
+        assert_eq!(Amount::try_from_bigdecimal(&big("0e40"), EUR), Ok(eur!(0)));
+        assert_eq!(
+            Amount::try_from_bigdecimal(&big("1.500000000000000000000000000000000"), EUR),
+            Ok(Amount(dec!(1.5), EUR))
+        );
+    }
+
+    #[test]
+    fn test_try_from_bigdecimal_errors() {
+        assert_eq!(
+            Amount::try_from_bigdecimal(&big("0.00000000000000000000000000001"), EUR),
+            Err(InvalidPrecision(EUR))
+        );
+        assert_eq!(
+            Amount::try_from_bigdecimal(&big("79228162514264337593543950336"), EUR),
+            Err(Overflow)
+        );
+        assert_eq!(
+            Amount::try_from_bigdecimal(&big("1e29"), EUR),
+            Err(Overflow)
+        );
+        assert_eq!(
+            Amount::try_from_bigdecimal(&big("1e100"), EUR),
+            Err(Overflow)
+        );
+        assert_eq!(
+            Amount::try_from_bigdecimal(&big("1e-100"), EUR),
+            Err(InvalidPrecision(EUR))
+        );
+    }
+}
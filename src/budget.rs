@@ -0,0 +1,305 @@
+use crate::{Amount, AmountResult, Currency, Decimal};
+#[cfg(feature = "with_serde")]
+use crate::{CurrencyError, Result};
+
+/// `Envelope` represents a budget envelope: an [`Amount`] of money that
+/// was allocated for a given purpose, along with how much of it has
+/// already been spent.
+///
+/// All operations on an envelope are currency-checked: trying to spend,
+/// refund or transfer an amount in a currency other than the currency of
+/// the envelope results in a [`CurrencyError::Mismatch`](crate::CurrencyError::Mismatch)
+/// and leaves the envelope untouched. Likewise, operations after which the
+/// allocated amount, the amount spent or the remaining balance would not
+/// fit in a [`Decimal`] result in a
+/// [`CurrencyError::Overflow`](crate::CurrencyError::Overflow).
+///
+/// With the `with_serde` feature, envelopes are serialized as a structure
+/// with an `allocated` and a `spent` field. Deserializing an envelope
+/// whose fields are in different currencies, or whose remaining balance
+/// does not fit in a [`Decimal`], fails.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Envelope, Decimal};
+/// use oxydized_money_macros::{eur, usd};
+///
+/// let mut groceries = Envelope::new(eur!(400));
+/// assert_eq!(groceries.spend(eur!(120)), eur!(280));
+/// assert_eq!(groceries.refund(eur!(20)), eur!(300));
+/// assert!(groceries.spend(usd!(10)).is_mismatch());
+/// assert_eq!(groceries.remaining(), eur!(300));
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "with_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "EnvelopeRepr", into = "EnvelopeRepr")
+)]
+pub struct Envelope {
+    allocated: Amount,
+    spent: Amount,
+}
+
+#[cfg(feature = "with_serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Envelope")]
+struct EnvelopeRepr {
+    allocated: Amount,
+    spent: Amount,
+}
+
+#[cfg(feature = "with_serde")]
+impl TryFrom<EnvelopeRepr> for Envelope {
+    type Error = CurrencyError;
+
+    fn try_from(EnvelopeRepr { allocated, spent }: EnvelopeRepr) -> Result<Self> {
+        (allocated - spent).into_inner()?;
+        Ok(Self { allocated, spent })
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl From<Envelope> for EnvelopeRepr {
+    fn from(Envelope { allocated, spent }: Envelope) -> Self {
+        EnvelopeRepr { allocated, spent }
+    }
+}
+
+impl Envelope {
+    /// Creates a new [`Envelope`] with the provided allocation and
+    /// nothing spent yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Envelope, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// let envelope = Envelope::new(eur!(100));
+    /// assert_eq!(envelope.allocated(), eur!(100));
+    /// assert_eq!(envelope.spent(), eur!(0));
+    /// ```
+    pub fn new(allocated: Amount) -> Self {
+        let spent = Amount(Decimal::ZERO, allocated.currency());
+        Self { allocated, spent }
+    }
+
+    /// Returns the currency of the envelope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Envelope, Decimal, Currency::EUR};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// assert_eq!(Envelope::new(eur!(100)).currency(), EUR);
+    /// ```
+    pub fn currency(&self) -> Currency {
+        self.allocated.currency()
+    }
+
+    /// Returns the amount allocated to the envelope.
+    pub fn allocated(&self) -> Amount {
+        self.allocated
+    }
+
+    /// Returns the total amount spent from the envelope so far.
+    pub fn spent(&self) -> Amount {
+        self.spent
+    }
+
+    /// Returns the remaining balance of the envelope, i.e. the allocated
+    /// amount minus the amount spent. The remaining balance is negative
+    /// if the envelope was overspent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Envelope, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// let mut envelope = Envelope::new(eur!(100));
+    /// envelope.spend(eur!(130));
+    /// assert_eq!(envelope.remaining(), eur!(-30));
+    /// ```
+    pub fn remaining(&self) -> Amount {
+        // the operations on the envelope ensure that this cannot overflow
+        let remaining = self.allocated.value().checked_sub(self.spent.value());
+        Amount(remaining.expect("overflow"), self.currency())
+    }
+
+    /// Records `amount` as spent and returns the remaining balance.
+    /// Returns a [`CurrencyError::Mismatch`](crate::CurrencyError::Mismatch)
+    /// if `amount` is not in the currency of the envelope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Envelope, Decimal, CurrencyError, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let mut envelope = Envelope::new(eur!(100));
+    /// assert_eq!(envelope.spend(eur!(30)), eur!(70));
+    /// assert_eq!(envelope.spend(usd!(30)), CurrencyError::Mismatch(EUR, USD));
+    /// ```
+    pub fn spend(&mut self, amount: Amount) -> AmountResult {
+        self.update_spent(self.spent + amount)
+    }
+
+    /// Cancels a previous expense of `amount` and returns the remaining
+    /// balance. Returns a [`CurrencyError::Mismatch`](crate::CurrencyError::Mismatch)
+    /// if `amount` is not in the currency of the envelope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Envelope, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// let mut envelope = Envelope::new(eur!(100));
+    /// envelope.spend(eur!(30));
+    /// assert_eq!(envelope.refund(eur!(10)), eur!(80));
+    /// assert_eq!(envelope.spent(), eur!(20));
+    /// ```
+    pub fn refund(&mut self, amount: Amount) -> AmountResult {
+        self.update_spent(self.spent - amount)
+    }
+
+    /// Moves `amount` of allocation from `self` to `other` and returns the
+    /// remaining balance of `self`. Returns a
+    /// [`CurrencyError::Mismatch`](crate::CurrencyError::Mismatch) and leaves
+    /// both envelopes untouched if the currencies of the envelopes and of
+    /// `amount` do not all agree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Envelope, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let mut groceries = Envelope::new(eur!(400));
+    /// let mut restaurants = Envelope::new(eur!(100));
+    /// assert_eq!(groceries.transfer_to(&mut restaurants, eur!(50)), eur!(350));
+    /// assert_eq!(restaurants.remaining(), eur!(150));
+    ///
+    /// let mut travel = Envelope::new(usd!(1000));
+    /// assert!(groceries.transfer_to(&mut travel, eur!(50)).is_mismatch());
+    /// ```
+    pub fn transfer_to(&mut self, other: &mut Envelope, amount: Amount) -> AmountResult {
+        let allocated = self.allocated - amount;
+        let other_allocated = other.allocated + amount;
+        let remaining = allocated - self.spent;
+        let other_remaining = other_allocated - other.spent;
+        match (
+            allocated.0,
+            other_allocated.0,
+            remaining.0,
+            other_remaining.0,
+        ) {
+            (Ok(allocated), Ok(other_allocated), Ok(_), Ok(_)) => {
+                self.allocated = allocated;
+                other.allocated = other_allocated;
+                remaining
+            }
+            (_, _, Err(_), _) => remaining,
+            _ => other_remaining,
+        }
+    }
+
+    /// Records `spent` as the amount spent if it and the resulting
+    /// remaining balance are valid, and returns the remaining balance.
+    fn update_spent(&mut self, spent: AmountResult) -> AmountResult {
+        let remaining = self.allocated - spent;
+        if let (Ok(spent), Ok(_)) = (spent.0, remaining.0) {
+            self.spent = spent;
+        }
+        remaining
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, Decimal, Envelope, Operation};
+    use oxydized_money_macros::{eur, usd};
+
+    #[test]
+    fn test_new() {
+        let envelope = Envelope::new(usd!(50));
+        assert_eq!(envelope.currency(), USD);
+        assert_eq!(envelope.allocated(), usd!(50));
+        assert_eq!(envelope.spent(), usd!(0));
+        assert_eq!(envelope.remaining(), usd!(50));
+    }
+
+    #[test]
+    fn test_spend() {
+        let mut envelope = Envelope::new(eur!(100));
+        assert_eq!(envelope.spend(eur!(40)), eur!(60));
+        assert_eq!(envelope.spend(eur!(70)), eur!(-10));
+        assert_eq!(envelope.spent(), eur!(110));
+        assert_eq!(envelope.spend(usd!(1)), Mismatch(EUR, USD));
+        assert_eq!(envelope.spend(usd!(1)).operation(), Some(Operation::Add));
+        assert_eq!(envelope.spent(), eur!(110));
+    }
+
+    #[test]
+    fn test_refund() {
+        let mut envelope = Envelope::new(eur!(100));
+        envelope.spend(eur!(40));
+        assert_eq!(envelope.refund(eur!(15)), eur!(75));
+        assert_eq!(envelope.refund(usd!(15)), Mismatch(EUR, USD));
+        assert_eq!(envelope.refund(usd!(15)).operation(), Some(Operation::Sub));
+        assert_eq!(envelope.remaining(), eur!(75));
+    }
+
+    #[test]
+    fn test_transfer_to() {
+        let mut a = Envelope::new(eur!(100));
+        let mut b = Envelope::new(eur!(10));
+        assert_eq!(a.transfer_to(&mut b, eur!(30)), eur!(70));
+        assert_eq!(a.allocated(), eur!(70));
+        assert_eq!(b.allocated(), eur!(40));
+
+        assert_eq!(a.transfer_to(&mut b, usd!(30)), Mismatch(EUR, USD));
+        assert_eq!(a.allocated(), eur!(70));
+        assert_eq!(b.allocated(), eur!(40));
+
+        let mut c = Envelope::new(usd!(10));
+        assert_eq!(a.transfer_to(&mut c, eur!(30)), Mismatch(USD, EUR));
+        assert_eq!(a.allocated(), eur!(70));
+        assert_eq!(c.allocated(), usd!(10));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = Amount(Decimal::MAX, EUR);
+        let mut envelope = Envelope::new(max);
+        assert_eq!(envelope.refund(eur!(1)), Overflow);
+        assert_eq!(envelope.spent(), eur!(0));
+        assert_eq!(envelope.spend(max), eur!(0));
+        assert_eq!(envelope.spend(eur!(1)), Overflow);
+        assert_eq!(envelope.spent(), max);
+
+        let mut other = Envelope::new(eur!(0));
+        assert_eq!(other.spend(max), -max);
+        assert_eq!(other.transfer_to(&mut envelope, eur!(1)), Overflow);
+        assert_eq!(envelope.transfer_to(&mut other, eur!(-1)), Overflow);
+        assert_eq!(envelope.allocated(), max);
+        assert_eq!(other.allocated(), eur!(0));
+        assert_eq!(other.remaining(), -max);
+    }
+
+    #[test]
+    #[cfg(feature = "with_serde")]
+    fn test_serde() {
+        let mut envelope = Envelope::new(eur!(100));
+        envelope.spend(eur!(30));
+        let json = serde_json::to_string(&envelope).unwrap();
+        assert_eq!(serde_json::from_str::<Envelope>(&json).unwrap(), envelope);
+        let json = json.replacen("EUR", "USD", 1);
+        assert!(serde_json::from_str::<Envelope>(&json).is_err());
+    }
+}
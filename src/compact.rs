@@ -0,0 +1,147 @@
+use crate::{Amount, Currency, Decimal};
+use core::fmt::{Debug, Display};
+
+const SIGN_MASK: u32 = 0x8000_0000;
+const SCALE_MASK: u32 = 0x00FF_0000;
+const SCALE_SHIFT: u32 = 16;
+const CURRENCY_MASK: u32 = 0x0000_FFFF;
+
+/// `CompactAmount` is a 16 bytes representation of an [`Amount`], for large
+/// collections of amounts where the padding of [`Amount`] (a [`Decimal`]
+/// followed by a [`Currency`]) wastes memory and cache.
+///
+/// A [`Decimal`] only uses the sign and the scale bits of its 32 bits of
+/// flags, so that the ISO 4217 numeric code of the currency is stored in the
+/// unused low 16 bits. Compact amounts are only meant for storage: they are
+/// converted from and to [`Amount`]s without loss, and compared as the
+/// corresponding amounts, i.e. `10.5 EUR` equals `10.50 EUR`.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, CompactAmount, Currency::EUR, Decimal};
+/// use oxydized_money_macros::{dec, eur};
+///
+/// assert_eq!(std::mem::size_of::<CompactAmount>(), 16);
+///
+/// let prices: Vec<CompactAmount> = vec![eur!(0.25).into(), eur!(-0.75).into()];
+/// assert_eq!(prices[1].value(), dec!(-0.75));
+/// assert_eq!(prices[1].currency(), EUR);
+/// assert_eq!(Amount::from(prices[1]), eur!(-0.75));
+/// ```
+#[derive(Clone, Copy)]
+pub struct CompactAmount {
+    lo: u32,
+    mid: u32,
+    hi: u32,
+    flags: u32,
+}
+
+const _: () = assert!(core::mem::size_of::<CompactAmount>() == 16);
+
+impl CompactAmount {
+    /// Returns the quantity of money.
+    pub fn value(&self) -> Decimal {
+        Decimal::from_parts(
+            self.lo,
+            self.mid,
+            self.hi,
+            self.flags & SIGN_MASK != 0,
+            (self.flags & SCALE_MASK) >> SCALE_SHIFT,
+        )
+    }
+
+    /// Returns the currency in which [`value`](CompactAmount::value) is
+    /// measured.
+    pub fn currency(&self) -> Currency {
+        Currency::from_numeric((self.flags & CURRENCY_MASK) as u16)
+            .expect("compact amounts only hold valid numeric codes")
+    }
+
+    /// Returns the [`Amount`] represented by `self`.
+    pub fn to_amount(&self) -> Amount {
+        Amount(self.value(), self.currency())
+    }
+}
+
+impl From<Amount> for CompactAmount {
+    fn from(Amount(value, currency): Amount) -> Self {
+        let parts = value.unpack();
+        let sign = if parts.negative { SIGN_MASK } else { 0 };
+        Self {
+            lo: parts.lo,
+            mid: parts.mid,
+            hi: parts.hi,
+            flags: sign | (parts.scale << SCALE_SHIFT) | u32::from(currency.numeric()),
+        }
+    }
+}
+
+impl From<CompactAmount> for Amount {
+    fn from(amount: CompactAmount) -> Self {
+        amount.to_amount()
+    }
+}
+
+impl PartialEq for CompactAmount {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_amount() == other.to_amount()
+    }
+}
+
+impl Eq for CompactAmount {}
+
+impl Debug for CompactAmount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.to_amount(), f)
+    }
+}
+
+impl Display for CompactAmount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_amount(), f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CompactAmount;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, Decimal};
+    use oxydized_money_macros::{bhd, dec, eur, jpy, usd};
+    use std::mem::size_of;
+
+    #[test]
+    fn test_size() {
+        assert_eq!(size_of::<CompactAmount>(), 16);
+        assert!(size_of::<CompactAmount>() < size_of::<Amount>());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for amount in [
+            eur!(10.50),
+            usd!(-0.01),
+            jpy!(0),
+            bhd!(-1234.567),
+            Amount(Decimal::MAX, XXX),
+            Amount(Decimal::MIN, XAU),
+            Amount(dec!(0.0000000000000000000000000001), EUR),
+        ] {
+            let compact = CompactAmount::from(amount);
+            assert_eq!(compact.value(), amount.value());
+            assert_eq!(compact.value().scale(), amount.value().scale());
+            assert_eq!(compact.currency(), amount.currency());
+            assert_eq!(Amount::from(compact), amount);
+        }
+    }
+
+    #[test]
+    fn test_eq_display() {
+        let compact = CompactAmount::from(eur!(10.5));
+        assert_eq!(compact, CompactAmount::from(eur!(10.50)));
+        assert_ne!(compact, CompactAmount::from(usd!(10.50)));
+        assert_eq!(format!("{}", compact), "€ 10.50");
+        assert_eq!(format!("{:?}", compact), format!("{:?}", eur!(10.5)));
+    }
+}
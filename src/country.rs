@@ -0,0 +1,368 @@
+use crate::{Amount, Currency, Decimal};
+
+/// Primary currency of each country, indexed by ISO 3166-1 alpha-2 code.
+/// Countries using several currencies are mapped to the one used for
+/// everyday payments (e.g. `CHF` rather than `CHE` or `CHW` for `CH`).
+const COUNTRY_CURRENCIES: &[(&str, &str)] = &[
+    ("AD", "EUR"),
+    ("AE", "AED"),
+    ("AF", "AFN"),
+    ("AG", "XCD"),
+    ("AI", "XCD"),
+    ("AL", "ALL"),
+    ("AM", "AMD"),
+    ("AO", "AOA"),
+    ("AR", "ARS"),
+    ("AS", "USD"),
+    ("AT", "EUR"),
+    ("AU", "AUD"),
+    ("AW", "AWG"),
+    ("AX", "EUR"),
+    ("AZ", "AZN"),
+    ("BA", "BAM"),
+    ("BB", "BBD"),
+    ("BD", "BDT"),
+    ("BE", "EUR"),
+    ("BF", "XOF"),
+    ("BG", "EUR"),
+    ("BH", "BHD"),
+    ("BI", "BIF"),
+    ("BJ", "XOF"),
+    ("BL", "EUR"),
+    ("BM", "BMD"),
+    ("BN", "BND"),
+    ("BO", "BOB"),
+    ("BQ", "USD"),
+    ("BR", "BRL"),
+    ("BS", "BSD"),
+    ("BT", "BTN"),
+    ("BV", "NOK"),
+    ("BW", "BWP"),
+    ("BY", "BYN"),
+    ("BZ", "BZD"),
+    ("CA", "CAD"),
+    ("CC", "AUD"),
+    ("CD", "CDF"),
+    ("CF", "XAF"),
+    ("CG", "XAF"),
+    ("CH", "CHF"),
+    ("CI", "XOF"),
+    ("CK", "NZD"),
+    ("CL", "CLP"),
+    ("CM", "XAF"),
+    ("CN", "CNY"),
+    ("CO", "COP"),
+    ("CR", "CRC"),
+    ("CU", "CUP"),
+    ("CV", "CVE"),
+    ("CW", "ANG"),
+    ("CX", "AUD"),
+    ("CY", "EUR"),
+    ("CZ", "CZK"),
+    ("DE", "EUR"),
+    ("DJ", "DJF"),
+    ("DK", "DKK"),
+    ("DM", "XCD"),
+    ("DO", "DOP"),
+    ("DZ", "DZD"),
+    ("EC", "USD"),
+    ("EE", "EUR"),
+    ("EG", "EGP"),
+    ("EH", "MAD"),
+    ("ER", "ERN"),
+    ("ES", "EUR"),
+    ("ET", "ETB"),
+    ("FI", "EUR"),
+    ("FJ", "FJD"),
+    ("FK", "FKP"),
+    ("FM", "USD"),
+    ("FO", "DKK"),
+    ("FR", "EUR"),
+    ("GA", "XAF"),
+    ("GB", "GBP"),
+    ("GD", "XCD"),
+    ("GE", "GEL"),
+    ("GF", "EUR"),
+    ("GG", "GBP"),
+    ("GH", "GHS"),
+    ("GI", "GIP"),
+    ("GL", "DKK"),
+    ("GM", "GMD"),
+    ("GN", "GNF"),
+    ("GP", "EUR"),
+    ("GQ", "XAF"),
+    ("GR", "EUR"),
+    ("GS", "GBP"),
+    ("GT", "GTQ"),
+    ("GU", "USD"),
+    ("GW", "XOF"),
+    ("GY", "GYD"),
+    ("HK", "HKD"),
+    ("HM", "AUD"),
+    ("HN", "HNL"),
+    ("HR", "EUR"),
+    ("HT", "HTG"),
+    ("HU", "HUF"),
+    ("ID", "IDR"),
+    ("IE", "EUR"),
+    ("IL", "ILS"),
+    ("IM", "GBP"),
+    ("IN", "INR"),
+    ("IO", "USD"),
+    ("IQ", "IQD"),
+    ("IR", "IRR"),
+    ("IS", "ISK"),
+    ("IT", "EUR"),
+    ("JE", "GBP"),
+    ("JM", "JMD"),
+    ("JO", "JOD"),
+    ("JP", "JPY"),
+    ("KE", "KES"),
+    ("KG", "KGS"),
+    ("KH", "KHR"),
+    ("KI", "AUD"),
+    ("KM", "KMF"),
+    ("KN", "XCD"),
+    ("KP", "KPW"),
+    ("KR", "KRW"),
+    ("KW", "KWD"),
+    ("KY", "KYD"),
+    ("KZ", "KZT"),
+    ("LA", "LAK"),
+    ("LB", "LBP"),
+    ("LC", "XCD"),
+    ("LI", "CHF"),
+    ("LK", "LKR"),
+    ("LR", "LRD"),
+    ("LS", "LSL"),
+    ("LT", "EUR"),
+    ("LU", "EUR"),
+    ("LV", "EUR"),
+    ("LY", "LYD"),
+    ("MA", "MAD"),
+    ("MC", "EUR"),
+    ("MD", "MDL"),
+    ("ME", "EUR"),
+    ("MF", "EUR"),
+    ("MG", "MGA"),
+    ("MH", "USD"),
+    ("MK", "MKD"),
+    ("ML", "XOF"),
+    ("MM", "MMK"),
+    ("MN", "MNT"),
+    ("MO", "MOP"),
+    ("MP", "USD"),
+    ("MQ", "EUR"),
+    ("MR", "MRU"),
+    ("MS", "XCD"),
+    ("MT", "EUR"),
+    ("MU", "MUR"),
+    ("MV", "MVR"),
+    ("MW", "MWK"),
+    ("MX", "MXN"),
+    ("MY", "MYR"),
+    ("MZ", "MZN"),
+    ("NA", "NAD"),
+    ("NC", "XPF"),
+    ("NE", "XOF"),
+    ("NF", "AUD"),
+    ("NG", "NGN"),
+    ("NI", "NIO"),
+    ("NL", "EUR"),
+    ("NO", "NOK"),
+    ("NP", "NPR"),
+    ("NR", "AUD"),
+    ("NU", "NZD"),
+    ("NZ", "NZD"),
+    ("OM", "OMR"),
+    ("PA", "PAB"),
+    ("PE", "PEN"),
+    ("PF", "XPF"),
+    ("PG", "PGK"),
+    ("PH", "PHP"),
+    ("PK", "PKR"),
+    ("PL", "PLN"),
+    ("PM", "EUR"),
+    ("PN", "NZD"),
+    ("PR", "USD"),
+    ("PS", "ILS"),
+    ("PT", "EUR"),
+    ("PW", "USD"),
+    ("PY", "PYG"),
+    ("QA", "QAR"),
+    ("RE", "EUR"),
+    ("RO", "RON"),
+    ("RS", "RSD"),
+    ("RU", "RUB"),
+    ("RW", "RWF"),
+    ("SA", "SAR"),
+    ("SB", "SBD"),
+    ("SC", "SCR"),
+    ("SD", "SDG"),
+    ("SE", "SEK"),
+    ("SG", "SGD"),
+    ("SH", "SHP"),
+    ("SI", "EUR"),
+    ("SJ", "NOK"),
+    ("SK", "EUR"),
+    ("SL", "SLE"),
+    ("SM", "EUR"),
+    ("SN", "XOF"),
+    ("SO", "SOS"),
+    ("SR", "SRD"),
+    ("SS", "SSP"),
+    ("ST", "STN"),
+    ("SV", "USD"),
+    ("SX", "ANG"),
+    ("SY", "SYP"),
+    ("SZ", "SZL"),
+    ("TC", "USD"),
+    ("TD", "XAF"),
+    ("TF", "EUR"),
+    ("TG", "XOF"),
+    ("TH", "THB"),
+    ("TJ", "TJS"),
+    ("TK", "NZD"),
+    ("TL", "USD"),
+    ("TM", "TMT"),
+    ("TN", "TND"),
+    ("TO", "TOP"),
+    ("TR", "TRY"),
+    ("TT", "TTD"),
+    ("TV", "AUD"),
+    ("TW", "TWD"),
+    ("TZ", "TZS"),
+    ("UA", "UAH"),
+    ("UG", "UGX"),
+    ("UM", "USD"),
+    ("US", "USD"),
+    ("UY", "UYU"),
+    ("UZ", "UZS"),
+    ("VA", "EUR"),
+    ("VC", "XCD"),
+    ("VE", "VES"),
+    ("VG", "USD"),
+    ("VI", "USD"),
+    ("VN", "VND"),
+    ("VU", "VUV"),
+    ("WF", "XPF"),
+    ("WS", "WST"),
+    ("YE", "YER"),
+    ("YT", "EUR"),
+    ("ZA", "ZAR"),
+    ("ZM", "ZMW"),
+    ("ZW", "ZWL"),
+];
+
+/// Returns the currency used in the country identified by the provided
+/// ISO 3166-1 alpha-2 code (e.g. `"CH"`), or `None` if the country is
+/// unknown. The lookup is case insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{currency_for_country, Currency};
+///
+/// assert_eq!(currency_for_country("CH"), Some(Currency::CHF));
+/// assert_eq!(currency_for_country("de"), Some(Currency::EUR));
+/// assert_eq!(currency_for_country("XX"), None);
+/// ```
+pub fn currency_for_country(country: &str) -> Option<Currency> {
+    COUNTRY_CURRENCIES
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(country))
+        .and_then(|(_, currency)| Currency::from_code(currency))
+}
+
+/// Returns the default currency for the provided locale, such as `"de-CH"`,
+/// `"en_US"`, `"fr-Latn-CA"` or `"pt_BR.UTF-8"`, based on its region. Returns
+/// `None` if the locale does not specify a known region.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{default_currency_for_locale, Currency};
+///
+/// assert_eq!(default_currency_for_locale("de-CH"), Some(Currency::CHF));
+/// assert_eq!(default_currency_for_locale("en_US.UTF-8"), Some(Currency::USD));
+/// assert_eq!(default_currency_for_locale("de"), None);
+/// ```
+pub fn default_currency_for_locale(locale: &str) -> Option<Currency> {
+    let locale = locale.split(['.', '@']).next().unwrap_or_default();
+    locale
+        .split(['-', '_'])
+        .skip(1)
+        .find(|subtag| subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+        .and_then(currency_for_country)
+}
+
+impl Amount {
+    /// Creates an amount of `value` in the currency used in the country
+    /// identified by the provided ISO 3166-1 alpha-2 code (see
+    /// [`currency_for_country`]), or returns `None` if the country is
+    /// unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Decimal};
+    /// use oxydized_money_macros::{dec, jpy};
+    ///
+    /// assert_eq!(Amount::new_in_country(dec!(500), "JP"), Some(jpy!(500)));
+    /// assert_eq!(Amount::new_in_country(dec!(500), "XX"), None);
+    /// ```
+    pub fn new_in_country(value: Decimal, country: &str) -> Option<Amount> {
+        currency_for_country(country).map(|currency| Amount(value, currency))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::COUNTRY_CURRENCIES;
+    use crate as oxydized_money;
+    use oxydized_money::{
+        currency_for_country, default_currency_for_locale, Amount, Currency, Currency::*, Decimal,
+    };
+    use oxydized_money_macros::{chf, dec};
+
+    #[test]
+    fn test_currency_for_country() {
+        assert_eq!(currency_for_country("US"), Some(USD));
+        assert_eq!(currency_for_country("gb"), Some(GBP));
+        assert_eq!(currency_for_country("FR"), Some(EUR));
+        assert_eq!(currency_for_country("LI"), Some(CHF));
+        assert_eq!(currency_for_country("GL"), Some(DKK));
+        assert_eq!(currency_for_country("BG"), Some(EUR));
+        assert_eq!(currency_for_country(""), None);
+        assert_eq!(currency_for_country("USA"), None);
+    }
+
+    #[test]
+    fn test_default_currency_for_locale() {
+        assert_eq!(default_currency_for_locale("fr-CH"), Some(CHF));
+        assert_eq!(default_currency_for_locale("fr_FR"), Some(EUR));
+        assert_eq!(default_currency_for_locale("de-Latn-DE"), Some(EUR));
+        assert_eq!(default_currency_for_locale("zh-Hant-HK"), Some(HKD));
+        assert_eq!(default_currency_for_locale("pt_BR.UTF-8"), Some(BRL));
+        assert_eq!(default_currency_for_locale("sv_SE@euro"), Some(SEK));
+        assert_eq!(default_currency_for_locale("es-419"), None);
+        assert_eq!(default_currency_for_locale("C"), None);
+        assert_eq!(default_currency_for_locale(""), None);
+    }
+
+    #[test]
+    fn test_new_in_country() {
+        assert_eq!(Amount::new_in_country(dec!(10), "ch"), Some(chf!(10)));
+        assert_eq!(Amount::new_in_country(dec!(10), "??"), None);
+    }
+
+    #[test]
+    fn test_known_currencies() {
+        for (country, code) in COUNTRY_CURRENCIES {
+            assert!(
+                Currency::from_code(code).is_some(),
+                "unknown currency {code} for {country}"
+            );
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::Currency;
+use core::{fmt::Debug, fmt::Display, hash::Hash};
+
+/// `CurrencyLike` is implemented by all the types that can be used as
+/// the currency part of an [`Amount`](crate::Amount).
+///
+/// By default, amounts are measured in ISO 4217 [`Currency`]s, but this
+/// trait allows the crate to be used with currencies that are not part of
+/// that standard, such as loyalty points, in-game gold or internal
+/// settlement units. All the arithmetic operations, including the currency
+/// checks, are available for such currencies.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, AmountResult, CurrencyError, CurrencyLike, Decimal};
+/// use oxydized_money_macros::dec;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// enum Reward {
+///     Points,
+///     Miles,
+/// }
+///
+/// impl CurrencyLike for Reward {
+///     fn code(&self) -> &'static str {
+///         match self {
+///             Reward::Points => "PTS",
+///             Reward::Miles => "MLS",
+///         }
+///     }
+///
+///     fn exponent(&self) -> Option<u16> {
+///         Some(0)
+///     }
+/// }
+///
+/// let points = Amount(dec!(100), Reward::Points);
+/// let miles = Amount(dec!(250), Reward::Miles);
+/// assert_eq!(points + points, Amount(dec!(200), Reward::Points));
+/// assert_eq!(points + miles, CurrencyError::Mismatch(Reward::Points, Reward::Miles));
+/// assert_eq!(format!("{}", points), "PTS 100.00");
+/// ```
+pub trait CurrencyLike: Copy + Eq + Hash + Debug {
+    /// Returns the code identifying the currency, e.g. `"EUR"`.
+    fn code(&self) -> &'static str;
+
+    /// Returns the symbol used to display amounts in this currency,
+    /// e.g. `"€"`. Defaults to the [code](CurrencyLike::code) of the currency.
+    fn symbol(&self) -> impl Display {
+        self.code()
+    }
+
+    /// Returns the number of decimal places used by the currency, or `None`
+    /// if the currency does not define one. Defaults to `None`.
+    fn exponent(&self) -> Option<u16> {
+        None
+    }
+}
+
+impl CurrencyLike for Currency {
+    fn code(&self) -> &'static str {
+        Currency::code(*self)
+    }
+
+    fn symbol(&self) -> impl Display {
+        Currency::symbol(*self)
+    }
+
+    fn exponent(&self) -> Option<u16> {
+        Currency::exponent(*self)
+    }
+}
+
+/// Parses a currency from its alphabetic code (e.g. `"EUR"`) or from its
+/// numeric code (e.g. `"978"`).
+#[cfg(any(
+    feature = "with_serde",
+    feature = "async-graphql",
+    feature = "ffi",
+    feature = "juniper",
+    feature = "rusqlite",
+    feature = "sea-query",
+    feature = "sqlx-sqlite"
+))]
+pub(crate) fn parse_code(code: &str) -> Option<Currency> {
+    match code.parse::<u16>() {
+        Ok(numeric) => Currency::from_numeric(numeric),
+        Err(_) => Currency::from_code(code),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, CurrencyError, CurrencyLike, Decimal};
+    use oxydized_money_macros::{dec, eur};
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    enum Game {
+        Gold,
+        Gems,
+    }
+
+    impl CurrencyLike for Game {
+        fn code(&self) -> &'static str {
+            match self {
+                Game::Gold => "GLD",
+                Game::Gems => "GEM",
+            }
+        }
+    }
+
+    #[test]
+    fn test_iso() {
+        assert_eq!(CurrencyLike::code(&EUR), "EUR");
+        assert_eq!(CurrencyLike::symbol(&EUR).to_string(), "€");
+        assert_eq!(CurrencyLike::exponent(&EUR), Some(2));
+        assert_eq!(CurrencyLike::exponent(&JPY), Some(0));
+    }
+
+    #[test]
+    fn test_custom() {
+        let gold = |value| Amount(value, Game::Gold);
+        let gems = |value| Amount(value, Game::Gems);
+        assert_eq!(gold(dec!(3)) + gold(dec!(4)), gold(dec!(7)));
+        assert_eq!(gold(dec!(3)) - gold(dec!(4)) + gold(dec!(2)), gold(dec!(1)));
+        assert_eq!(gold(dec!(3)) * dec!(2), gold(dec!(6)));
+        assert_eq!(gold(dec!(3)) / dec!(0), CurrencyError::DivideByZero);
+        assert_eq!(
+            gold(dec!(3)) + gems(dec!(4)),
+            CurrencyError::Mismatch(Game::Gold, Game::Gems)
+        );
+        assert_eq!(format!("{}", gold(dec!(3))), "GLD 3.00");
+        assert_eq!(
+            format!("{}", CurrencyError::Mismatch(Game::Gold, Game::Gems)),
+            "mismatch currency 'GLD' and 'GEM'"
+        );
+        assert_eq!(
+            gold(dec!(1.234)).rounded(oxydized_money::RoundingStrategy::ToZero),
+            gold(dec!(1.234))
+        );
+        assert!(gold(dec!(1)) < gold(dec!(2)));
+        assert_eq!(
+            [gold(dec!(1)), gold(dec!(2))]
+                .iter()
+                .sum::<oxydized_money::AmountResult<_>>(),
+            gold(dec!(3))
+        );
+        assert_eq!(eur!(1) + eur!(1), eur!(2));
+    }
+}
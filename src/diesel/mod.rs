@@ -0,0 +1,99 @@
+//! Integration with `diesel`, for the `diesel-postgres` and `diesel-mysql`
+//! features.
+
+#[cfg(feature = "diesel-postgres")]
+mod postgres;
+
+#[cfg(feature = "diesel-postgres")]
+pub use postgres::MoneyAmount;
+
+use crate::{Amount, Currency, Decimal};
+use ::diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, Queryable},
+    sql_types::{Numeric, Text},
+};
+
+/// With the `diesel-postgres` and `diesel-mysql` features, amounts are
+/// loaded from a `NUMERIC` (or `DECIMAL(p,s)`) and a `TEXT` (or `CHAR(3)`)
+/// column holding their value and their currency code, e.g. as a field of a
+/// structure deriving `Queryable`:
+///
+/// ```ignore
+/// diesel::table! {
+///     invoices (id) {
+///         id -> Integer,
+///         total_value -> Numeric,
+///         total_currency -> Text,
+///     }
+/// }
+///
+/// #[derive(Queryable)]
+/// struct Invoice {
+///     id: i32,
+///     total: Amount,
+/// }
+///
+/// let invoices: Vec<Invoice> = invoices::table
+///     .select((invoices::id, (invoices::total_value, invoices::total_currency)))
+///     .load(&mut connection)?;
+/// ```
+///
+/// They are stored by binding their value and their currency code to the
+/// two columns:
+///
+/// ```ignore
+/// let total = eur!(10.50);
+/// diesel::insert_into(invoices::table)
+///     .values((
+///         invoices::id.eq(1),
+///         invoices::total_value.eq(total.value()),
+///         invoices::total_currency.eq(total.currency().code()),
+///     ))
+///     .execute(&mut connection)?;
+/// ```
+///
+/// With PostgreSQL, amounts can also be stored in a single column of a
+/// composite type, see [`MoneyAmount`](crate::MoneyAmount).
+impl<DB> Queryable<(Numeric, Text), DB> for Amount
+where
+    DB: Backend,
+    Decimal: FromSql<Numeric, DB>,
+    String: FromSql<Text, DB>,
+{
+    type Row = (Decimal, String);
+
+    fn build((value, code): Self::Row) -> deserialize::Result<Self> {
+        Ok(Amount(value, currency(&code)?))
+    }
+}
+
+fn currency(code: &str) -> deserialize::Result<Currency> {
+    Currency::from_code(code.trim()).ok_or_else(|| format!("unknown currency '{}'", code).into())
+}
+
+#[cfg(all(test, feature = "diesel-postgres"))]
+mod test {
+    use crate as oxydized_money;
+    use diesel::{
+        deserialize::Queryable,
+        pg::Pg,
+        sql_types::{Numeric, Text},
+    };
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy};
+
+    fn build(value: Decimal, code: &str) -> diesel::deserialize::Result<Amount> {
+        <Amount as Queryable<(Numeric, Text), Pg>>::build((value, code.to_owned()))
+    }
+
+    #[test]
+    fn test_queryable() {
+        assert_eq!(build(dec!(10.50), "EUR").unwrap(), eur!(10.50));
+        assert_eq!(build(dec!(-1200), "JPY ").unwrap(), jpy!(-1200));
+        assert_eq!(
+            build(dec!(1), "XYZ").unwrap_err().to_string(),
+            "unknown currency 'XYZ'"
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use super::currency;
+use crate::{Amount, Decimal};
+use ::diesel::{
+    deserialize::{self, FromSql, Queryable},
+    pg::{sql_types::Record, Pg, PgValue},
+    query_builder::QueryId,
+    serialize::{self, Output, ToSql, WriteTuple},
+    sql_types::{Numeric, SqlType, Text},
+};
+
+/// `MoneyAmount` is the SQL type of [amounts](Amount) stored in a single
+/// PostgreSQL column of the `money_amount` composite type, with the
+/// `diesel-postgres` feature:
+///
+/// ```sql
+/// CREATE TYPE money_amount AS (value NUMERIC, currency CHAR(3));
+/// ```
+///
+/// Columns of that type are declared with `MoneyAmount` in the schema, and
+/// amounts can then be loaded from and bound to them directly:
+///
+/// ```ignore
+/// diesel::table! {
+///     use diesel::sql_types::*;
+///     use oxydized_money::MoneyAmount;
+///
+///     invoices (id) {
+///         id -> Integer,
+///         total -> MoneyAmount,
+///     }
+/// }
+///
+/// diesel::insert_into(invoices::table)
+///     .values((invoices::id.eq(1), invoices::total.eq(eur!(10.50))))
+///     .execute(&mut connection)?;
+/// let total: Amount = invoices::table
+///     .select(invoices::total)
+///     .find(1)
+///     .first(&mut connection)?;
+/// ```
+#[derive(Clone, Copy, Debug, Default, SqlType, QueryId)]
+#[diesel(postgres_type(name = "money_amount"))]
+pub struct MoneyAmount;
+
+impl ToSql<MoneyAmount, Pg> for Amount {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        WriteTuple::<(Numeric, Text)>::write_tuple(&(self.value(), self.currency().code()), out)
+    }
+}
+
+impl FromSql<MoneyAmount, Pg> for Amount {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let (value, code) =
+            <(Decimal, String) as FromSql<Record<(Numeric, Text)>, Pg>>::from_sql(bytes)?;
+        Ok(Amount(value, currency(&code)?))
+    }
+}
+
+impl Queryable<MoneyAmount, Pg> for Amount {
+    type Row = Self;
+
+    fn build(row: Self) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MoneyAmount;
+    use crate as oxydized_money;
+    use diesel::{debug_query, pg::Pg, IntoSql};
+    use oxydized_money::Decimal;
+    use oxydized_money_macros::eur;
+
+    #[test]
+    fn test_bind() {
+        let query = diesel::select(eur!(10.50).into_sql::<MoneyAmount>());
+        assert_eq!(
+            debug_query::<Pg, _>(&query).to_string(),
+            "SELECT $1 -- binds: [Amount(10.50, EUR)]"
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use crate::{Amount, Currency, Decimal};
+use core::fmt::Display;
+
+const VERSION: u8 = 1;
+const SIGN_MASK: u32 = 0x8000_0000;
+const SCALE_SHIFT: u32 = 16;
+const MAX_SCALE: u32 = 28;
+const POSITIVE: u8 = 0x01;
+const NEGATIVE: u8 = 0x00;
+
+/// `BinaryAmountError` represents the reasons why bytes are not a valid
+/// binary encoding of an [`Amount`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BinaryAmountError {
+    /// The bytes do not have the length of the encoding.
+    InvalidLength(usize),
+
+    /// The version tag of the encoding is not supported.
+    UnsupportedVersion(u8),
+
+    /// The encoded value is malformed or does not fit in a [`Decimal`].
+    InvalidValue,
+
+    /// The currency is not a known ISO 4217 numeric code.
+    UnknownCurrency(u16),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BinaryAmountError {}
+
+impl Display for BinaryAmountError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use BinaryAmountError::*;
+        match self {
+            InvalidLength(len) => write!(f, "invalid encoded amount length {}", len),
+            UnsupportedVersion(version) => {
+                write!(f, "unsupported encoded amount version {}", version)
+            }
+            InvalidValue => write!(f, "invalid encoded amount value"),
+            UnknownCurrency(code) => write!(f, "unknown currency numeric code {}", code),
+        }
+    }
+}
+
+impl Amount {
+    /// Number of bytes of the binary encoding of an amount, see
+    /// [`Amount::to_bytes`].
+    pub const ENCODED_LEN: usize = 19;
+
+    /// Encodes the amount in a compact binary layout, independent of any
+    /// serialization framework, so that it can be decoded by services
+    /// written in any language. All the integers are big endian:
+    ///
+    /// | Bytes   | Content                                                        |
+    /// |---------|----------------------------------------------------------------|
+    /// | 0       | version tag, currently `1`                                     |
+    /// | 1..5    | flags (`u32`): bits 16 to 23 hold the scale, bit 31 the sign   |
+    /// | 5..17   | coefficient of the value (96 bits unsigned integer)            |
+    /// | 17..19  | ISO 4217 numeric code of the currency (`u16`)                  |
+    ///
+    /// The value is the coefficient divided by 10 to the power of the scale,
+    /// which is at most 28, negated if the sign bit is set. This is the same
+    /// representation as the .NET `System.Decimal` type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// let bytes = eur!(-10.50).to_bytes();
+    /// assert_eq!(bytes[0], 1);
+    /// assert_eq!(bytes[1..5], [0x80, 0x02, 0x00, 0x00]);
+    /// assert_eq!(bytes[15..17], 1050u16.to_be_bytes());
+    /// assert_eq!(bytes[17..19], 978u16.to_be_bytes());
+    /// assert_eq!(Amount::from_bytes(&bytes), Ok(eur!(-10.50)));
+    /// ```
+    pub fn to_bytes(&self) -> [u8; Amount::ENCODED_LEN] {
+        let value = self.value();
+        let mut flags = value.scale() << SCALE_SHIFT;
+        if value.is_sign_negative() {
+            flags |= SIGN_MASK;
+        }
+        let coefficient = value.mantissa().unsigned_abs().to_be_bytes();
+        let mut bytes = [0; Amount::ENCODED_LEN];
+        bytes[0] = VERSION;
+        bytes[1..5].copy_from_slice(&flags.to_be_bytes());
+        bytes[5..17].copy_from_slice(&coefficient[4..]);
+        bytes[17..19].copy_from_slice(&self.currency().numeric().to_be_bytes());
+        bytes
+    }
+
+    /// Decodes an amount encoded by [`Amount::to_bytes`], keeping the scale
+    /// of its value.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Amount, BinaryAmountError> {
+        let bytes: &[u8; Amount::ENCODED_LEN] = bytes
+            .try_into()
+            .map_err(|_| BinaryAmountError::InvalidLength(bytes.len()))?;
+        if bytes[0] != VERSION {
+            return Err(BinaryAmountError::UnsupportedVersion(bytes[0]));
+        }
+        let flags = u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+        let scale = (flags >> SCALE_SHIFT) & 0xff;
+        if flags & !(SIGN_MASK | 0xff << SCALE_SHIFT) != 0 || scale > MAX_SCALE {
+            return Err(BinaryAmountError::InvalidValue);
+        }
+        let word =
+            |i: usize| u32::from_be_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+        let value = Decimal::from_parts(word(13), word(9), word(5), flags & SIGN_MASK != 0, scale);
+        let code = u16::from_be_bytes([bytes[17], bytes[18]]);
+        let currency =
+            Currency::from_numeric(code).ok_or(BinaryAmountError::UnknownCurrency(code))?;
+        Ok(Amount(value, currency))
+    }
+}
+
+impl Amount {
+    /// Number of bytes of the order-preserving encoding of an amount, see
+    /// [`Amount::to_ordered_bytes`].
+    pub const ORDERED_LEN: usize = 27;
+
+    /// Encodes the amount so that the lexicographic order of the encodings
+    /// of amounts in the same currency matches the numeric order of their
+    /// values, e.g. to use amounts as keys of key-value stores supporting
+    /// range scans. All the integers are big endian:
+    ///
+    /// | Bytes   | Content                                                        |
+    /// |---------|----------------------------------------------------------------|
+    /// | 0..2    | ISO 4217 numeric code of the currency (`u16`)                  |
+    /// | 2       | sign: `0` for negative values, `1` for zero and positive ones  |
+    /// | 3..27   | magnitude of the value times 10^28 (192 bits unsigned integer) |
+    ///
+    /// The magnitude of negative values is bitwise inverted so that larger
+    /// magnitudes sort first. Amounts are grouped by currency, and values
+    /// that are equal but have different scales (e.g. `10.5` and `10.50`)
+    /// have the same encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Decimal};
+    /// use oxydized_money_macros::eur;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut transactions = BTreeMap::new();
+    /// for (id, amount) in [(1, eur!(250)), (2, eur!(-300)), (3, eur!(99.99)), (4, eur!(100.01))] {
+    ///     transactions.insert(amount.to_ordered_bytes(), id);
+    /// }
+    ///
+    /// // all transactions over 100 EUR
+    /// let over = eur!(100).to_ordered_bytes();
+    /// let ids: Vec<_> = transactions.range(over..).map(|(_, id)| *id).collect();
+    /// assert_eq!(ids, [4, 1]);
+    /// ```
+    pub fn to_ordered_bytes(&self) -> [u8; Amount::ORDERED_LEN] {
+        let value = self.value();
+        let mut magnitude = to_limbs(value.mantissa().unsigned_abs());
+        for _ in value.scale()..MAX_SCALE {
+            mul_10(&mut magnitude);
+        }
+        let negative = value.is_sign_negative() && !value.is_zero();
+        let mut bytes = [0; Amount::ORDERED_LEN];
+        bytes[0..2].copy_from_slice(&self.currency().numeric().to_be_bytes());
+        bytes[2] = if negative { NEGATIVE } else { POSITIVE };
+        for (i, limb) in magnitude.iter().enumerate() {
+            let limb = if negative { !limb } else { *limb };
+            bytes[3 + 8 * i..11 + 8 * i].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes an amount encoded by [`Amount::to_ordered_bytes`]. The
+    /// encoding does not keep the scale of the value, so the decoded value
+    /// has no trailing zeros, e.g. `10.50` is decoded as `10.5`.
+    pub fn from_ordered_bytes(bytes: &[u8]) -> Result<Amount, BinaryAmountError> {
+        let bytes: &[u8; Amount::ORDERED_LEN] = bytes
+            .try_into()
+            .map_err(|_| BinaryAmountError::InvalidLength(bytes.len()))?;
+        let code = u16::from_be_bytes([bytes[0], bytes[1]]);
+        let currency =
+            Currency::from_numeric(code).ok_or(BinaryAmountError::UnknownCurrency(code))?;
+        let negative = match bytes[2] {
+            NEGATIVE => true,
+            POSITIVE => false,
+            _ => return Err(BinaryAmountError::InvalidValue),
+        };
+        let mut magnitude = [0; 3];
+        for (i, limb) in magnitude.iter_mut().enumerate() {
+            let mut word = [0; 8];
+            word.copy_from_slice(&bytes[3 + 8 * i..11 + 8 * i]);
+            *limb = u64::from_be_bytes(word);
+            if negative {
+                *limb = !*limb;
+            }
+        }
+        let mut scale = MAX_SCALE;
+        while scale > 0 && rem_10(&magnitude) == 0 {
+            div_10(&mut magnitude);
+            scale -= 1;
+        }
+        let [high, middle, low] = magnitude;
+        if high != 0 || middle >> 32 != 0 || (negative && magnitude == [0; 3]) {
+            return Err(BinaryAmountError::InvalidValue);
+        }
+        let mantissa = (u128::from(middle) << 64 | u128::from(low)) as i128;
+        let mantissa = if negative { -mantissa } else { mantissa };
+        Ok(Amount(
+            Decimal::from_i128_with_scale(mantissa, scale),
+            currency,
+        ))
+    }
+}
+
+/// Splits a 96 bits integer in three 64 bits limbs, most significant first.
+fn to_limbs(value: u128) -> [u64; 3] {
+    [0, (value >> 64) as u64, value as u64]
+}
+
+fn mul_10(limbs: &mut [u64; 3]) {
+    let mut carry = 0;
+    for limb in limbs.iter_mut().rev() {
+        let product = u128::from(*limb) * 10 + carry;
+        *limb = product as u64;
+        carry = product >> 64;
+    }
+}
+
+fn div_10(limbs: &mut [u64; 3]) {
+    let mut remainder = 0;
+    for limb in limbs.iter_mut() {
+        let dividend = remainder << 64 | u128::from(*limb);
+        *limb = (dividend / 10) as u64;
+        remainder = dividend % 10;
+    }
+}
+
+fn rem_10(limbs: &[u64; 3]) -> u128 {
+    limbs.iter().fold(0, |remainder, limb| {
+        (remainder << 64 | u128::from(*limb)) % 10
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::BinaryAmountError::*;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy};
+
+    #[test]
+    fn test_to_bytes() {
+        assert_eq!(
+            eur!(10.50).to_bytes(),
+            [1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x04, 0x1a, 0x03, 0xd2]
+        );
+        assert_eq!(
+            jpy!(-1).to_bytes(),
+            [1, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0x01, 0x88]
+        );
+        let bytes = Amount(Decimal::MAX, USD).to_bytes();
+        assert_eq!(bytes[1..5], [0, 0, 0, 0]);
+        assert_eq!(bytes[5..17], [0xff; 12]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for amount in [
+            eur!(10.50),
+            eur!(-0.001),
+            jpy!(1200),
+            Amount(Decimal::MAX, USD),
+            Amount(Decimal::MIN, USD),
+            Amount(dec!(0.0000000000000000000000000001), EUR),
+        ] {
+            let decoded = Amount::from_bytes(&amount.to_bytes()).unwrap();
+            assert_eq!(decoded, amount);
+            assert_eq!(decoded.value().scale(), amount.value().scale());
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_errors() {
+        let bytes = eur!(10.50).to_bytes();
+        assert_eq!(Amount::from_bytes(&bytes[..18]), Err(InvalidLength(18)));
+        assert_eq!(Amount::from_bytes(&[0; 20]), Err(InvalidLength(20)));
+
+        let mut invalid = bytes;
+        invalid[0] = 2;
+        assert_eq!(Amount::from_bytes(&invalid), Err(UnsupportedVersion(2)));
+
+        let mut invalid = bytes;
+        invalid[2] = 29;
+        assert_eq!(Amount::from_bytes(&invalid), Err(InvalidValue));
+
+        let mut invalid = bytes;
+        invalid[4] = 1;
+        assert_eq!(Amount::from_bytes(&invalid), Err(InvalidValue));
+
+        let mut invalid = bytes;
+        invalid[17..19].copy_from_slice(&1u16.to_be_bytes());
+        assert_eq!(Amount::from_bytes(&invalid), Err(UnknownCurrency(1)));
+    }
+
+    #[test]
+    fn test_to_ordered_bytes() {
+        let bytes = eur!(1).to_ordered_bytes();
+        assert_eq!(bytes[0..2], 978u16.to_be_bytes());
+        assert_eq!(bytes[2], 1);
+        assert_eq!(
+            bytes[15..27],
+            [0x20, 0x4f, 0xce, 0x5e, 0x3e, 0x25, 0x02, 0x61, 0x10, 0, 0, 0]
+        );
+        let bytes = eur!(-1).to_ordered_bytes();
+        assert_eq!(bytes[2], 0);
+        assert_eq!(bytes[3..15], [0xff; 12]);
+        assert_eq!(
+            eur!(10.5).to_ordered_bytes(),
+            eur!(10.50).to_ordered_bytes()
+        );
+        let zero = eur!(0).to_ordered_bytes();
+        assert_eq!(zero[2], 1);
+        assert_eq!(zero[3..], [0; 24]);
+    }
+
+    #[test]
+    fn test_ordered_bytes_order() {
+        let values = [
+            Decimal::MIN,
+            dec!(-1000),
+            dec!(-100.01),
+            dec!(-100),
+            dec!(-1),
+            dec!(-0.0000000000000000000000000001),
+            dec!(0),
+            dec!(0.0000000000000000000000000001),
+            dec!(0.5),
+            dec!(1),
+            dec!(99.99),
+            dec!(100),
+            dec!(100.001),
+            dec!(1000000),
+            Decimal::MAX,
+        ];
+        let encoded: Vec<_> = values
+            .iter()
+            .map(|value| Amount(*value, EUR).to_ordered_bytes())
+            .collect();
+        assert!(encoded.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_ordered_bytes_round_trip() {
+        for amount in [
+            eur!(10.5),
+            eur!(-0.001),
+            eur!(0),
+            jpy!(1200),
+            jpy!(-1200),
+            Amount(Decimal::MAX, USD),
+            Amount(Decimal::MIN, USD),
+            Amount(dec!(0.0000000000000000000000000001), EUR),
+            Amount(dec!(-0.0000000000000000000000000001), EUR),
+        ] {
+            assert_eq!(
+                Amount::from_ordered_bytes(&amount.to_ordered_bytes()),
+                Ok(amount)
+            );
+        }
+        let decoded = Amount::from_ordered_bytes(&eur!(10.50).to_ordered_bytes()).unwrap();
+        assert_eq!(decoded.value().scale(), 1);
+        let decoded = Amount::from_ordered_bytes(&jpy!(1200).to_ordered_bytes()).unwrap();
+        assert_eq!(decoded.value().scale(), 0);
+    }
+
+    #[test]
+    fn test_from_ordered_bytes_errors() {
+        let bytes = eur!(10.50).to_ordered_bytes();
+        assert_eq!(
+            Amount::from_ordered_bytes(&bytes[..26]),
+            Err(InvalidLength(26))
+        );
+
+        let mut invalid = bytes;
+        invalid[2] = 2;
+        assert_eq!(Amount::from_ordered_bytes(&invalid), Err(InvalidValue));
+
+        let mut invalid = bytes;
+        invalid[3] = 0xff;
+        assert_eq!(Amount::from_ordered_bytes(&invalid), Err(InvalidValue));
+
+        let mut invalid = bytes;
+        invalid[2] = 0;
+        invalid[3..].fill(0xff);
+        assert_eq!(Amount::from_ordered_bytes(&invalid), Err(InvalidValue));
+
+        let mut invalid = bytes;
+        invalid[0..2].copy_from_slice(&1u16.to_be_bytes());
+        assert_eq!(
+            Amount::from_ordered_bytes(&invalid),
+            Err(UnknownCurrency(1))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            InvalidLength(3).to_string(),
+            "invalid encoded amount length 3"
+        );
+        assert_eq!(
+            UnsupportedVersion(2).to_string(),
+            "unsupported encoded amount version 2"
+        );
+        assert_eq!(
+            UnknownCurrency(1).to_string(),
+            "unknown currency numeric code 1"
+        );
+    }
+}
@@ -1,15 +1,51 @@
-use crate::Currency;
-use std::{error::Error, fmt::Display};
+use crate::{Currency, CurrencyLike};
+use core::fmt::Display;
 
 /// `CurrencyError` represents all currency error that can occur during
 /// arithmetic operations with [`Amount`](crate::Amount) or
 /// [`AmounrResult`](crate::AmountResult).
 ///
+/// The currency type `C` defaults to [`Currency`] in type annotations, but
+/// not in expressions: the variants without any currency, such as
+/// `Unknown`, need it to be known from the context, e.g. with
+/// `let error: CurrencyError = CurrencyError::Unknown;` or
+/// `CurrencyError::<Currency>::Unknown`.
+///
+/// This enum is non-exhaustive: new variants may be added in future
+/// releases, so matches outside of this crate must include a wildcard arm.
+///
+/// With the `with_serde` feature, errors are serialized as an internally
+/// tagged structure whose `type` field identifies the error, e.g.
+/// `{"type":"mismatch","left":"EUR","right":"USD"}`. This format is stable
+/// and does not depend on the layout of the enum:
+///
+/// | Error                   | Serialized as                                          |
+/// |-------------------------|--------------------------------------------------------|
+/// | `Mismatch(EUR, USD)`    | `{"type":"mismatch","left":"EUR","right":"USD"}`       |
+/// | `DivideByZero`          | `{"type":"divide_by_zero"}`                            |
+/// | `Unknown`               | `{"type":"unknown"}`                                   |
+/// | `MissingRate(EUR, USD)` | `{"type":"missing_rate","from":"EUR","to":"USD"}`      |
+/// | `Overflow`              | `{"type":"overflow"}`                                  |
+/// | `InvalidPrecision(EUR)` | `{"type":"invalid_precision","currency":"EUR"}`        |
+///
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
-pub enum CurrencyError {
+#[cfg_attr(
+    feature = "with_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        from = "CurrencyErrorRepr<C>",
+        into = "CurrencyErrorRepr<C>",
+        bound(
+            serialize = "C: Clone + serde::Serialize",
+            deserialize = "C: serde::Deserialize<'de>"
+        )
+    )
+)]
+#[non_exhaustive]
+pub enum CurrencyError<C = Currency> {
     /// Error that occurs if one tries to perform arithmetic operations
     /// on amounts from differenc currencies.
-    Mismatch(Currency, Currency),
+    Mismatch(C, C),
 
     /// Error that occurs if one tries to divide an [`Amount`](crate::Amount) or
     /// [`AmounrResult`](crate::AmountResult)  by zero.
@@ -18,32 +54,161 @@ pub enum CurrencyError {
     /// Error that occurs if one tries to perform a [`sum`](std::iter::Sum)
     /// on an empty collection of [`Amount`](crate::Amount)s.
     Unknown,
+
+    /// Error that occurs if one tries to convert an [`Amount`](crate::Amount)
+    /// between two currencies for which no [`ExchangeRate`](crate::ExchangeRate)
+    /// is available.
+    MissingRate(C, C),
+
+    /// Error that occurs if the result of an arithmetic operation does not
+    /// fit in the underlying representation of an amount.
+    Overflow,
+
+    /// Error that occurs if an amount has more decimal places than its
+    /// currency supports where this is not allowed, e.g. when converting
+    /// an [`Amount`](crate::Amount) into a `FastAmount`.
+    InvalidPrecision(C),
+}
+
+#[cfg(feature = "with_serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum CurrencyErrorRepr<C> {
+    Mismatch { left: C, right: C },
+    DivideByZero,
+    Unknown,
+    MissingRate { from: C, to: C },
+    Overflow,
+    InvalidPrecision { currency: C },
+}
+
+#[cfg(feature = "with_serde")]
+impl<C> From<CurrencyErrorRepr<C>> for CurrencyError<C> {
+    fn from(repr: CurrencyErrorRepr<C>) -> Self {
+        match repr {
+            CurrencyErrorRepr::Mismatch { left, right } => CurrencyError::Mismatch(left, right),
+            CurrencyErrorRepr::DivideByZero => CurrencyError::DivideByZero,
+            CurrencyErrorRepr::Unknown => CurrencyError::Unknown,
+            CurrencyErrorRepr::MissingRate { from, to } => CurrencyError::MissingRate(from, to),
+            CurrencyErrorRepr::Overflow => CurrencyError::Overflow,
+            CurrencyErrorRepr::InvalidPrecision { currency } => {
+                CurrencyError::InvalidPrecision(currency)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl<C> From<CurrencyError<C>> for CurrencyErrorRepr<C> {
+    fn from(error: CurrencyError<C>) -> Self {
+        match error {
+            CurrencyError::Mismatch(left, right) => CurrencyErrorRepr::Mismatch { left, right },
+            CurrencyError::DivideByZero => CurrencyErrorRepr::DivideByZero,
+            CurrencyError::Unknown => CurrencyErrorRepr::Unknown,
+            CurrencyError::MissingRate(from, to) => CurrencyErrorRepr::MissingRate { from, to },
+            CurrencyError::Overflow => CurrencyErrorRepr::Overflow,
+            CurrencyError::InvalidPrecision(currency) => {
+                CurrencyErrorRepr::InvalidPrecision { currency }
+            }
+        }
+    }
+}
+
+/// `Operation` identifies the arithmetic operation that produced a
+/// [`CurrencyError`] (see [`AmountResult::operation`](crate::AmountResult::operation)).
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operation {
+    /// The addition of two amounts.
+    Add,
+    /// The subtraction of two amounts.
+    Sub,
+    /// The multiplication of an amount.
+    Mul,
+    /// The division of an amount.
+    Div,
+    /// The [`sum`](std::iter::Sum) of a collection of amounts.
+    Sum,
 }
 
-impl Error for CurrencyError {}
+impl Display for Operation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Operation::Add => write!(f, "addition"),
+            Operation::Sub => write!(f, "subtraction"),
+            Operation::Mul => write!(f, "multiplication"),
+            Operation::Div => write!(f, "division"),
+            Operation::Sum => write!(f, "sum"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: CurrencyLike> std::error::Error for CurrencyError<C> {}
 
-impl From<&CurrencyError> for CurrencyError {
-    fn from(value: &CurrencyError) -> Self {
+impl<C: CurrencyLike> From<&CurrencyError<C>> for CurrencyError<C> {
+    fn from(value: &CurrencyError<C>) -> Self {
         *value
     }
 }
 
-impl From<&mut CurrencyError> for CurrencyError {
-    fn from(value: &mut CurrencyError) -> Self {
+impl<C: CurrencyLike> From<&mut CurrencyError<C>> for CurrencyError<C> {
+    fn from(value: &mut CurrencyError<C>) -> Self {
         *value
     }
 }
 
+/// With the `miette` feature, currency errors are [`miette::Diagnostic`]s
+/// with a code and a help text suggesting how to fix the error.
+#[cfg(feature = "miette")]
+impl<C: CurrencyLike> miette::Diagnostic for CurrencyError<C> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        use CurrencyError::*;
+        let code = match self {
+            Mismatch(_, _) => "oxydized_money::mismatch",
+            DivideByZero => "oxydized_money::divide_by_zero",
+            Unknown => "oxydized_money::unknown",
+            MissingRate(_, _) => "oxydized_money::missing_rate",
+            Overflow => "oxydized_money::overflow",
+            InvalidPrecision(_) => "oxydized_money::invalid_precision",
+        };
+        Some(Box::new(code))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        use CurrencyError::*;
+        let help = match self {
+            Mismatch(_, _) => {
+                "convert one of the operands with `converted_to` or a `Converter` before combining them"
+            }
+            DivideByZero => "check that the divisor is not zero before dividing",
+            Unknown => "use `unwrap_or_zero` or `or_amount` to provide a default for empty sums",
+            MissingRate(_, _) => "add an exchange rate between these currencies to the rate provider",
+            Overflow => "the result does not fit in a `Decimal`, check the magnitude of the operands",
+            InvalidPrecision(_) => "round the amount with `rounded` before the conversion",
+        };
+        Some(Box::new(help))
+    }
+}
+
 /// Type alias for a [`Result`] where the error is [`CurrencyError`]
-pub type Result<T> = std::result::Result<T, CurrencyError>;
+pub type Result<T, C = Currency> = core::result::Result<T, CurrencyError<C>>;
 
-impl Display for CurrencyError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<C: CurrencyLike> Display for CurrencyError<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use CurrencyError::*;
         match self {
             Mismatch(c1, c2) => write!(f, "mismatch currency '{}' and '{}'", c1.code(), c2.code()),
             DivideByZero => write!(f, "divide by zero"),
             Unknown => write!(f, "unknown currency"),
+            MissingRate(c1, c2) => write!(
+                f,
+                "missing exchange rate from '{}' to '{}'",
+                c1.code(),
+                c2.code()
+            ),
+            Overflow => write!(f, "arithmetic overflow"),
+            InvalidPrecision(c) => write!(f, "invalid precision for currency '{}'", c.code()),
         }
     }
 }
@@ -57,8 +222,8 @@ mod test {
         use Currency::{EUR, USD};
         use CurrencyError::*;
 
-        assert_eq!(format!("{}", Unknown), "unknown currency");
-        assert_eq!(format!("{}", DivideByZero), "divide by zero");
+        assert_eq!(format!("{}", Unknown::<Currency>), "unknown currency");
+        assert_eq!(format!("{}", DivideByZero::<Currency>), "divide by zero");
         assert_eq!(
             format!("{}", Mismatch(USD, EUR)),
             "mismatch currency 'USD' and 'EUR'"
@@ -67,5 +232,64 @@ mod test {
             format!("{}", Mismatch(EUR, USD)),
             "mismatch currency 'EUR' and 'USD'"
         );
+        assert_eq!(
+            format!("{}", MissingRate(EUR, USD)),
+            "missing exchange rate from 'EUR' to 'USD'"
+        );
+        assert_eq!(format!("{}", Overflow::<Currency>), "arithmetic overflow");
+        assert_eq!(
+            format!("{}", InvalidPrecision(EUR)),
+            "invalid precision for currency 'EUR'"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with_serde")]
+    fn test_serde() {
+        use Currency::{EUR, USD};
+        use CurrencyError::*;
+
+        let cases = [
+            (
+                Mismatch(EUR, USD),
+                r#"{"type":"mismatch","left":"EUR","right":"USD"}"#,
+            ),
+            (DivideByZero, r#"{"type":"divide_by_zero"}"#),
+            (Unknown, r#"{"type":"unknown"}"#),
+            (
+                MissingRate(EUR, USD),
+                r#"{"type":"missing_rate","from":"EUR","to":"USD"}"#,
+            ),
+            (Overflow, r#"{"type":"overflow"}"#),
+            (
+                InvalidPrecision(EUR),
+                r#"{"type":"invalid_precision","currency":"EUR"}"#,
+            ),
+        ];
+        for (error, json) in cases {
+            assert_eq!(serde_json::to_string(&error).unwrap(), json);
+            assert_eq!(serde_json::from_str::<CurrencyError>(json).unwrap(), error);
+        }
+        assert!(serde_json::from_str::<CurrencyError>(r#"{"type":"oops"}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "miette")]
+    fn test_diagnostic() {
+        use miette::Diagnostic;
+        use Currency::{EUR, USD};
+        use CurrencyError::*;
+
+        let error = Mismatch(EUR, USD);
+        assert_eq!(
+            error.code().unwrap().to_string(),
+            "oxydized_money::mismatch"
+        );
+        assert!(error.help().unwrap().to_string().contains("converted_to"));
+        assert_eq!(
+            MissingRate(EUR, USD).code().unwrap().to_string(),
+            "oxydized_money::missing_rate"
+        );
+        assert!(Unknown::<Currency>.help().is_some());
     }
 }
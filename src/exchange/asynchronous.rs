@@ -0,0 +1,204 @@
+use super::converter::cross_rate;
+use crate::{AmountResult, Converter, Currency, ExchangeRate};
+use std::future::Future;
+
+/// `AsyncRateProvider` is the asynchronous counterpart of
+/// [`RateProvider`](crate::RateProvider), for sources of exchange rates
+/// that cannot be queried without blocking (database, remote API, ...).
+///
+/// The trait does not depend on any particular async runtime.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{AsyncRateProvider, Currency, Currency::{EUR, USD}, Decimal, ExchangeRate};
+/// use oxydized_money_macros::dec;
+/// use std::future::Future;
+///
+/// struct RemoteRates;
+///
+/// impl AsyncRateProvider for RemoteRates {
+///     fn rate(
+///         &self,
+///         from: Currency,
+///         to: Currency,
+///     ) -> impl Future<Output = Option<ExchangeRate>> + Send {
+///         async move {
+///             // query the remote API here...
+///             (from == EUR && to == USD).then(|| ExchangeRate::new(EUR, USD, dec!(1.1)))
+///         }
+///     }
+/// }
+/// ```
+pub trait AsyncRateProvider {
+    /// Returns a future resolving to the [`ExchangeRate`] converting amounts
+    /// in currency `from` into currency `to`, or `None` if that rate is not
+    /// available. The future is [`Send`], so that it can be spawned on
+    /// multi-threaded runtimes.
+    fn rate(
+        &self,
+        from: Currency,
+        to: Currency,
+    ) -> impl Future<Output = Option<ExchangeRate>> + Send;
+}
+
+impl<P: AsyncRateProvider + ?Sized> AsyncRateProvider for &P {
+    fn rate(
+        &self,
+        from: Currency,
+        to: Currency,
+    ) -> impl Future<Output = Option<ExchangeRate>> + Send {
+        (**self).rate(from, to)
+    }
+}
+
+impl<P: AsyncRateProvider + ?Sized> AsyncRateProvider for Box<P> {
+    fn rate(
+        &self,
+        from: Currency,
+        to: Currency,
+    ) -> impl Future<Output = Option<ExchangeRate>> + Send {
+        (**self).rate(from, to)
+    }
+}
+
+impl<P: AsyncRateProvider> Converter<P> {
+    /// Asynchronous counterpart of [`Converter::convert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, AsyncRateProvider, Converter, Currency::USD};
+    /// use oxydized_money_macros::eur;
+    /// use oxydized_money::Decimal;
+    ///
+    /// async fn total_in_usd(converter: &Converter<impl AsyncRateProvider>) -> AmountResult {
+    ///     converter.convert_async(eur!(10), USD).await + converter.convert_async(eur!(5), USD).await
+    /// }
+    /// ```
+    pub async fn convert_async(
+        &self,
+        amount: impl Into<AmountResult>,
+        target: Currency,
+    ) -> AmountResult {
+        let amount = amount.into();
+        match amount.0 {
+            Ok(value) if value.currency() == target => value.into(),
+            Ok(value) => match self.rate_async(value.currency(), target).await {
+                Some(rate) => rate.convert(value),
+                None => AmountResult::missing_rate(value.currency(), target),
+            },
+            Err(_) => amount,
+        }
+    }
+
+    /// Asynchronous counterpart of [`Converter::rate`], resolving missing
+    /// pairs in the same order.
+    pub async fn rate_async(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        if let Some(rate) = self.direct_rate_async(from, to).await {
+            return Some(rate);
+        }
+        let base = self.triangulation_base(from, to)?;
+        let first = self.direct_rate_async(from, base).await?;
+        let second = self.direct_rate_async(base, to).await?;
+        cross_rate(first, second)
+    }
+
+    async fn direct_rate_async(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        match self.provider().rate(from, to).await {
+            Some(rate) => Some(rate),
+            None => self.provider().rate(to, from).await?.invert().ok(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        AsyncRateProvider, Converter, Currency, Currency::*, CurrencyError::*, Decimal,
+        ExchangeRate, Operation,
+    };
+    use oxydized_money_macros::{dec, eur, gbp, usd};
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    struct Rates(Vec<ExchangeRate>);
+
+    impl AsyncRateProvider for Rates {
+        fn rate(
+            &self,
+            from: Currency,
+            to: Currency,
+        ) -> impl Future<Output = Option<ExchangeRate>> + Send {
+            let rate = self
+                .0
+                .iter()
+                .find(|rate| rate.from() == from && rate.to() == to)
+                .copied();
+            async move { rate }
+        }
+    }
+
+    #[test]
+    fn test_convert_async() {
+        let rates = Rates(vec![
+            ExchangeRate::new(EUR, USD, dec!(2)),
+            ExchangeRate::new(GBP, EUR, dec!(1.25)),
+        ]);
+        let converter = Converter::new(&rates);
+        assert_eq!(block_on(converter.convert_async(eur!(3), USD)), usd!(6));
+        assert_eq!(block_on(converter.convert_async(usd!(3), EUR)), eur!(1.5));
+        assert_eq!(block_on(converter.convert_async(usd!(3), USD)), usd!(3));
+        assert_eq!(
+            block_on(converter.convert_async(gbp!(4), USD)),
+            MissingRate(GBP, USD)
+        );
+        assert_eq!(
+            block_on(converter.convert_async(eur!(1) + gbp!(1), USD)),
+            Mismatch(EUR, GBP)
+        );
+        let error = block_on(converter.convert_async((eur!(1) + gbp!(1)).context("label"), USD));
+        assert_eq!(error.error_context(), Some("label"));
+        assert_eq!(error.operation(), Some(Operation::Add));
+
+        let converter = Converter::new(&rates).with_base(EUR);
+        assert_eq!(block_on(converter.convert_async(gbp!(4), USD)), usd!(10));
+        assert_eq!(
+            block_on(converter.rate_async(USD, GBP)),
+            Some(ExchangeRate::new(USD, GBP, dec!(0.4)))
+        );
+    }
+
+    #[test]
+    fn test_send() {
+        fn assert_send<T: Send>(value: T) -> T {
+            value
+        }
+        let rates = Rates(vec![ExchangeRate::new(EUR, USD, dec!(2))]);
+        let converter = Converter::new(&rates).with_base(EUR);
+        let conversion = assert_send(converter.convert_async(eur!(3), USD));
+        assert_eq!(block_on(conversion), usd!(6));
+    }
+}
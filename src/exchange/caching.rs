@@ -0,0 +1,238 @@
+use crate::{Currency, ExchangeRate, RateProvider};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+/// `CacheError` represents the reasons why a [`CachingRateProvider`] could
+/// not return a fresh [`ExchangeRate`].
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub enum CacheError {
+    /// The rate is neither in the cache nor available from the underlying
+    /// provider.
+    Missing(Currency, Currency),
+
+    /// The underlying provider no longer returns the rate and the cached
+    /// rate has outlived its time-to-live. The stale rate is returned along
+    /// with its age so that callers can decide whether it is still usable.
+    Stale {
+        /// The last known exchange rate.
+        rate: ExchangeRate,
+        /// The time elapsed since the rate was fetched.
+        age: Duration,
+    },
+}
+
+impl Error for CacheError {}
+
+impl Display for CacheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use CacheError::*;
+        match self {
+            Missing(c1, c2) => write!(
+                f,
+                "missing exchange rate from '{}' to '{}'",
+                c1.code(),
+                c2.code()
+            ),
+            Stale { rate, age } => write!(
+                f,
+                "stale exchange rate '{}' fetched {}s ago",
+                rate,
+                age.as_secs()
+            ),
+        }
+    }
+}
+
+/// `CachingRateProvider` wraps any [`RateProvider`] and caches the rates it
+/// returns for a configurable time-to-live, so that expensive lookups
+/// (database, remote API, ...) are not performed on every conversion.
+///
+/// The cache is internally synchronized, so a single instance can be
+/// shared between threads if the underlying provider allows it.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{CachingRateProvider, Converter, ExchangeRate, Decimal, Currency::{EUR, USD}};
+/// use oxydized_money_macros::{eur, usd, dec};
+/// use std::time::Duration;
+///
+/// let rates = vec![ExchangeRate::new(EUR, USD, dec!(1.1))];
+/// let cache = CachingRateProvider::new(rates, Duration::from_secs(3600));
+/// let converter = Converter::new(&cache);
+/// assert_eq!(converter.convert(eur!(10), USD), usd!(11));
+/// assert_eq!(cache.try_rate(EUR, USD).unwrap().rate(), dec!(1.1));
+/// ```
+#[derive(Debug)]
+pub struct CachingRateProvider<P> {
+    inner: P,
+    ttl: Duration,
+    cache: Mutex<HashMap<(Currency, Currency), (Instant, ExchangeRate)>>,
+}
+
+impl<P: RateProvider> CachingRateProvider<P> {
+    /// Creates a new [`CachingRateProvider`] caching the rates returned by
+    /// `inner` for `ttl`.
+    pub fn new(inner: P, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the time-to-live of the cached rates.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns a reference to the underlying [`RateProvider`].
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the rate converting `from` into `to`, either from the cache
+    /// if it is still fresh, or from the underlying provider otherwise.
+    ///
+    /// Returns a [`CacheError::Stale`] if the underlying provider no longer
+    /// returns the rate but an expired rate is still in the cache, and a
+    /// [`CacheError::Missing`] if the rate is not known at all.
+    ///
+    /// The cache is not locked while querying the underlying provider, so
+    /// that a slow lookup does not block the threads using other rates.
+    pub fn try_rate(&self, from: Currency, to: Currency) -> Result<ExchangeRate, CacheError> {
+        let now = Instant::now();
+        let cached = self.lock().get(&(from, to)).copied();
+        if let Some((fetched, rate)) = cached {
+            if now.duration_since(fetched) < self.ttl {
+                return Ok(rate);
+            }
+        }
+        match self.inner.rate(from, to) {
+            Some(rate) => {
+                self.lock().insert((from, to), (now, rate));
+                Ok(rate)
+            }
+            None => match cached {
+                Some((fetched, rate)) => Err(CacheError::Stale {
+                    rate,
+                    age: now.duration_since(fetched),
+                }),
+                None => Err(CacheError::Missing(from, to)),
+            },
+        }
+    }
+
+    /// Removes the rate converting `from` into `to` from the cache.
+    pub fn invalidate(&self, from: Currency, to: Currency) {
+        self.lock().remove(&(from, to));
+    }
+
+    /// Removes all rates from the cache.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Locks the cache, ignoring the poisoning by a panicking thread as the
+    /// cache is never left in an inconsistent state.
+    fn lock(&self) -> MutexGuard<'_, HashMap<(Currency, Currency), (Instant, ExchangeRate)>> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<P: RateProvider> RateProvider for CachingRateProvider<P> {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        self.try_rate(from, to).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        CacheError, CachingRateProvider, Currency, Currency::*, Decimal, ExchangeRate, RateProvider,
+    };
+    use oxydized_money_macros::dec;
+    use std::{cell::Cell, time::Duration};
+
+    #[derive(Default)]
+    struct Counting {
+        calls: Cell<usize>,
+        rate: Cell<Option<Decimal>>,
+    }
+
+    impl RateProvider for Counting {
+        fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+            self.calls.set(self.calls.get() + 1);
+            Some(ExchangeRate::new(from, to, self.rate.get()?))
+        }
+    }
+
+    #[test]
+    fn test_fresh() {
+        let inner = Counting::default();
+        inner.rate.set(Some(dec!(1.1)));
+        let cache = CachingRateProvider::new(&inner, Duration::from_secs(3600));
+        assert_eq!(cache.ttl(), Duration::from_secs(3600));
+        assert_eq!(cache.rate(EUR, USD).unwrap().rate(), dec!(1.1));
+        inner.rate.set(Some(dec!(1.2)));
+        assert_eq!(cache.rate(EUR, USD).unwrap().rate(), dec!(1.1));
+        assert_eq!(cache.inner().calls.get(), 1);
+        cache.invalidate(EUR, USD);
+        assert_eq!(cache.rate(EUR, USD).unwrap().rate(), dec!(1.2));
+        assert_eq!(inner.calls.get(), 2);
+        cache.clear();
+        assert_eq!(cache.rate(EUR, USD).unwrap().rate(), dec!(1.2));
+        assert_eq!(inner.calls.get(), 3);
+    }
+
+    #[test]
+    fn test_expired() {
+        let inner = Counting::default();
+        inner.rate.set(Some(dec!(1.1)));
+        let cache = CachingRateProvider::new(&inner, Duration::ZERO);
+        assert_eq!(cache.rate(EUR, USD).unwrap().rate(), dec!(1.1));
+        inner.rate.set(Some(dec!(1.2)));
+        assert_eq!(cache.rate(EUR, USD).unwrap().rate(), dec!(1.2));
+        assert_eq!(inner.calls.get(), 2);
+    }
+
+    #[test]
+    fn test_errors() {
+        let inner = Counting::default();
+        let cache = CachingRateProvider::new(&inner, Duration::ZERO);
+        assert_eq!(cache.try_rate(EUR, USD), Err(CacheError::Missing(EUR, USD)));
+        inner.rate.set(Some(dec!(1.1)));
+        assert!(cache.try_rate(EUR, USD).is_ok());
+        inner.rate.set(None);
+        match cache.try_rate(EUR, USD) {
+            Err(CacheError::Stale { rate, .. }) => assert_eq!(rate.rate(), dec!(1.1)),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(cache.rate(EUR, USD), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let rate = ExchangeRate::new(EUR, USD, dec!(1.1));
+        assert_eq!(
+            format!("{}", CacheError::Missing(EUR, USD)),
+            "missing exchange rate from 'EUR' to 'USD'"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                CacheError::Stale {
+                    rate,
+                    age: Duration::from_secs(90)
+                }
+            ),
+            "stale exchange rate 'EUR/USD 1.1' fetched 90s ago"
+        );
+    }
+}
@@ -0,0 +1,304 @@
+#[cfg(feature = "std")]
+use crate::HistoricalRateProvider;
+use crate::{AmountResult, Currency, ExchangeRate, RateProvider};
+
+/// `Converter` converts [amounts](crate::Amount) between currencies using
+/// the exchange rates obtained from a [`RateProvider`].
+///
+/// When the provider does not know the rate for a given pair of
+/// currencies, the converter falls back to the inverse of the rate for
+/// the opposite pair. If a [base currency](Converter::with_base) is
+/// configured, missing pairs are then derived through that base currency
+/// (see [`Converter::rate`]). If no rate can be found, the conversion
+/// results in a [`CurrencyError::MissingRate`](crate::CurrencyError::MissingRate).
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Converter, ExchangeRate, Decimal, Currency::{EUR, USD, GBP}};
+/// use oxydized_money_macros::{eur, usd, gbp, dec};
+///
+/// let converter = Converter::new(vec![ExchangeRate::new(EUR, USD, dec!(1.25))]);
+/// assert_eq!(converter.convert(eur!(10), USD), usd!(12.5));
+/// assert_eq!(converter.convert(usd!(10), EUR), eur!(8));
+/// assert!(converter.convert(gbp!(10), USD).is_missing_rate());
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Converter<P> {
+    provider: P,
+    base: Option<Currency>,
+}
+
+impl<P> Converter<P> {
+    /// Creates a new [`Converter`] looking up exchange rates in `provider`.
+    pub fn new(provider: P) -> Self {
+        Self {
+            provider,
+            base: None,
+        }
+    }
+
+    /// Configures `base` as the currency through which missing pairs are
+    /// triangulated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Converter, ExchangeRate, Decimal, Currency::{EUR, GBP, SEK}};
+    /// use oxydized_money_macros::{gbp, sek, dec};
+    ///
+    /// let rates = [
+    ///     ExchangeRate::new(EUR, GBP, dec!(0.8)),
+    ///     ExchangeRate::new(EUR, SEK, dec!(11.2)),
+    /// ];
+    /// let converter = Converter::new(rates).with_base(EUR);
+    /// assert_eq!(converter.convert(gbp!(100), SEK), sek!(1400));
+    /// ```
+    pub fn with_base(mut self, base: Currency) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// Returns the base currency used for triangulation, if any.
+    pub fn base(&self) -> Option<Currency> {
+        self.base
+    }
+
+    /// Returns a reference to the underlying rate provider.
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Consumes the [`Converter`] and returns the underlying rate provider.
+    pub fn into_provider(self) -> P {
+        self.provider
+    }
+
+    fn convert_with<L>(&self, amount: AmountResult, target: Currency, lookup: L) -> AmountResult
+    where
+        L: Fn(Currency, Currency) -> Option<ExchangeRate>,
+    {
+        match amount.0 {
+            Ok(value) if value.currency() == target => value.into(),
+            Ok(value) => match self.resolve(value.currency(), target, lookup) {
+                Some(rate) => rate.convert(value),
+                None => AmountResult::missing_rate(value.currency(), target),
+            },
+            Err(_) => amount,
+        }
+    }
+
+    fn resolve<L>(&self, from: Currency, to: Currency, lookup: L) -> Option<ExchangeRate>
+    where
+        L: Fn(Currency, Currency) -> Option<ExchangeRate>,
+    {
+        let direct = |from, to| lookup(from, to).or_else(|| lookup(to, from)?.invert().ok());
+        direct(from, to).or_else(|| {
+            let base = self.triangulation_base(from, to)?;
+            cross_rate(direct(from, base)?, direct(base, to)?)
+        })
+    }
+
+    /// Returns the base currency through which the rate converting `from`
+    /// into `to` can be triangulated, if any.
+    pub(crate) fn triangulation_base(&self, from: Currency, to: Currency) -> Option<Currency> {
+        self.base.filter(|base| *base != from && *base != to)
+    }
+}
+
+/// Returns the cross rate converting amounts through the `first` and then
+/// the `second` leg, or `None` if it does not fit in a [`Decimal`](crate::Decimal).
+pub(crate) fn cross_rate(first: ExchangeRate, second: ExchangeRate) -> Option<ExchangeRate> {
+    let rate = first.rate().checked_mul(second.rate())?;
+    Some(ExchangeRate::new(first.from(), second.to(), rate))
+}
+
+impl<P: RateProvider> Converter<P> {
+    /// Converts `amount` into the `target` currency. Amounts already
+    /// expressed in `target` are returned as is. Errors are coalesced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Converter, CurrencyError, ExchangeRate, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd, dec};
+    ///
+    /// let converter = Converter::new([ExchangeRate::new(EUR, USD, dec!(1.1))]);
+    /// assert_eq!(converter.convert(eur!(10), USD), usd!(11));
+    /// assert_eq!(converter.convert(usd!(10), USD), usd!(10));
+    /// assert_eq!(converter.convert(eur!(1) + usd!(1), USD), CurrencyError::Mismatch(EUR, USD));
+    /// assert_eq!(converter.convert(AmountResult::unknown(), USD), CurrencyError::Unknown);
+    /// ```
+    pub fn convert(&self, amount: impl Into<AmountResult>, target: Currency) -> AmountResult {
+        self.convert_with(amount.into(), target, |from, to| {
+            self.provider.rate(from, to)
+        })
+    }
+
+    /// Returns the [`ExchangeRate`] used to convert amounts from currency
+    /// `from` into currency `to`, looking up, in that order:
+    ///
+    /// 1. the rate for `from`→`to` published by the provider,
+    /// 2. the inverse of the rate for `to`→`from` published by the provider,
+    /// 3. if a [base currency](Converter::with_base) is configured, the
+    ///    cross rate `from`→`base`→`to` where each leg is itself looked up
+    ///    directly or inverted.
+    ///
+    /// Cross rates are computed by multiplying both legs, and the amount is
+    /// then converted once using the resulting rate. The inverted legs and
+    /// the product are rounded to the 28 significant digits of a
+    /// [`Decimal`](crate::Decimal) when they cannot be represented exactly,
+    /// so that a cross rate may differ from the exact one in its last
+    /// digits, e.g. when a leg is the inverse of `3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Converter, ExchangeRate, Decimal, Currency::{EUR, GBP, SEK, USD}};
+    /// use oxydized_money_macros::dec;
+    ///
+    /// let rates = [
+    ///     ExchangeRate::new(EUR, GBP, dec!(0.8)),
+    ///     ExchangeRate::new(EUR, SEK, dec!(11.2)),
+    /// ];
+    /// let converter = Converter::new(rates).with_base(EUR);
+    /// assert_eq!(converter.rate(GBP, SEK), Some(ExchangeRate::new(GBP, SEK, dec!(14))));
+    /// assert_eq!(converter.rate(GBP, USD), None);
+    /// ```
+    pub fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        self.resolve(from, to, |from, to| self.provider.rate(from, to))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: HistoricalRateProvider> Converter<P> {
+    /// Converts `amount` into the `target` currency using the exchange rates
+    /// applicable at `date`. Missing pairs are resolved the same way as in
+    /// [`Converter::rate`], all legs being looked up at `date`. Errors are
+    /// coalesced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Converter, DatedRates, ExchangeRate, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd, dec};
+    ///
+    /// let mut rates = DatedRates::default();
+    /// rates.insert("2024-01-02", ExchangeRate::new(EUR, USD, dec!(1.1)));
+    /// rates.insert("2024-01-03", ExchangeRate::new(EUR, USD, dec!(1.2)));
+    ///
+    /// let converter = Converter::new(rates);
+    /// assert_eq!(converter.convert_at(eur!(10), USD, &"2024-01-02"), usd!(11));
+    /// assert_eq!(converter.convert_at(eur!(10), USD, &"2024-01-05"), usd!(12));
+    /// assert!(converter.convert_at(eur!(10), USD, &"2023-12-31").is_missing_rate());
+    /// ```
+    pub fn convert_at(
+        &self,
+        amount: impl Into<AmountResult>,
+        target: Currency,
+        date: &P::Date,
+    ) -> AmountResult {
+        self.convert_with(amount.into(), target, |from, to| {
+            self.provider.rate_at(from, to, date)
+        })
+    }
+
+    /// Returns the [`ExchangeRate`] used to convert amounts from currency
+    /// `from` into currency `to` at `date`, resolved the same way as in
+    /// [`Converter::rate`].
+    pub fn rate_at(&self, from: Currency, to: Currency, date: &P::Date) -> Option<ExchangeRate> {
+        self.resolve(from, to, |from, to| self.provider.rate_at(from, to, date))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        AmountResult, Converter, Currency::*, CurrencyError::*, DateFallback, DatedRates, Decimal,
+        ExchangeRate, Operation,
+    };
+    use oxydized_money_macros::{dec, eur, gbp, sek, usd};
+
+    #[test]
+    fn test_convert() {
+        let converter = Converter::new(vec![
+            ExchangeRate::new(EUR, USD, dec!(2)),
+            ExchangeRate::new(GBP, EUR, dec!(0)),
+        ]);
+        assert_eq!(converter.convert(eur!(3), USD), usd!(6));
+        assert_eq!(converter.convert(usd!(3), EUR), eur!(1.5));
+        assert_eq!(converter.convert(eur!(3), EUR), eur!(3));
+        assert_eq!(converter.convert(gbp!(3), USD), MissingRate(GBP, USD));
+        assert_eq!(converter.convert(eur!(3), GBP), MissingRate(EUR, GBP));
+        assert_eq!(converter.convert(AmountResult::unknown(), USD), Unknown);
+        assert_eq!(converter.convert(eur!(3) / dec!(0), USD), DivideByZero);
+        assert_eq!(
+            converter.convert(eur!(3) + gbp!(1), USD),
+            Mismatch(EUR, GBP)
+        );
+        let error = converter.convert((eur!(3) + gbp!(1)).context("label"), USD);
+        assert_eq!(error.error_context(), Some("label"));
+        assert_eq!(error.operation(), Some(Operation::Add));
+    }
+
+    #[test]
+    fn test_triangulation() {
+        let rates = [
+            ExchangeRate::new(EUR, USD, dec!(1.25)),
+            ExchangeRate::new(GBP, EUR, dec!(1.25)),
+            ExchangeRate::new(EUR, SEK, dec!(11)),
+        ];
+
+        let converter = Converter::new(rates);
+        assert_eq!(converter.base(), None);
+        assert_eq!(converter.convert(gbp!(10), USD), MissingRate(GBP, USD));
+
+        let converter = converter.with_base(EUR);
+        assert_eq!(converter.base(), Some(EUR));
+        assert_eq!(converter.convert(gbp!(10), USD), usd!(15.625));
+        assert_eq!(converter.convert(usd!(15.625), GBP), gbp!(10));
+        assert_eq!(converter.convert(usd!(10), SEK), sek!(88));
+        assert_eq!(converter.convert(gbp!(10), EUR), eur!(12.5));
+        assert_eq!(converter.convert(gbp!(10), JPY), MissingRate(GBP, JPY));
+        assert_eq!(
+            converter.rate(GBP, SEK),
+            Some(ExchangeRate::new(GBP, SEK, dec!(13.75)))
+        );
+    }
+
+    #[test]
+    fn test_convert_at() {
+        let mut rates = DatedRates::new(DateFallback::Exact);
+        rates.insert(2, ExchangeRate::new(EUR, USD, dec!(2)));
+        rates.insert(2, ExchangeRate::new(GBP, EUR, dec!(1.25)));
+        rates.insert(4, ExchangeRate::new(EUR, USD, dec!(4)));
+
+        let converter = Converter::new(rates).with_base(EUR);
+        assert_eq!(converter.convert_at(eur!(1), USD, &2), usd!(2));
+        assert_eq!(converter.convert_at(usd!(2), EUR, &2), eur!(1));
+        assert_eq!(converter.convert_at(gbp!(4), USD, &2), usd!(10));
+        assert_eq!(converter.convert_at(eur!(1), USD, &4), usd!(4));
+        assert_eq!(
+            converter.convert_at(gbp!(4), USD, &4),
+            MissingRate(GBP, USD)
+        );
+        assert_eq!(
+            converter.convert_at(eur!(1), USD, &3),
+            MissingRate(EUR, USD)
+        );
+        assert_eq!(converter.convert_at(eur!(1), EUR, &3), eur!(1));
+        assert_eq!(
+            converter.rate_at(GBP, USD, &2),
+            Some(ExchangeRate::new(GBP, USD, dec!(2.5)))
+        );
+    }
+
+    #[test]
+    fn test_provider() {
+        let rates = [ExchangeRate::new(EUR, USD, dec!(2))];
+        let converter = Converter::new(rates);
+        assert_eq!(converter.provider(), &rates);
+        assert_eq!(converter.into_provider(), rates);
+    }
+}
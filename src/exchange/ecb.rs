@@ -0,0 +1,257 @@
+use crate::{
+    Currency, DateFallback, DatedRates, Decimal, ExchangeRate, RateProvider, RateSourceError,
+};
+use std::{collections::HashMap, str::FromStr};
+
+/// `EcbRates` is a [`RateProvider`] serving the euro foreign exchange
+/// reference rates published daily by the European Central Bank.
+///
+/// The ECB only publishes rates from `EUR` to other currencies. Use a
+/// [`Converter`](crate::Converter) with `EUR` as
+/// [base currency](crate::Converter::with_base) to convert between any two
+/// published currencies.
+///
+/// Currencies published by the ECB but unknown to [`Currency`] are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Converter, EcbRates, Decimal, Currency::{EUR, GBP, USD}};
+/// use oxydized_money_macros::{eur, usd, dec};
+///
+/// let xml = r#"
+///     <gesmes:Envelope>
+///         <Cube>
+///             <Cube time="2024-01-05">
+///                 <Cube currency="USD" rate="1.0921"/>
+///                 <Cube currency="GBP" rate="0.86"/>
+///             </Cube>
+///         </Cube>
+///     </gesmes:Envelope>
+/// "#;
+///
+/// let rates = EcbRates::from_xml(xml).unwrap();
+/// assert_eq!(rates.date(), "2024-01-05");
+/// let converter = Converter::new(rates).with_base(EUR);
+/// assert_eq!(converter.convert(eur!(100), USD), usd!(109.21));
+/// assert!(converter.convert(usd!(100), GBP).is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EcbRates {
+    date: String,
+    rates: HashMap<Currency, Decimal>,
+}
+
+impl EcbRates {
+    /// URL of the daily reference rates document.
+    pub const DAILY_URL: &'static str =
+        "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml";
+
+    /// URL of the document containing the reference rates for the last 90 days.
+    pub const HISTORY_90_DAYS_URL: &'static str =
+        "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-hist-90d.xml";
+
+    /// URL of the document containing all the reference rates since 1999.
+    pub const HISTORY_URL: &'static str =
+        "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-hist.xml";
+
+    /// Loads the most recent reference rates contained in an ECB XML
+    /// document, such as the one served at [`EcbRates::DAILY_URL`].
+    pub fn from_xml(xml: &str) -> Result<Self, RateSourceError> {
+        let (date, rates) = parse(xml)?
+            .into_iter()
+            .max_by(|(d1, _), (d2, _)| d1.cmp(d2))
+            .ok_or_else(|| RateSourceError::Parse("no reference rates found".into()))?;
+        Ok(Self {
+            date,
+            rates: rates.into_iter().collect(),
+        })
+    }
+
+    /// Loads all the reference rates contained in an ECB XML document, such
+    /// as the ones served at [`EcbRates::HISTORY_90_DAYS_URL`] or
+    /// [`EcbRates::HISTORY_URL`], keyed by their publication date in
+    /// ISO 8601 format. Dates without publication (weekends, bank holidays)
+    /// fall back to the nearest prior publication.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{EcbRates, HistoricalRateProvider, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::dec;
+    ///
+    /// let xml = r#"
+    ///     <Cube>
+    ///         <Cube time="2024-01-08"><Cube currency="USD" rate="1.0946"/></Cube>
+    ///         <Cube time="2024-01-05"><Cube currency="USD" rate="1.0921"/></Cube>
+    ///     </Cube>
+    /// "#;
+    ///
+    /// let history = EcbRates::history_from_xml(xml).unwrap();
+    /// let rate = history.rate_at(EUR, USD, &"2024-01-07".into()).unwrap();
+    /// assert_eq!(rate.rate(), dec!(1.0921));
+    /// ```
+    pub fn history_from_xml(xml: &str) -> Result<DatedRates<String>, RateSourceError> {
+        let mut history = DatedRates::new(DateFallback::NearestPrior);
+        for (date, rates) in parse(xml)? {
+            for (currency, rate) in rates {
+                history.insert(
+                    date.clone(),
+                    ExchangeRate::new(Currency::EUR, currency, rate),
+                );
+            }
+        }
+        Ok(history)
+    }
+
+    /// Downloads and loads the daily reference rates from
+    /// [`EcbRates::DAILY_URL`].
+    #[cfg(feature = "ecb-fetch")]
+    pub fn fetch() -> Result<Self, RateSourceError> {
+        Self::from_xml(&super::source::download(Self::DAILY_URL)?)
+    }
+
+    /// Downloads and loads the complete history of reference rates from
+    /// [`EcbRates::HISTORY_URL`].
+    #[cfg(feature = "ecb-fetch")]
+    pub fn fetch_history() -> Result<DatedRates<String>, RateSourceError> {
+        Self::history_from_xml(&super::source::download(Self::HISTORY_URL)?)
+    }
+
+    /// Returns the publication date of the reference rates, in ISO 8601
+    /// format.
+    pub fn date(&self) -> &str {
+        &self.date
+    }
+}
+
+impl RateProvider for EcbRates {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        if from != Currency::EUR {
+            return None;
+        }
+        let rate = self.rates.get(&to)?;
+        Some(ExchangeRate::new(from, to, *rate))
+    }
+}
+
+type Publication = (String, Vec<(Currency, Decimal)>);
+
+fn parse(xml: &str) -> Result<Vec<Publication>, RateSourceError> {
+    let mut publications: Vec<Publication> = Vec::new();
+    for tag in xml.split("<Cube").skip(1) {
+        let tag = tag.split('>').next().unwrap_or_default();
+        if let Some(date) = attribute(tag, "time") {
+            publications.push((date.to_string(), Vec::new()));
+        } else if let Some(code) = attribute(tag, "currency") {
+            let (_, rates) = publications.last_mut().ok_or_else(|| {
+                RateSourceError::Parse(format!("rate for '{}' without date", code))
+            })?;
+            let rate = attribute(tag, "rate")
+                .ok_or_else(|| RateSourceError::Parse(format!("missing rate for '{}'", code)))?;
+            let rate = Decimal::from_str(rate).map_err(|_| {
+                RateSourceError::Parse(format!("invalid rate '{}' for '{}'", rate, code))
+            })?;
+            if let Some(currency) = Currency::from_code(code) {
+                rates.push((currency, rate));
+            }
+        }
+    }
+    Ok(publications)
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(start) = rest.find(name) {
+        let after = rest[start + name.len()..].trim_start();
+        let preceded_by_space = rest[..start].ends_with(char::is_whitespace);
+        if let (true, Some(value)) = (preceded_by_space, after.strip_prefix('=')) {
+            let value = value.trim_start();
+            let quote = value.chars().next()?;
+            if quote == '"' || quote == '\'' {
+                let value = &value[1..];
+                return value.find(quote).map(|end| &value[..end]);
+            }
+        }
+        rest = &rest[start + name.len()..];
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        Converter, Currency::*, Decimal, EcbRates, HistoricalRateProvider, RateProvider,
+        RateSourceError,
+    };
+    use oxydized_money_macros::{dec, eur, usd};
+
+    const DAILY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+	<gesmes:subject>Reference rates</gesmes:subject>
+	<gesmes:Sender>
+		<gesmes:name>European Central Bank</gesmes:name>
+	</gesmes:Sender>
+	<Cube>
+		<Cube time='2024-01-05'>
+			<Cube currency='USD' rate='1.0921'/>
+			<Cube currency='JPY' rate='158.32'/>
+			<Cube currency='GBP' rate='0.86'/>
+			<Cube currency='XYZ' rate='42'/>
+		</Cube>
+	</Cube>
+</gesmes:Envelope>"#;
+
+    #[test]
+    fn test_from_xml() {
+        let rates = EcbRates::from_xml(DAILY).unwrap();
+        assert_eq!(rates.date(), "2024-01-05");
+        assert_eq!(rates.rate(EUR, USD).unwrap().rate(), dec!(1.0921));
+        assert_eq!(rates.rate(EUR, JPY).unwrap().rate(), dec!(158.32));
+        assert_eq!(rates.rate(USD, EUR), None);
+        assert_eq!(rates.rate(EUR, CHF), None);
+
+        let converter = Converter::new(rates).with_base(EUR);
+        assert_eq!(converter.convert(usd!(1.0921), EUR), eur!(1));
+        assert_eq!(
+            converter.rate(GBP, USD).unwrap().rate().round_dp(4),
+            dec!(1.2699)
+        );
+    }
+
+    #[test]
+    fn test_history_from_xml() {
+        let xml = r#"<Cube>
+            <Cube time="2024-01-08"><Cube currency="USD" rate="1.0946"/></Cube>
+            <Cube time="2024-01-05"><Cube currency="USD" rate="1.0921"/></Cube>
+        </Cube>"#;
+        let history = EcbRates::history_from_xml(xml).unwrap();
+        let rate = |date: &str| history.rate_at(EUR, USD, &date.into()).map(|r| r.rate());
+        assert_eq!(rate("2024-01-04"), None);
+        assert_eq!(rate("2024-01-05"), Some(dec!(1.0921)));
+        assert_eq!(rate("2024-01-07"), Some(dec!(1.0921)));
+        assert_eq!(rate("2024-01-09"), Some(dec!(1.0946)));
+        assert_eq!(EcbRates::from_xml(xml).unwrap().date(), "2024-01-08");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            EcbRates::from_xml("<Cube></Cube>"),
+            Err(RateSourceError::Parse("no reference rates found".into()))
+        );
+        assert_eq!(
+            EcbRates::from_xml("<Cube currency='USD' rate='1'/>"),
+            Err(RateSourceError::Parse("rate for 'USD' without date".into()))
+        );
+        assert_eq!(
+            EcbRates::from_xml("<Cube time='2024-01-05'><Cube currency='USD'/></Cube>"),
+            Err(RateSourceError::Parse("missing rate for 'USD'".into()))
+        );
+        assert_eq!(
+            EcbRates::from_xml("<Cube time='2024-01-05'><Cube currency='USD' rate='x'/></Cube>"),
+            Err(RateSourceError::Parse("invalid rate 'x' for 'USD'".into()))
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{Currency, ExchangeRate, RateProvider};
+use std::collections::{BTreeMap, HashMap};
+
+/// `HistoricalRateProvider` is the extension point through which a
+/// [`Converter`](crate::Converter) looks up [exchange rates](ExchangeRate)
+/// applicable at a given date.
+///
+/// The type used to represent dates is left to the implementor, so that
+/// any date library (or plain strings in ISO 8601 format) can be used.
+pub trait HistoricalRateProvider {
+    /// The type used to represent dates.
+    type Date;
+
+    /// Returns the [`ExchangeRate`] converting amounts in currency `from`
+    /// into currency `to` applicable at `date`, or `None` if that rate is
+    /// not available.
+    fn rate_at(&self, from: Currency, to: Currency, date: &Self::Date) -> Option<ExchangeRate>;
+}
+
+impl<P: HistoricalRateProvider + ?Sized> HistoricalRateProvider for &P {
+    type Date = P::Date;
+
+    fn rate_at(&self, from: Currency, to: Currency, date: &Self::Date) -> Option<ExchangeRate> {
+        (**self).rate_at(from, to, date)
+    }
+}
+
+impl<P: HistoricalRateProvider + ?Sized> HistoricalRateProvider for Box<P> {
+    type Date = P::Date;
+
+    fn rate_at(&self, from: Currency, to: Currency, date: &Self::Date) -> Option<ExchangeRate> {
+        (**self).rate_at(from, to, date)
+    }
+}
+
+/// `DateFallback` defines how [`DatedRates`] behaves when no rate was
+/// published at the requested date.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DateFallback {
+    /// Only the rate published exactly at the requested date is used.
+    Exact,
+
+    /// The most recent rate published on or before the requested date is
+    /// used, e.g. Friday's rate for a transaction booked on a Sunday.
+    #[default]
+    NearestPrior,
+}
+
+/// `DatedRates` is an in-memory [`HistoricalRateProvider`] storing the
+/// exchange rates published at various dates.
+///
+/// It also implements [`RateProvider`] by returning, for each pair of
+/// currencies, the most recently published rate.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{DateFallback, DatedRates, ExchangeRate, HistoricalRateProvider, Decimal, Currency::{EUR, USD}};
+/// use oxydized_money_macros::dec;
+///
+/// let mut rates = DatedRates::new(DateFallback::NearestPrior);
+/// rates.insert((2024, 1, 5), ExchangeRate::new(EUR, USD, dec!(1.09)));
+/// rates.insert((2024, 1, 8), ExchangeRate::new(EUR, USD, dec!(1.10)));
+///
+/// assert_eq!(rates.rate_at(EUR, USD, &(2024, 1, 7)).unwrap().rate(), dec!(1.09));
+/// assert_eq!(rates.rate_at(EUR, USD, &(2024, 1, 4)), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DatedRates<D> {
+    fallback: DateFallback,
+    rates: HashMap<(Currency, Currency), BTreeMap<D, ExchangeRate>>,
+}
+
+impl<D: Ord> DatedRates<D> {
+    /// Creates an empty [`DatedRates`] using the provided fallback policy.
+    pub fn new(fallback: DateFallback) -> Self {
+        Self {
+            fallback,
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Returns the fallback policy used when no rate was published at the
+    /// requested date.
+    pub fn fallback(&self) -> DateFallback {
+        self.fallback
+    }
+
+    /// Records `rate` as published at `date`, replacing any rate
+    /// previously recorded for the same pair of currencies at that date.
+    pub fn insert(&mut self, date: D, rate: ExchangeRate) {
+        self.rates
+            .entry((rate.from(), rate.to()))
+            .or_default()
+            .insert(date, rate);
+    }
+}
+
+impl<D: Ord> Default for DatedRates<D> {
+    fn default() -> Self {
+        Self::new(DateFallback::default())
+    }
+}
+
+impl<D: Ord> HistoricalRateProvider for DatedRates<D> {
+    type Date = D;
+
+    fn rate_at(&self, from: Currency, to: Currency, date: &D) -> Option<ExchangeRate> {
+        let history = self.rates.get(&(from, to))?;
+        match self.fallback {
+            DateFallback::Exact => history.get(date).copied(),
+            DateFallback::NearestPrior => history.range(..=date).next_back().map(|(_, rate)| *rate),
+        }
+    }
+}
+
+impl<D: Ord> RateProvider for DatedRates<D> {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        let history = self.rates.get(&(from, to))?;
+        history.values().next_back().copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        Currency::*, DateFallback, DatedRates, Decimal, ExchangeRate, HistoricalRateProvider,
+        RateProvider,
+    };
+    use oxydized_money_macros::dec;
+
+    fn rates(fallback: DateFallback) -> DatedRates<&'static str> {
+        let mut rates = DatedRates::new(fallback);
+        rates.insert("2024-03-01", ExchangeRate::new(EUR, USD, dec!(1.08)));
+        rates.insert("2024-03-04", ExchangeRate::new(EUR, USD, dec!(1.09)));
+        rates.insert("2024-03-05", ExchangeRate::new(EUR, USD, dec!(1.10)));
+        rates.insert("2024-03-04", ExchangeRate::new(EUR, GBP, dec!(0.85)));
+        rates
+    }
+
+    #[test]
+    fn test_exact() {
+        let rates = rates(DateFallback::Exact);
+        assert_eq!(rates.fallback(), DateFallback::Exact);
+        let rate = |date| rates.rate_at(EUR, USD, &date).map(|r| r.rate());
+        assert_eq!(rate("2024-03-01"), Some(dec!(1.08)));
+        assert_eq!(rate("2024-03-04"), Some(dec!(1.09)));
+        assert_eq!(rate("2024-03-03"), None);
+        assert_eq!(rate("2024-02-28"), None);
+        assert_eq!(rates.rate_at(USD, EUR, &"2024-03-01"), None);
+    }
+
+    #[test]
+    fn test_nearest_prior() {
+        let rates = rates(DateFallback::NearestPrior);
+        assert_eq!(rates.fallback(), DateFallback::NearestPrior);
+        let rate = |date| rates.rate_at(EUR, USD, &date).map(|r| r.rate());
+        assert_eq!(rate("2024-03-01"), Some(dec!(1.08)));
+        assert_eq!(rate("2024-03-03"), Some(dec!(1.08)));
+        assert_eq!(rate("2024-03-04"), Some(dec!(1.09)));
+        assert_eq!(rate("2024-12-31"), Some(dec!(1.10)));
+        assert_eq!(rate("2024-02-28"), None);
+        assert_eq!(
+            rates.rate_at(EUR, GBP, &"2024-03-05").map(|r| r.rate()),
+            Some(dec!(0.85))
+        );
+    }
+
+    #[test]
+    fn test_latest() {
+        let rates = rates(DateFallback::Exact);
+        assert_eq!(rates.rate(EUR, USD).map(|r| r.rate()), Some(dec!(1.10)));
+        assert_eq!(rates.rate(EUR, GBP).map(|r| r.rate()), Some(dec!(0.85)));
+        assert_eq!(rates.rate(GBP, EUR), None);
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(feature = "async")]
+mod asynchronous;
+#[cfg(feature = "std")]
+mod caching;
+mod converter;
+#[cfg(feature = "ecb")]
+mod ecb;
+#[cfg(feature = "std")]
+mod historical;
+#[cfg(feature = "openexchangerates")]
+mod oxr;
+mod provider;
+mod rate;
+#[cfg(feature = "alloc")]
+mod snapshot;
+#[cfg(any(feature = "ecb", feature = "openexchangerates"))]
+mod source;
+
+#[cfg(feature = "async")]
+pub use asynchronous::AsyncRateProvider;
+#[cfg(feature = "std")]
+pub use caching::{CacheError, CachingRateProvider};
+pub use converter::Converter;
+#[cfg(feature = "ecb")]
+pub use ecb::EcbRates;
+#[cfg(feature = "std")]
+pub use historical::{DateFallback, DatedRates, HistoricalRateProvider};
+#[cfg(feature = "openexchangerates")]
+pub use oxr::OpenExchangeRates;
+pub use provider::RateProvider;
+pub use rate::ExchangeRate;
+#[cfg(feature = "alloc")]
+pub use snapshot::StaticRates;
+#[cfg(any(feature = "ecb", feature = "openexchangerates"))]
+pub use source::RateSourceError;
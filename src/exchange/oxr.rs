@@ -0,0 +1,161 @@
+use crate::{Currency, Decimal, ExchangeRate, RateProvider, RateSourceError};
+use serde::Deserialize;
+use serde_json::value::RawValue;
+use std::{collections::HashMap, str::FromStr};
+
+/// `OpenExchangeRates` is a [`RateProvider`] serving the rates returned by
+/// the [Open Exchange Rates](https://openexchangerates.org) JSON API.
+///
+/// The API publishes rates from a single base currency (`USD` unless
+/// configured otherwise on the account) to all other currencies. Use a
+/// [`Converter`](crate::Converter) with the [base](OpenExchangeRates::base)
+/// as [base currency](crate::Converter::with_base) to convert between any
+/// two published currencies.
+///
+/// Rates are parsed exactly from the JSON numbers, without going through
+/// floating point. Currencies unknown to [`Currency`] are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Converter, OpenExchangeRates, Decimal, Currency::{EUR, GBP, USD}};
+/// use oxydized_money_macros::{eur, usd, dec};
+///
+/// let json = r#"{
+///     "timestamp": 1704412800,
+///     "base": "USD",
+///     "rates": { "EUR": 0.915667, "GBP": 0.788 }
+/// }"#;
+///
+/// let rates = OpenExchangeRates::from_json(json).unwrap();
+/// assert_eq!(rates.base(), USD);
+/// assert_eq!(rates.timestamp(), 1704412800);
+/// let converter = Converter::new(rates).with_base(USD);
+/// assert_eq!(converter.convert(usd!(100), EUR), eur!(91.5667));
+/// assert!(converter.convert(eur!(100), GBP).is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OpenExchangeRates {
+    base: Currency,
+    timestamp: u64,
+    rates: HashMap<Currency, Decimal>,
+}
+
+#[derive(Deserialize)]
+struct Payload<'a> {
+    timestamp: u64,
+    base: &'a str,
+    #[serde(borrow)]
+    rates: HashMap<&'a str, &'a RawValue>,
+}
+
+impl OpenExchangeRates {
+    /// URL of the endpoint serving the latest rates.
+    pub const LATEST_URL: &'static str = "https://openexchangerates.org/api/latest.json";
+
+    /// Loads the rates contained in a response of the Open Exchange Rates
+    /// API, such as the one served at [`OpenExchangeRates::LATEST_URL`].
+    pub fn from_json(json: &str) -> Result<Self, RateSourceError> {
+        let payload: Payload = serde_json::from_str(json)
+            .map_err(|error| RateSourceError::Parse(error.to_string()))?;
+        let base = Currency::from_code(payload.base).ok_or_else(|| {
+            RateSourceError::Parse(format!("unknown base currency '{}'", payload.base))
+        })?;
+        let mut rates = HashMap::new();
+        for (code, rate) in payload.rates {
+            let rate = Decimal::from_str(rate.get())
+                .or_else(|_| Decimal::from_scientific(rate.get()))
+                .map_err(|_| {
+                    RateSourceError::Parse(format!("invalid rate '{}' for '{}'", rate, code))
+                })?;
+            if let Some(currency) = Currency::from_code(code) {
+                rates.insert(currency, rate);
+            }
+        }
+        Ok(Self {
+            base,
+            timestamp: payload.timestamp,
+            rates,
+        })
+    }
+
+    /// Downloads and loads the latest rates from
+    /// [`OpenExchangeRates::LATEST_URL`] using the provided application id.
+    #[cfg(feature = "openexchangerates-fetch")]
+    pub fn fetch(app_id: &str) -> Result<Self, RateSourceError> {
+        let url = format!("{}?app_id={}", Self::LATEST_URL, app_id);
+        Self::from_json(&super::source::download(&url)?)
+    }
+
+    /// Returns the currency from which all rates are published.
+    pub fn base(&self) -> Currency {
+        self.base
+    }
+
+    /// Returns the time at which the rates were published, as a UNIX
+    /// timestamp.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+impl RateProvider for OpenExchangeRates {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        if from != self.base {
+            return None;
+        }
+        let rate = self.rates.get(&to)?;
+        Some(ExchangeRate::new(from, to, *rate))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Currency::*, Decimal, OpenExchangeRates, RateProvider, RateSourceError};
+    use oxydized_money_macros::dec;
+
+    #[test]
+    fn test_from_json() {
+        let json = r#"{
+            "disclaimer": "Usage subject to terms: https://openexchangerates.org/terms",
+            "license": "https://openexchangerates.org/license",
+            "timestamp": 1704412800,
+            "base": "USD",
+            "rates": {
+                "EUR": 0.915667,
+                "JPY": 144.6895,
+                "BTC": 2.2908e-5,
+                "XAU": 4.9e-4
+            }
+        }"#;
+        let rates = OpenExchangeRates::from_json(json).unwrap();
+        assert_eq!(rates.base(), USD);
+        assert_eq!(rates.timestamp(), 1704412800);
+        assert_eq!(rates.rate(USD, EUR).unwrap().rate(), dec!(0.915667));
+        assert_eq!(rates.rate(USD, JPY).unwrap().rate(), dec!(144.6895));
+        assert_eq!(rates.rate(USD, XAU).unwrap().rate(), dec!(0.00049));
+        assert_eq!(rates.rate(EUR, USD), None);
+        assert_eq!(rates.rate(USD, GBP), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            OpenExchangeRates::from_json("{"),
+            Err(RateSourceError::Parse(_))
+        ));
+        assert_eq!(
+            OpenExchangeRates::from_json(r#"{"timestamp": 0, "base": "XYZ", "rates": {}}"#),
+            Err(RateSourceError::Parse("unknown base currency 'XYZ'".into()))
+        );
+        assert_eq!(
+            OpenExchangeRates::from_json(
+                r#"{"timestamp": 0, "base": "USD", "rates": {"EUR": "x"}}"#
+            ),
+            Err(RateSourceError::Parse(
+                "invalid rate '\"x\"' for 'EUR'".into()
+            ))
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Currency, ExchangeRate};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+/// `RateProvider` is the extension point through which a
+/// [`Converter`](crate::Converter) looks up [exchange rates](ExchangeRate).
+///
+/// Implement this trait to plug any source of exchange rates (database
+/// table, configuration file, remote API, ...) into the conversion
+/// machinery of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Currency, Currency::{EUR, USD}, Decimal, ExchangeRate, RateProvider};
+/// use oxydized_money_macros::dec;
+///
+/// struct FixedRates;
+///
+/// impl RateProvider for FixedRates {
+///     fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+///         match (from, to) {
+///             (EUR, USD) => Some(ExchangeRate::new(EUR, USD, dec!(1.1))),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(FixedRates.rate(EUR, USD).unwrap().rate(), dec!(1.1));
+/// assert_eq!(FixedRates.rate(USD, EUR), None);
+/// ```
+pub trait RateProvider {
+    /// Returns the [`ExchangeRate`] converting amounts in currency `from`
+    /// into currency `to`, or `None` if that rate is not available.
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate>;
+}
+
+impl<P: RateProvider + ?Sized> RateProvider for &P {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        (**self).rate(from, to)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: RateProvider + ?Sized> RateProvider for Box<P> {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        (**self).rate(from, to)
+    }
+}
+
+impl RateProvider for [ExchangeRate] {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        self.iter()
+            .find(|rate| rate.from() == from && rate.to() == to)
+            .copied()
+    }
+}
+
+impl<const N: usize> RateProvider for [ExchangeRate; N] {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        self.as_slice().rate(from, to)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl RateProvider for Vec<ExchangeRate> {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        self.as_slice().rate(from, to)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Currency::*, Decimal, ExchangeRate, RateProvider};
+    use oxydized_money_macros::dec;
+
+    #[test]
+    fn test_array() {
+        let rates = [ExchangeRate::new(EUR, USD, dec!(1.1))];
+        assert_eq!(rates.rate(EUR, USD), Some(rates[0]));
+        assert_eq!(rates.rate(USD, EUR), None);
+        assert_eq!(rates.rate(EUR, GBP), None);
+    }
+
+    #[test]
+    fn test_slice() {
+        let rates = vec![
+            ExchangeRate::new(EUR, USD, dec!(1.1)),
+            ExchangeRate::new(EUR, GBP, dec!(0.85)),
+        ];
+        assert_eq!(rates.rate(EUR, GBP), Some(rates[1]));
+        assert_eq!(rates.as_slice().rate(EUR, USD), Some(rates[0]));
+        let borrowed: &dyn RateProvider = &rates;
+        assert_eq!(borrowed.rate(GBP, EUR), None);
+        let boxed: Box<dyn RateProvider> = Box::new(rates.clone());
+        assert_eq!(boxed.rate(EUR, USD), Some(rates[0]));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Amount, AmountResult, Currency, CurrencyError, Decimal, Result};
+use core::fmt::Display;
+
+/// `ExchangeRate` represents the rate at which an amount of money in a
+/// source currency can be converted into a target currency.
+///
+/// Contrary to [`Amount::converted_to`], converting an amount with an
+/// [`ExchangeRate`] checks that the amount is actually expressed in the
+/// source currency of the rate.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{ExchangeRate, Decimal, Currency::{EUR, USD}};
+/// use oxydized_money_macros::{eur, usd, gbp, dec};
+///
+/// let eur_usd = ExchangeRate::new(EUR, USD, dec!(1.1));
+/// assert_eq!(eur_usd.convert(eur!(10)), usd!(11));
+/// assert!(eur_usd.convert(gbp!(10)).is_mismatch());
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExchangeRate {
+    from: Currency,
+    to: Currency,
+    rate: Decimal,
+}
+
+impl ExchangeRate {
+    /// Creates a new [`ExchangeRate`] converting amounts in currency
+    /// `from` into currency `to`, such that one unit of `from` is worth
+    /// `rate` units of `to`.
+    pub fn new(from: Currency, to: Currency, rate: Decimal) -> Self {
+        Self { from, to, rate }
+    }
+
+    /// Returns the source currency of the exchange rate.
+    pub fn from(&self) -> Currency {
+        self.from
+    }
+
+    /// Returns the target currency of the exchange rate.
+    pub fn to(&self) -> Currency {
+        self.to
+    }
+
+    /// Returns the value of one unit of [`from`](ExchangeRate::from)
+    /// expressed in [`to`](ExchangeRate::to).
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Converts `amount` into the target currency of the exchange rate.
+    /// Returns a [`CurrencyError::Mismatch`] if `amount` is not expressed
+    /// in the source currency of the exchange rate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{ExchangeRate, CurrencyError, Decimal, Currency::{EUR, GBP, USD}};
+    /// use oxydized_money_macros::{eur, usd, gbp, dec};
+    ///
+    /// let eur_usd = ExchangeRate::new(EUR, USD, dec!(1.1));
+    /// assert_eq!(eur_usd.convert(eur!(100)), usd!(110));
+    /// assert_eq!(eur_usd.convert(gbp!(100)), CurrencyError::Mismatch(EUR, GBP));
+    /// ```
+    pub fn convert(&self, amount: Amount) -> AmountResult {
+        if amount.currency() == self.from {
+            amount.converted_to(self.to, self.rate).into()
+        } else {
+            AmountResult::mismatch(self.from, amount.currency())
+        }
+    }
+
+    /// Returns the inverse exchange rate, converting from
+    /// [`to`](ExchangeRate::to) back to [`from`](ExchangeRate::from).
+    /// Returns a [`CurrencyError::DivideByZero`] if the rate is zero.
+    ///
+    /// The inverse is rounded to the 28 significant digits of a
+    /// [`Decimal`] when it cannot be represented exactly, e.g. the inverse
+    /// of `3` is `0.3333333333333333333333333333`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{ExchangeRate, CurrencyError, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::dec;
+    ///
+    /// let eur_usd = ExchangeRate::new(EUR, USD, dec!(1.25));
+    /// assert_eq!(eur_usd.invert(), Ok(ExchangeRate::new(USD, EUR, dec!(0.8))));
+    ///
+    /// let eur_xyz = ExchangeRate::new(EUR, USD, dec!(3));
+    /// assert_eq!(eur_xyz.invert().unwrap().rate(), dec!(0.3333333333333333333333333333));
+    ///
+    /// let broken = ExchangeRate::new(EUR, USD, dec!(0));
+    /// assert_eq!(broken.invert(), Err(CurrencyError::DivideByZero));
+    /// ```
+    pub fn invert(&self) -> Result<Self> {
+        match Decimal::ONE.checked_div(self.rate) {
+            Some(rate) => Ok(Self::new(self.to, self.from, rate)),
+            None => Err(CurrencyError::DivideByZero),
+        }
+    }
+}
+
+impl Display for ExchangeRate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{} {}", self.from.code(), self.to.code(), self.rate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Currency::*, CurrencyError::*, Decimal, ExchangeRate};
+    use oxydized_money_macros::{dec, eur, gbp, usd};
+
+    #[test]
+    fn test_display() {
+        let rate = ExchangeRate::new(EUR, USD, dec!(1.0876));
+        assert_eq!(format!("{}", rate), "EUR/USD 1.0876");
+    }
+
+    #[test]
+    fn test_convert() {
+        let rate = ExchangeRate::new(EUR, USD, dec!(1.5));
+        assert_eq!(rate.convert(eur!(2)), usd!(3));
+        assert_eq!(rate.convert(eur!(-2)), usd!(-3));
+        assert_eq!(rate.convert(usd!(2)), Mismatch(EUR, USD));
+        assert_eq!(rate.convert(gbp!(2)), Mismatch(EUR, GBP));
+    }
+
+    #[test]
+    fn test_invert() {
+        let rate = ExchangeRate::new(EUR, USD, dec!(2));
+        let inverse = rate.invert().unwrap();
+        assert_eq!(inverse.from(), USD);
+        assert_eq!(inverse.to(), EUR);
+        assert_eq!(inverse.rate(), dec!(0.5));
+        assert_eq!(inverse.convert(usd!(3)), eur!(1.5));
+        assert_eq!(
+            ExchangeRate::new(EUR, USD, dec!(0)).invert(),
+            Err(DivideByZero)
+        );
+    }
+}
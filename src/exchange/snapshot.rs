@@ -0,0 +1,133 @@
+use crate::{Currency, Decimal, ExchangeRate, RateProvider};
+use alloc::vec::Vec;
+
+/// `StaticRates` is a [`RateProvider`] serving a fixed snapshot of exchange
+/// rates, typically used in tests and offline tools.
+///
+/// With the `with_serde` feature, snapshots can be loaded from (and saved
+/// to) any format supported by `serde`, so that rate fixtures can be
+/// checked into repositories.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Converter, StaticRates, Decimal, Currency::{EUR, USD}};
+/// use oxydized_money_macros::{eur, usd, dec};
+///
+/// let rates = StaticRates::new(&[(EUR, USD, dec!(1.1))]);
+/// assert_eq!(Converter::new(rates).convert(eur!(10), USD), usd!(11));
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "with_serde")]
+/// # {
+/// use oxydized_money::{RateProvider, StaticRates, Decimal, Currency::{EUR, USD}};
+/// use oxydized_money_macros::dec;
+///
+/// let rates: StaticRates = toml::from_str(r#"
+///     [[rates]]
+///     from = "EUR"
+///     to = "USD"
+///     rate = "1.1"
+/// "#).unwrap();
+/// assert_eq!(rates.rate(EUR, USD).unwrap().rate(), dec!(1.1));
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "with_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StaticRates {
+    rates: Vec<ExchangeRate>,
+}
+
+impl StaticRates {
+    /// Creates a new [`StaticRates`] from a slice of `(from, to, rate)`
+    /// triplets.
+    pub fn new(rates: &[(Currency, Currency, Decimal)]) -> Self {
+        rates
+            .iter()
+            .map(|(from, to, rate)| ExchangeRate::new(*from, *to, *rate))
+            .collect()
+    }
+
+    /// Adds `rate` to the snapshot, replacing any rate previously recorded
+    /// for the same pair of currencies.
+    pub fn insert(&mut self, rate: ExchangeRate) {
+        let pair = |r: &ExchangeRate| (r.from(), r.to());
+        match self.rates.iter_mut().find(|r| pair(r) == pair(&rate)) {
+            Some(existing) => *existing = rate,
+            None => self.rates.push(rate),
+        }
+    }
+
+    /// Returns the exchange rates of the snapshot.
+    pub fn rates(&self) -> &[ExchangeRate] {
+        &self.rates
+    }
+}
+
+impl From<&[(Currency, Currency, Decimal)]> for StaticRates {
+    fn from(rates: &[(Currency, Currency, Decimal)]) -> Self {
+        Self::new(rates)
+    }
+}
+
+impl FromIterator<ExchangeRate> for StaticRates {
+    fn from_iter<I: IntoIterator<Item = ExchangeRate>>(iter: I) -> Self {
+        let mut rates = Self::default();
+        iter.into_iter().for_each(|rate| rates.insert(rate));
+        rates
+    }
+}
+
+impl RateProvider for StaticRates {
+    fn rate(&self, from: Currency, to: Currency) -> Option<ExchangeRate> {
+        self.rates.rate(from, to)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Currency::*, Decimal, ExchangeRate, RateProvider, StaticRates};
+    use oxydized_money_macros::dec;
+
+    #[test]
+    fn test_new() {
+        let rates = StaticRates::new(&[
+            (EUR, USD, dec!(1.1)),
+            (EUR, GBP, dec!(0.85)),
+            (EUR, USD, dec!(1.2)),
+        ]);
+        assert_eq!(rates.rates().len(), 2);
+        assert_eq!(rates.rate(EUR, USD).unwrap().rate(), dec!(1.2));
+        assert_eq!(rates.rate(EUR, GBP).unwrap().rate(), dec!(0.85));
+        assert_eq!(rates.rate(GBP, EUR), None);
+    }
+
+    #[test]
+    fn test_insert() {
+        let slice: &[_] = &[(EUR, USD, dec!(1.1))];
+        let mut rates = StaticRates::from(slice);
+        rates.insert(ExchangeRate::new(USD, JPY, dec!(150)));
+        rates.insert(ExchangeRate::new(EUR, USD, dec!(1.05)));
+        assert_eq!(
+            rates.rates(),
+            &[
+                ExchangeRate::new(EUR, USD, dec!(1.05)),
+                ExchangeRate::new(USD, JPY, dec!(150))
+            ]
+        );
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn test_serde() {
+        let rates = StaticRates::new(&[(EUR, USD, dec!(1.1))]);
+        let json = serde_json::to_string(&rates).unwrap();
+        assert_eq!(
+            json,
+            r#"{"rates":[{"from":"EUR","to":"USD","rate":"1.1"}]}"#
+        );
+        assert_eq!(serde_json::from_str::<StaticRates>(&json).unwrap(), rates);
+    }
+}
@@ -0,0 +1,51 @@
+use std::{error::Error, fmt::Display};
+
+/// `RateSourceError` represents the errors that can occur while loading
+/// exchange rates from one of the built-in rate sources.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RateSourceError {
+    /// The document could not be parsed.
+    Parse(String),
+
+    /// The document could not be downloaded.
+    Http(String),
+}
+
+impl Error for RateSourceError {}
+
+impl Display for RateSourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use RateSourceError::*;
+        match self {
+            Parse(message) => write!(f, "invalid exchange rates: {}", message),
+            Http(message) => write!(f, "could not download exchange rates: {}", message),
+        }
+    }
+}
+
+#[cfg(any(feature = "ecb-fetch", feature = "openexchangerates-fetch"))]
+pub(crate) fn download(url: &str) -> Result<String, RateSourceError> {
+    reqwest::blocking::get(url)
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.text())
+        .map_err(|error| RateSourceError::Http(error.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        use RateSourceError::*;
+
+        assert_eq!(
+            format!("{}", Parse("oops".into())),
+            "invalid exchange rates: oops"
+        );
+        assert_eq!(
+            format!("{}", Http("timeout".into())),
+            "could not download exchange rates: timeout"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{Amount, Currency, Decimal};
+use ::fake::{Dummy, Faker, Rng};
+use std::ops::{Bound, RangeBounds};
+
+/// Currencies of the amounts generated by [`Faker`].
+const CURRENCIES: [Currency; 8] = [
+    Currency::EUR,
+    Currency::USD,
+    Currency::GBP,
+    Currency::JPY,
+    Currency::CHF,
+    Currency::CAD,
+    Currency::AUD,
+    Currency::CNY,
+];
+
+/// `AmountIn` generates fake amounts in a given currency, whose values are
+/// within a range of major units and have the number of decimal places of
+/// the currency. It is created with [`amount_in`].
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct AmountIn {
+    currency: Currency,
+    start: Bound<i64>,
+    end: Bound<i64>,
+}
+
+/// Returns an [`AmountIn`] generating fake amounts in `currency` whose
+/// values are within `range`, in major units of the currency, e.g.
+/// `amount_in(EUR, 1..1000)` generates amounts from `1.00 EUR` to
+/// `999.99 EUR`.
+///
+/// # Examples
+///
+/// ```
+/// use fake::{Fake, Faker};
+/// use oxydized_money::{amount_in, Amount, Currency::*};
+///
+/// let amount: Amount = amount_in(EUR, 1..1000).fake();
+/// assert_eq!(amount.currency(), EUR);
+/// assert!(amount.value() >= 1.into() && amount.value() < 1000.into());
+///
+/// let amount: Amount = Faker.fake();
+/// assert!(amount.value().scale() <= 2);
+/// ```
+///
+/// # Panics
+///
+/// Generating an amount panics if `range` is empty.
+pub fn amount_in<R: RangeBounds<i64>>(currency: Currency, range: R) -> AmountIn {
+    AmountIn {
+        currency,
+        start: range.start_bound().cloned(),
+        end: range.end_bound().cloned(),
+    }
+}
+
+impl Dummy<AmountIn> for Amount {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &AmountIn, rng: &mut R) -> Self {
+        let scale = u32::from(config.currency.exponent().unwrap_or_default());
+        let unit = 10i128.pow(scale);
+        let start = match config.start {
+            Bound::Included(start) => i128::from(start) * unit,
+            Bound::Excluded(start) => (i128::from(start) + 1) * unit,
+            Bound::Unbounded => i128::from(i64::MIN) * unit,
+        };
+        let end = match config.end {
+            Bound::Included(end) => i128::from(end) * unit,
+            Bound::Excluded(end) => i128::from(end) * unit - 1,
+            Bound::Unbounded => i128::from(i64::MAX) * unit,
+        };
+        let minor_units = rng.random_range(start..=end);
+        Amount(
+            Decimal::from_i128_with_scale(minor_units, scale),
+            config.currency,
+        )
+    }
+}
+
+/// With the `fake` feature, [`Faker`] generates amounts in a major currency,
+/// between zero and ten thousand major units.
+impl Dummy<Faker> for Amount {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        let currency = CURRENCIES[rng.random_range(0..CURRENCIES.len())];
+        Amount::dummy_with_rng(&amount_in(currency, 0..10_000), rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::amount_in;
+    use crate as oxydized_money;
+    use fake::{
+        rand::{rngs::StdRng, SeedableRng},
+        Fake, Faker,
+    };
+    use oxydized_money::{Amount, Currency::*, Decimal};
+    use oxydized_money_macros::{bhd, eur, jpy};
+
+    #[test]
+    fn test_amount_in() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let amount: Amount = amount_in(EUR, 1..1000).fake_with_rng(&mut rng);
+            assert_eq!(amount.currency(), EUR);
+            assert_eq!(amount.value().scale(), 2);
+            assert!(amount >= eur!(1) && amount <= eur!(999.99));
+
+            let amount: Amount = amount_in(JPY, -10..=10).fake_with_rng(&mut rng);
+            assert_eq!(amount.value().scale(), 0);
+            assert!(amount >= jpy!(-10) && amount <= jpy!(10));
+
+            let amount: Amount = amount_in(BHD, 0..1).fake_with_rng(&mut rng);
+            assert!(amount >= bhd!(0) && amount <= bhd!(0.999));
+        }
+        let amount: Amount = amount_in(EUR, 5..=5).fake();
+        assert_eq!(amount, eur!(5));
+    }
+
+    #[test]
+    fn test_faker() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let amount: Amount = Faker.fake_with_rng(&mut rng);
+            let scale = amount.currency().exponent().unwrap_or_default();
+            assert_eq!(amount.value().scale(), u32::from(scale));
+            assert!(amount.value() >= Decimal::ZERO);
+            assert!(amount.value() < Decimal::from(10_000));
+        }
+    }
+}
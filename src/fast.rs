@@ -0,0 +1,315 @@
+use crate::{
+    Amount, Currency,
+    CurrencyError::{self, Mismatch, Overflow},
+    Decimal, Result,
+};
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// `FastAmount` represents an amount of money stored as a 64-bit integer
+/// number of minor units of its currency (e.g. cents for `EUR`, yens for
+/// `JPY`), for hot paths where a 128-bit [`Decimal`] per amount is
+/// overkill. Currencies without minor units (e.g. `XAU`) are stored as
+/// whole units.
+///
+/// Fast amounts mirror the operations of [`Amount`]: additions and
+/// subtractions are currency-checked and output a [`Result`] whose error
+/// is a [`CurrencyError::Mismatch`], or a [`CurrencyError::Overflow`] if
+/// the result does not fit in 64 bits. As with
+/// [`AmountResult`](crate::AmountResult)s, these results can be chained:
+/// fast amounts can be added to or subtracted from a `Result<FastAmount>`
+/// and vice versa, an error being carried through. However, two `Result`s
+/// can neither be added together, nor multiplied or negated, as the
+/// operators cannot be implemented for `Result` alone.
+///
+/// Fast amounts can always be converted into [`Amount`]s without loss, and
+/// [`Amount`]s can be converted into fast amounts if they do not have more
+/// decimal places than their currency and fit in 64 bits.
+///
+/// # Panics
+///
+/// As for integers and `Amount * Decimal`, multiplying or negating a
+/// [`FastAmount`] panics on overflow. Use [`FastAmount::checked_mul`] and
+/// [`FastAmount::checked_neg`] instead to get a
+/// [`CurrencyError::Overflow`].
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, CurrencyError, FastAmount, Decimal, Currency::{EUR, USD}};
+/// use oxydized_money_macros::{eur, usd};
+///
+/// let price = FastAmount::from_amount(eur!(10.25)).unwrap();
+/// assert_eq!(price.minor_units(), 1025);
+/// let total = (price * 3 + FastAmount::new(5, EUR)).unwrap();
+/// assert_eq!(Amount::from(total), eur!(30.80));
+/// let chained = price + FastAmount::new(5, EUR) - FastAmount::new(30, EUR);
+/// assert_eq!(chained, Ok(FastAmount::new(1000, EUR)));
+/// assert_eq!(price.checked_mul(i64::MAX), Err(CurrencyError::Overflow));
+/// assert!((total + FastAmount::new(5, USD)).is_err());
+/// assert_eq!(FastAmount::from_amount(eur!(10.255)), None);
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct FastAmount {
+    minor_units: i64,
+    currency: Currency,
+}
+
+impl FastAmount {
+    /// Creates a new [`FastAmount`] of `minor_units` in `currency`.
+    pub fn new(minor_units: i64, currency: Currency) -> Self {
+        Self {
+            minor_units,
+            currency,
+        }
+    }
+
+    /// Converts `amount` into a [`FastAmount`], or returns `None` if the
+    /// conversion would lose precision or overflow (see the [`TryFrom`]
+    /// implementation for the corresponding errors).
+    pub fn from_amount(amount: Amount) -> Option<Self> {
+        Self::try_from(amount).ok()
+    }
+
+    /// Returns the number of minor units of the amount.
+    pub fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    /// Returns the currency of the amount.
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns the amount multiplied by `rhs`, or a
+    /// [`CurrencyError::Overflow`] if the result does not fit in 64 bits.
+    pub fn checked_mul(self, rhs: i64) -> Result<FastAmount> {
+        let minor_units = self.minor_units.checked_mul(rhs).ok_or(Overflow)?;
+        Ok(Self::new(minor_units, self.currency))
+    }
+
+    /// Returns the opposite of the amount, or a [`CurrencyError::Overflow`]
+    /// if the result does not fit in 64 bits.
+    pub fn checked_neg(self) -> Result<FastAmount> {
+        let minor_units = self.minor_units.checked_neg().ok_or(Overflow)?;
+        Ok(Self::new(minor_units, self.currency))
+    }
+
+    /// Returns the quantity of money, in units of the currency.
+    pub fn value(&self) -> Decimal {
+        Decimal::new(self.minor_units, Amount::minor_scale(self.currency))
+    }
+}
+
+impl From<FastAmount> for Amount {
+    fn from(amount: FastAmount) -> Self {
+        Amount(amount.value(), amount.currency())
+    }
+}
+
+impl TryFrom<Amount> for FastAmount {
+    type Error = CurrencyError;
+
+    fn try_from(amount: Amount) -> Result<Self> {
+        let scale = Amount::minor_scale(amount.currency());
+        let minor_units = i64::try_from(amount.scaled_units(scale)?).map_err(|_| Overflow)?;
+        Ok(Self::new(minor_units, amount.currency()))
+    }
+}
+
+impl Display for FastAmount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&Amount::from(*self), f)
+    }
+}
+
+impl PartialOrd for FastAmount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.currency == other.currency {
+            self.minor_units.partial_cmp(&other.minor_units)
+        } else {
+            None
+        }
+    }
+}
+
+impl Add for FastAmount {
+    type Output = Result<FastAmount>;
+
+    fn add(self, rhs: FastAmount) -> Self::Output {
+        if self.currency == rhs.currency {
+            let minor_units = self.minor_units.checked_add(rhs.minor_units);
+            Ok(Self::new(minor_units.ok_or(Overflow)?, self.currency))
+        } else {
+            Err(Mismatch(self.currency, rhs.currency))
+        }
+    }
+}
+
+impl Sub for FastAmount {
+    type Output = Result<FastAmount>;
+
+    fn sub(self, rhs: FastAmount) -> Self::Output {
+        if self.currency == rhs.currency {
+            let minor_units = self.minor_units.checked_sub(rhs.minor_units);
+            Ok(Self::new(minor_units.ok_or(Overflow)?, self.currency))
+        } else {
+            Err(Mismatch(self.currency, rhs.currency))
+        }
+    }
+}
+
+impl Add<Result<FastAmount>> for FastAmount {
+    type Output = Result<FastAmount>;
+
+    fn add(self, rhs: Result<FastAmount>) -> Self::Output {
+        self + rhs?
+    }
+}
+
+impl Add<FastAmount> for Result<FastAmount> {
+    type Output = Result<FastAmount>;
+
+    fn add(self, rhs: FastAmount) -> Self::Output {
+        self? + rhs
+    }
+}
+
+impl Sub<Result<FastAmount>> for FastAmount {
+    type Output = Result<FastAmount>;
+
+    fn sub(self, rhs: Result<FastAmount>) -> Self::Output {
+        self - rhs?
+    }
+}
+
+impl Sub<FastAmount> for Result<FastAmount> {
+    type Output = Result<FastAmount>;
+
+    fn sub(self, rhs: FastAmount) -> Self::Output {
+        self? - rhs
+    }
+}
+
+impl Mul<i64> for FastAmount {
+    type Output = FastAmount;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        self.checked_mul(rhs).expect("overflow")
+    }
+}
+
+impl Neg for FastAmount {
+    type Output = FastAmount;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("overflow")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, Decimal, FastAmount};
+    use oxydized_money_macros::{bhd, dec, eur, jpy, usd, xau};
+
+    #[test]
+    fn test_from_amount() {
+        let fast = |amount| FastAmount::from_amount(amount).map(|f| f.minor_units());
+        assert_eq!(fast(eur!(1.5)), Some(150));
+        assert_eq!(fast(eur!(-1.50)), Some(-150));
+        assert_eq!(fast(eur!(1.000)), Some(100));
+        assert_eq!(fast(eur!(1.001)), None);
+        assert_eq!(fast(jpy!(100)), Some(100));
+        assert_eq!(fast(jpy!(0.5)), None);
+        assert_eq!(fast(bhd!(1.234)), Some(1234));
+        assert_eq!(fast(xau!(3)), Some(3));
+        assert_eq!(fast(eur!(92233720368547758.07)), Some(i64::MAX));
+        assert_eq!(fast(eur!(92233720368547758.08)), None);
+    }
+
+    #[test]
+    fn test_into_amount() {
+        for amount in [eur!(1.5), eur!(-0.01), jpy!(7), bhd!(0.001), usd!(0)] {
+            let fast = FastAmount::from_amount(amount).unwrap();
+            assert_eq!(Amount::from(fast), amount);
+            assert_eq!(fast.value(), amount.value());
+            assert_eq!(fast.currency(), amount.currency());
+        }
+        assert_eq!(Amount::from(FastAmount::new(12345, EUR)), eur!(123.45));
+        assert_eq!(format!("{}", FastAmount::new(12345, EUR)), "€ 123.45");
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = FastAmount::new(150, EUR);
+        let b = FastAmount::new(25, EUR);
+        assert_eq!(a + b, Ok(FastAmount::new(175, EUR)));
+        assert_eq!(a - b, Ok(FastAmount::new(125, EUR)));
+        assert_eq!(a * -2, FastAmount::new(-300, EUR));
+        assert_eq!(-a, FastAmount::new(-150, EUR));
+        assert_eq!(a + FastAmount::new(1, USD), Err(Mismatch(EUR, USD)));
+        assert_eq!(a - FastAmount::new(1, USD), Err(Mismatch(EUR, USD)));
+        assert!(b < a);
+        assert_eq!(a.partial_cmp(&FastAmount::new(1, USD)), None);
+        assert_eq!(Amount::from(a * 3), eur!(1.5) * dec!(3));
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(
+            FastAmount::try_from(eur!(1.5)),
+            Ok(FastAmount::new(150, EUR))
+        );
+        assert_eq!(
+            FastAmount::try_from(eur!(1.001)),
+            Err(InvalidPrecision(EUR))
+        );
+        assert_eq!(FastAmount::try_from(jpy!(0.5)), Err(InvalidPrecision(JPY)));
+        assert_eq!(
+            FastAmount::try_from(eur!(92233720368547758.08)),
+            Err(Overflow)
+        );
+        assert_eq!(
+            FastAmount::try_from(Amount(Decimal::MAX, EUR)),
+            Err(Overflow)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = FastAmount::new(i64::MAX, EUR);
+        assert_eq!(max + FastAmount::new(1, EUR), Err(Overflow));
+        assert_eq!(-max - FastAmount::new(2, EUR), Err(Overflow));
+        assert_eq!(
+            max - FastAmount::new(1, EUR),
+            Ok(FastAmount::new(i64::MAX - 1, EUR))
+        );
+        assert_eq!(max.checked_mul(2), Err(Overflow));
+        assert_eq!(max.checked_mul(-1), Ok(-max));
+        assert_eq!(max.checked_neg(), Ok(FastAmount::new(-i64::MAX, EUR)));
+        assert_eq!(FastAmount::new(i64::MIN, EUR).checked_neg(), Err(Overflow));
+    }
+
+    #[test]
+    fn test_chaining() {
+        let a = FastAmount::new(150, EUR);
+        let b = FastAmount::new(25, EUR);
+        assert_eq!(a + b + b, Ok(FastAmount::new(200, EUR)));
+        assert_eq!(a - b - b, Ok(FastAmount::new(100, EUR)));
+        assert_eq!(a + (a - b), Ok(FastAmount::new(275, EUR)));
+        assert_eq!(a - (a + b), Ok(FastAmount::new(-25, EUR)));
+        assert_eq!(a + FastAmount::new(1, USD) + b, Err(Mismatch(EUR, USD)));
+        assert_eq!(a - (b + FastAmount::new(1, JPY)), Err(Mismatch(EUR, JPY)));
+        assert_eq!(FastAmount::new(i64::MAX, EUR) + b - b, Err(Overflow));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_overflow() {
+        let _ = FastAmount::new(i64::MAX, EUR) * 2;
+    }
+}
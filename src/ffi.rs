@@ -0,0 +1,496 @@
+//! C-compatible interface, for the `ffi` feature.
+//!
+//! Amounts cross the FFI boundary as an [`OxmAmount`], i.e. a decimal value
+//! (a 128 bits two's complement mantissa split in two 64 bits halves, and a
+//! scale between 0 and 28) along with the ISO 4217 numeric code of its
+//! currency. Every function returns an [`OxmStatus`] and writes its result
+//! through an out pointer, so that errors are reported as status codes
+//! rather than panics.
+//!
+//! ```c
+//! OxmAmount price, total;
+//! OxmDecimal quantity = {0, 3, 0};
+//! if (oxm_amount_parse("10.50 EUR", &price) == OXM_STATUS_OK &&
+//!     oxm_amount_mul(price, quantity, &total) == OXM_STATUS_OK) {
+//!     char buffer[64];
+//!     oxm_amount_format(total, buffer, sizeof(buffer)); // "31.50 EUR"
+//! }
+//! ```
+
+use crate::{Amount, Currency, CurrencyError, Decimal};
+use std::{
+    cmp::Ordering,
+    ffi::{c_char, CStr},
+};
+
+/// A decimal number equal to `mantissa / 10^scale`, where the mantissa is
+/// the 128 bits two's complement integer `mantissa_high << 64 |
+/// mantissa_low`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OxmDecimal {
+    /// Most significant 64 bits of the mantissa.
+    pub mantissa_high: i64,
+    /// Least significant 64 bits of the mantissa.
+    pub mantissa_low: u64,
+    /// Number of decimal places, between 0 and 28.
+    pub scale: u32,
+}
+
+/// C-compatible representation of an [`Amount`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OxmAmount {
+    /// Value of the amount.
+    pub value: OxmDecimal,
+    /// ISO 4217 numeric code of the currency of the amount.
+    pub currency: u16,
+}
+
+/// Status returned by the functions of the C interface.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OxmStatus {
+    /// The operation succeeded.
+    Ok = 0,
+    /// The amounts are in different currencies, see [`CurrencyError::Mismatch`].
+    Mismatch = 1,
+    /// Division by zero, see [`CurrencyError::DivideByZero`].
+    DivideByZero = 2,
+    /// See [`CurrencyError::Unknown`].
+    Unknown = 3,
+    /// See [`CurrencyError::MissingRate`].
+    MissingRate = 4,
+    /// The result does not fit in a decimal, see [`CurrencyError::Overflow`].
+    Overflow = 5,
+    /// See [`CurrencyError::InvalidPrecision`].
+    InvalidPrecision = 6,
+    /// A decimal has a scale above 28 or a mantissa above 96 bits, or a
+    /// string is not a valid amount.
+    InvalidArgument = 7,
+    /// The currency is not a known ISO 4217 numeric code.
+    UnknownCurrency = 8,
+    /// A pointer argument is null.
+    NullPointer = 9,
+    /// The output buffer is too small.
+    BufferTooSmall = 10,
+}
+
+impl From<CurrencyError> for OxmStatus {
+    fn from(error: CurrencyError) -> Self {
+        match error {
+            CurrencyError::Mismatch(_, _) => OxmStatus::Mismatch,
+            CurrencyError::DivideByZero => OxmStatus::DivideByZero,
+            CurrencyError::Unknown => OxmStatus::Unknown,
+            CurrencyError::MissingRate(_, _) => OxmStatus::MissingRate,
+            CurrencyError::Overflow => OxmStatus::Overflow,
+            CurrencyError::InvalidPrecision(_) => OxmStatus::InvalidPrecision,
+        }
+    }
+}
+
+impl From<Decimal> for OxmDecimal {
+    fn from(value: Decimal) -> Self {
+        let mantissa = value.mantissa();
+        OxmDecimal {
+            mantissa_high: (mantissa >> 64) as i64,
+            mantissa_low: mantissa as u64,
+            scale: value.scale(),
+        }
+    }
+}
+
+impl TryFrom<OxmDecimal> for Decimal {
+    type Error = OxmStatus;
+
+    fn try_from(value: OxmDecimal) -> Result<Self, OxmStatus> {
+        let mantissa = i128::from(value.mantissa_high) << 64 | i128::from(value.mantissa_low);
+        Decimal::try_from_i128_with_scale(mantissa, value.scale)
+            .map_err(|_| OxmStatus::InvalidArgument)
+    }
+}
+
+impl From<Amount> for OxmAmount {
+    fn from(amount: Amount) -> Self {
+        OxmAmount {
+            value: amount.value().into(),
+            currency: amount.currency().numeric(),
+        }
+    }
+}
+
+impl TryFrom<OxmAmount> for Amount {
+    type Error = OxmStatus;
+
+    fn try_from(amount: OxmAmount) -> Result<Self, OxmStatus> {
+        let value = Decimal::try_from(amount.value)?;
+        let currency = Currency::from_numeric(amount.currency).ok_or(OxmStatus::UnknownCurrency)?;
+        Ok(Amount(value, currency))
+    }
+}
+
+/// Writes the result of an operation to `out`, returning its status.
+unsafe fn write<T, R: Into<T>>(out: *mut T, result: Result<R, OxmStatus>) -> OxmStatus {
+    if out.is_null() {
+        return OxmStatus::NullPointer;
+    }
+    match result {
+        Ok(value) => {
+            out.write(value.into());
+            OxmStatus::Ok
+        }
+        Err(status) => status,
+    }
+}
+
+fn binary<F>(a: OxmAmount, b: OxmAmount, f: F) -> Result<Amount, OxmStatus>
+where
+    F: FnOnce(Amount, Amount) -> crate::AmountResult,
+{
+    Ok(f(a.try_into()?, b.try_into()?).into_inner()?)
+}
+
+/// Creates an amount of `value` in the currency whose ISO 4217 numeric code
+/// is `currency`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_new(
+    value: OxmDecimal,
+    currency: u16,
+    out: *mut OxmAmount,
+) -> OxmStatus {
+    let amount = OxmAmount { value, currency };
+    write(out, Amount::try_from(amount))
+}
+
+/// Creates an amount from a number of minor units of a currency, e.g.
+/// `1050` cents for `10.50 EUR`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_from_minor_units(
+    minor_units: i64,
+    currency: u16,
+    out: *mut OxmAmount,
+) -> OxmStatus {
+    let amount = Currency::from_numeric(currency)
+        .ok_or(OxmStatus::UnknownCurrency)
+        .map(|currency| {
+            let scale = currency.exponent().unwrap_or_default().into();
+            Amount(Decimal::new(minor_units, scale), currency)
+        });
+    write(out, amount)
+}
+
+/// Parses an amount from a NUL-terminated string holding its value and its
+/// currency code separated by a space, e.g. `"10.50 EUR"`.
+///
+/// # Safety
+///
+/// `text` must be null or point to a NUL-terminated string, and `out` must
+/// be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_parse(text: *const c_char, out: *mut OxmAmount) -> OxmStatus {
+    if text.is_null() {
+        return OxmStatus::NullPointer;
+    }
+    let amount = CStr::from_ptr(text)
+        .to_str()
+        .ok()
+        .and_then(Amount::parse_canonical)
+        .ok_or(OxmStatus::InvalidArgument);
+    write(out, amount)
+}
+
+/// Formats an amount as a NUL-terminated string holding its value and its
+/// currency code separated by a space, e.g. `"10.50 EUR"`. Fails with
+/// [`OxmStatus::BufferTooSmall`] if the string and its terminating NUL do
+/// not fit in the `len` bytes of `buffer`.
+///
+/// # Safety
+///
+/// `buffer` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_format(
+    amount: OxmAmount,
+    buffer: *mut c_char,
+    len: usize,
+) -> OxmStatus {
+    if buffer.is_null() {
+        return OxmStatus::NullPointer;
+    }
+    let text = match Amount::try_from(amount) {
+        Ok(amount) => amount.canonical(),
+        Err(status) => return status,
+    };
+    if text.len() >= len {
+        return OxmStatus::BufferTooSmall;
+    }
+    std::ptr::copy_nonoverlapping(text.as_ptr().cast(), buffer, text.len());
+    buffer.add(text.len()).write(0);
+    OxmStatus::Ok
+}
+
+/// Adds two amounts in the same currency.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_add(
+    a: OxmAmount,
+    b: OxmAmount,
+    out: *mut OxmAmount,
+) -> OxmStatus {
+    write(out, binary(a, b, |a, b| a + b))
+}
+
+/// Subtracts an amount from another one in the same currency.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_sub(
+    a: OxmAmount,
+    b: OxmAmount,
+    out: *mut OxmAmount,
+) -> OxmStatus {
+    write(out, binary(a, b, |a, b| a - b))
+}
+
+/// Negates an amount.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_neg(a: OxmAmount, out: *mut OxmAmount) -> OxmStatus {
+    write(out, Amount::try_from(a).map(|a| -a))
+}
+
+/// Multiplies an amount by a decimal factor.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_mul(
+    a: OxmAmount,
+    factor: OxmDecimal,
+    out: *mut OxmAmount,
+) -> OxmStatus {
+    let result = Amount::try_from(a).and_then(|a| {
+        let factor = Decimal::try_from(factor)?;
+        let value = a.value().checked_mul(factor).ok_or(OxmStatus::Overflow)?;
+        Ok(Amount(value, a.currency()))
+    });
+    write(out, result)
+}
+
+/// Divides an amount by a decimal divisor.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_div(
+    a: OxmAmount,
+    divisor: OxmDecimal,
+    out: *mut OxmAmount,
+) -> OxmStatus {
+    let result =
+        Amount::try_from(a).and_then(|a| Ok((a / Decimal::try_from(divisor)?).into_inner()?));
+    write(out, result)
+}
+
+/// Compares two amounts in the same currency, writing `-1`, `0` or `1` to
+/// `out` if `a` is respectively less than, equal to or greater than `b`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn oxm_amount_cmp(a: OxmAmount, b: OxmAmount, out: *mut i32) -> OxmStatus {
+    let result = Amount::try_from(a).and_then(|a| {
+        let b = Amount::try_from(b)?;
+        if a.currency() != b.currency() {
+            return Err(OxmStatus::Mismatch);
+        }
+        Ok(match a.value().cmp(&b.value()) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        })
+    });
+    write(out, result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate as oxydized_money;
+    use oxydized_money::Currency::*;
+    use oxydized_money_macros::{dec, eur, jpy, usd};
+
+    fn call<F>(f: F) -> Result<Amount, OxmStatus>
+    where
+        F: FnOnce(*mut OxmAmount) -> OxmStatus,
+    {
+        let mut out = OxmAmount::default();
+        match f(&mut out) {
+            OxmStatus::Ok => Ok(Amount::try_from(out).unwrap()),
+            status => Err(status),
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        for amount in [eur!(10.50), jpy!(-1200), Amount(Decimal::MIN, USD)] {
+            assert_eq!(Amount::try_from(OxmAmount::from(amount)), Ok(amount));
+        }
+        let amount = OxmAmount::from(eur!(-10.50));
+        assert_eq!(amount.value.mantissa_high, -1);
+        assert_eq!(amount.value.mantissa_low, -1050i64 as u64);
+        assert_eq!(amount.value.scale, 2);
+        assert_eq!(amount.currency, 978);
+
+        let invalid = OxmDecimal {
+            scale: 29,
+            ..OxmDecimal::default()
+        };
+        assert_eq!(Decimal::try_from(invalid), Err(OxmStatus::InvalidArgument));
+        let invalid = OxmDecimal {
+            mantissa_high: 1 << 32,
+            ..OxmDecimal::default()
+        };
+        assert_eq!(Decimal::try_from(invalid), Err(OxmStatus::InvalidArgument));
+    }
+
+    #[test]
+    fn test_constructors() {
+        let value = OxmDecimal::from(dec!(10.50));
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_new(value, 978, out) }),
+            Ok(eur!(10.50))
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_new(value, 1, out) }),
+            Err(OxmStatus::UnknownCurrency)
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_from_minor_units(1050, 978, out) }),
+            Ok(eur!(10.50))
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_from_minor_units(-7, 392, out) }),
+            Ok(jpy!(-7))
+        );
+        assert_eq!(
+            unsafe { oxm_amount_new(value, 978, std::ptr::null_mut()) },
+            OxmStatus::NullPointer
+        );
+    }
+
+    #[test]
+    fn test_parse_and_format() {
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_parse(c"10.50 EUR".as_ptr(), out) }),
+            Ok(eur!(10.50))
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_parse(c"10.50".as_ptr(), out) }),
+            Err(OxmStatus::InvalidArgument)
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_parse(std::ptr::null(), out) }),
+            Err(OxmStatus::NullPointer)
+        );
+
+        let mut buffer = [1 as c_char; 10];
+        let amount = OxmAmount::from(eur!(10.50));
+        let status = unsafe { oxm_amount_format(amount, buffer.as_mut_ptr(), buffer.len()) };
+        assert_eq!(status, OxmStatus::Ok);
+        let text = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(text.to_str(), Ok("10.50 EUR"));
+        let status = unsafe { oxm_amount_format(amount, buffer.as_mut_ptr(), 9) };
+        assert_eq!(status, OxmStatus::BufferTooSmall);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (OxmAmount::from(eur!(10.50)), OxmAmount::from(eur!(0.25)));
+        let three = OxmDecimal::from(dec!(3));
+        let zero = OxmDecimal::default();
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_add(a, b, out) }),
+            Ok(eur!(10.75))
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_sub(a, b, out) }),
+            Ok(eur!(10.25))
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_neg(a, out) }),
+            Ok(eur!(-10.50))
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_mul(a, three, out) }),
+            Ok(eur!(31.50))
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_div(a, three, out) }),
+            Ok(eur!(3.50))
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_div(a, zero, out) }),
+            Err(OxmStatus::DivideByZero)
+        );
+
+        let usd = OxmAmount::from(usd!(1));
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_add(a, usd, out) }),
+            Err(OxmStatus::Mismatch)
+        );
+        let max = OxmAmount::from(Amount(Decimal::MAX, EUR));
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_add(max, max, out) }),
+            Err(OxmStatus::Overflow)
+        );
+        assert_eq!(
+            call(|out| unsafe { oxm_amount_mul(max, three, out) }),
+            Err(OxmStatus::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_cmp() {
+        let (a, b) = (OxmAmount::from(eur!(10.50)), OxmAmount::from(eur!(0.25)));
+        let mut ordering = 2;
+        assert_eq!(
+            unsafe { oxm_amount_cmp(a, b, &mut ordering) },
+            OxmStatus::Ok
+        );
+        assert_eq!(ordering, 1);
+        assert_eq!(
+            unsafe { oxm_amount_cmp(b, a, &mut ordering) },
+            OxmStatus::Ok
+        );
+        assert_eq!(ordering, -1);
+        assert_eq!(
+            unsafe { oxm_amount_cmp(a, a, &mut ordering) },
+            OxmStatus::Ok
+        );
+        assert_eq!(ordering, 0);
+        let usd = OxmAmount::from(usd!(1));
+        assert_eq!(
+            unsafe { oxm_amount_cmp(a, usd, &mut ordering) },
+            OxmStatus::Mismatch
+        );
+    }
+}
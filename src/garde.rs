@@ -0,0 +1,160 @@
+//! Validators for [`garde`](::garde), for the `garde` feature.
+//!
+//! The validators are used as `garde` custom rules on fields holding an
+//! [`Amount`], and are generic over the validation context, so that they
+//! can be used in any structure deriving `Validate`:
+//!
+//! ```ignore
+//! use garde::Validate;
+//! use oxydized_money::{garde::*, Amount, Currency::*, Decimal};
+//! use oxydized_money_macros::eur;
+//!
+//! #[derive(Validate)]
+//! struct Order {
+//!     #[garde(custom(currency_in(&[EUR, USD])), custom(currency_scale))]
+//!     #[garde(custom(min(eur!(1))), custom(max(eur!(1000))))]
+//!     total: Amount,
+//! }
+//! ```
+//!
+//! The validators can also be called directly:
+//!
+//! ```
+//! use oxydized_money::{garde::*, Currency::*, Decimal};
+//! use oxydized_money_macros::{eur, usd};
+//!
+//! assert!(min(eur!(1))(&eur!(10), &()).is_ok());
+//! assert!(min(eur!(1))(&eur!(0.50), &()).is_err());
+//! assert!(min(eur!(1))(&usd!(10), &()).is_err());
+//! assert!(currency_in(&[EUR, USD])(&usd!(10), &()).is_ok());
+//! assert!(currency_scale(&eur!(10.001), &()).is_err());
+//! ```
+
+use crate::{Amount, Currency};
+use ::garde::{Error, Result};
+
+/// Checks that an amount is in the currency of `minimum` and greater than
+/// or equal to it.
+pub fn min<C>(minimum: Amount) -> impl FnOnce(&Amount, &C) -> Result {
+    move |amount, _| {
+        same_currency(amount, minimum)?;
+        if amount.value() < minimum.value() {
+            return Err(Error::new(format!(
+                "lower than {} {}",
+                minimum.value(),
+                minimum.currency().code()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Checks that an amount is in the currency of `maximum` and less than or
+/// equal to it.
+pub fn max<C>(maximum: Amount) -> impl FnOnce(&Amount, &C) -> Result {
+    move |amount, _| {
+        same_currency(amount, maximum)?;
+        if amount.value() > maximum.value() {
+            return Err(Error::new(format!(
+                "greater than {} {}",
+                maximum.value(),
+                maximum.currency().code()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Checks that an amount is in one of the given `currencies`.
+pub fn currency_in<C>(currencies: &[Currency]) -> impl FnOnce(&Amount, &C) -> Result + '_ {
+    move |amount, _| {
+        if currencies.contains(&amount.currency()) {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "currency {} is not allowed",
+                amount.currency().code()
+            )))
+        }
+    }
+}
+
+/// Checks that an amount has no more decimal places than its currency, e.g.
+/// `10.50 EUR` is valid but `10.505 EUR` is not.
+pub fn currency_scale<C>(amount: &Amount, _: &C) -> Result {
+    let scale = u32::from(amount.currency().exponent().unwrap_or_default());
+    if amount.value().normalize().scale() > scale {
+        return Err(Error::new(format!(
+            "more than {} decimal places for {}",
+            scale,
+            amount.currency().code()
+        )));
+    }
+    Ok(())
+}
+
+fn same_currency(amount: &Amount, expected: Amount) -> Result {
+    if amount.currency() != expected.currency() {
+        return Err(Error::new(format!(
+            "currency {} is not {}",
+            amount.currency().code(),
+            expected.currency().code()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{currency_in, currency_scale, max, min};
+    use crate as oxydized_money;
+    use oxydized_money::{Currency::*, Decimal};
+    use oxydized_money_macros::{bhd, eur, jpy, usd};
+
+    #[test]
+    fn test_min() {
+        assert!(min(eur!(1))(&eur!(1), &()).is_ok());
+        assert!(min(eur!(1))(&eur!(1000), &()).is_ok());
+        assert_eq!(
+            min(eur!(1))(&eur!(0.99), &()).unwrap_err().to_string(),
+            "lower than 1 EUR"
+        );
+        assert_eq!(
+            min(eur!(1))(&usd!(10), &()).unwrap_err().to_string(),
+            "currency USD is not EUR"
+        );
+    }
+
+    #[test]
+    fn test_max() {
+        assert!(max(eur!(1000))(&eur!(1000), &()).is_ok());
+        assert!(max(eur!(1000))(&eur!(-5), &()).is_ok());
+        assert!(max(eur!(1000))(&eur!(1000.01), &()).is_err());
+        assert!(max(eur!(1000))(&jpy!(10), &()).is_err());
+    }
+
+    #[test]
+    fn test_currency_in() {
+        assert!(currency_in(&[EUR, USD])(&eur!(1), &()).is_ok());
+        assert!(currency_in(&[EUR, USD])(&usd!(1), &()).is_ok());
+        assert_eq!(
+            currency_in(&[EUR, USD])(&jpy!(1), &())
+                .unwrap_err()
+                .to_string(),
+            "currency JPY is not allowed"
+        );
+    }
+
+    #[test]
+    fn test_currency_scale() {
+        assert!(currency_scale(&eur!(10.50), &()).is_ok());
+        assert!(currency_scale(&eur!(10.5000), &()).is_ok());
+        assert!(currency_scale(&bhd!(1.125), &()).is_ok());
+        assert!(currency_scale(&jpy!(12), &()).is_ok());
+        assert_eq!(
+            currency_scale(&eur!(10.505), &()).unwrap_err().to_string(),
+            "more than 2 decimal places for EUR"
+        );
+        assert!(currency_scale(&jpy!(1.5), &()).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    Amount, Currency,
+    CurrencyError::{self, Overflow},
+    Decimal,
+};
+use std::{error::Error, fmt::Display};
+
+const NANOS_PER_UNIT: i128 = 1_000_000_000;
+const MAX_NANOS: i32 = 999_999_999;
+
+/// `GoogleMoney` mirrors the `google.type.Money` protobuf message used by
+/// APIs following the Google API conventions: an amount is represented by
+/// a currency code, a number of whole `units` and a number of `nanos`
+/// (10^-9 units), both carrying the sign of the amount.
+///
+/// With the `prost` feature, `GoogleMoney` is a [`prost::Message`] that is
+/// wire compatible with `google.type.Money`, so it can be decoded from or
+/// encoded to gRPC payloads directly. Otherwise, its fields can be copied
+/// from or to the type generated for `google.type.Money` by any other
+/// protobuf library.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Decimal, GoogleMoney};
+/// use oxydized_money_macros::eur;
+///
+/// let money = GoogleMoney::try_from(eur!(-10.75)).unwrap();
+/// assert_eq!(money.currency_code, "EUR");
+/// assert_eq!(money.units, -10);
+/// assert_eq!(money.nanos, -750_000_000);
+/// assert_eq!(Amount::try_from(money), Ok(eur!(-10.75)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(not(feature = "prost"), derive(Default))]
+#[cfg_attr(feature = "prost", derive(prost::Message), prost(skip_debug))]
+pub struct GoogleMoney {
+    /// The three-letter currency code defined in ISO 4217.
+    #[cfg_attr(feature = "prost", prost(string, tag = "1"))]
+    pub currency_code: String,
+
+    /// The whole units of the amount.
+    #[cfg_attr(feature = "prost", prost(int64, tag = "2"))]
+    pub units: i64,
+
+    /// Number of nano (10^-9) units of the amount, between -999,999,999
+    /// and +999,999,999 inclusive, with the same sign as `units`.
+    #[cfg_attr(feature = "prost", prost(int32, tag = "3"))]
+    pub nanos: i32,
+}
+
+/// `GoogleMoneyError` represents the reasons why a [`GoogleMoney`] is not
+/// a valid [`Amount`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GoogleMoneyError {
+    /// The currency code is not a known ISO 4217 code.
+    UnknownCurrency(String),
+
+    /// The number of nanos is not between -999,999,999 and +999,999,999.
+    NanosOutOfRange(i32),
+
+    /// The units and the nanos have different signs.
+    SignMismatch {
+        /// The whole units of the amount.
+        units: i64,
+        /// The nano units of the amount.
+        nanos: i32,
+    },
+}
+
+impl Error for GoogleMoneyError {}
+
+impl Display for GoogleMoneyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use GoogleMoneyError::*;
+        match self {
+            UnknownCurrency(code) => write!(f, "unknown currency code '{}'", code),
+            NanosOutOfRange(nanos) => write!(f, "nanos {} out of range", nanos),
+            SignMismatch { units, nanos } => write!(
+                f,
+                "units {} and nanos {} have different signs",
+                units, nanos
+            ),
+        }
+    }
+}
+
+impl TryFrom<Amount> for GoogleMoney {
+    type Error = CurrencyError;
+
+    /// Converts an amount to a [`GoogleMoney`]. Fails with
+    /// [`CurrencyError::InvalidPrecision`] if the amount has more than nine
+    /// decimal places, or with [`CurrencyError::Overflow`] if its units do
+    /// not fit in an `i64`.
+    fn try_from(amount: Amount) -> Result<Self, CurrencyError> {
+        let nanos = amount.scaled_units(9)?;
+        Ok(GoogleMoney {
+            currency_code: amount.currency().code().to_owned(),
+            units: i64::try_from(nanos / NANOS_PER_UNIT).map_err(|_| Overflow)?,
+            nanos: (nanos % NANOS_PER_UNIT) as i32,
+        })
+    }
+}
+
+impl TryFrom<GoogleMoney> for Amount {
+    type Error = GoogleMoneyError;
+
+    /// Converts a [`GoogleMoney`] to an amount, with at least as many
+    /// decimal places as its currency uses. Fails if the currency code is
+    /// unknown, if the nanos are out of range or if the units and the nanos
+    /// have different signs.
+    fn try_from(money: GoogleMoney) -> Result<Self, GoogleMoneyError> {
+        let currency = Currency::from_code(&money.currency_code)
+            .ok_or_else(|| GoogleMoneyError::UnknownCurrency(money.currency_code.clone()))?;
+        if !(-MAX_NANOS..=MAX_NANOS).contains(&money.nanos) {
+            return Err(GoogleMoneyError::NanosOutOfRange(money.nanos));
+        }
+        if (money.units > 0 && money.nanos < 0) || (money.units < 0 && money.nanos > 0) {
+            return Err(GoogleMoneyError::SignMismatch {
+                units: money.units,
+                nanos: money.nanos,
+            });
+        }
+        let nanos = i128::from(money.units) * NANOS_PER_UNIT + i128::from(money.nanos);
+        let mut value = Decimal::from_i128_with_scale(nanos, 9).normalize();
+        let exponent = currency.exponent().unwrap_or_default().into();
+        if value.scale() < exponent {
+            value.rescale(exponent);
+        }
+        Ok(Amount(value, currency))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GoogleMoney, GoogleMoneyError};
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, Decimal};
+    use oxydized_money_macros::{bhd, eur, jpy};
+
+    fn money(currency_code: &str, units: i64, nanos: i32) -> GoogleMoney {
+        GoogleMoney {
+            currency_code: currency_code.to_owned(),
+            units,
+            nanos,
+        }
+    }
+
+    #[test]
+    fn test_from_amount() {
+        assert_eq!(
+            GoogleMoney::try_from(eur!(10.50)),
+            Ok(money("EUR", 10, 500_000_000))
+        );
+        assert_eq!(
+            GoogleMoney::try_from(eur!(-0.01)),
+            Ok(money("EUR", 0, -10_000_000))
+        );
+        assert_eq!(GoogleMoney::try_from(jpy!(-12)), Ok(money("JPY", -12, 0)));
+        assert_eq!(
+            GoogleMoney::try_from(bhd!(1.000000001)),
+            Ok(money("BHD", 1, 1))
+        );
+        assert_eq!(
+            GoogleMoney::try_from(eur!(0.0000000001)),
+            Err(InvalidPrecision(EUR))
+        );
+        assert_eq!(
+            GoogleMoney::try_from(Amount(Decimal::MAX, EUR)),
+            Err(Overflow)
+        );
+    }
+
+    #[test]
+    fn test_to_amount() {
+        let amount = |code, units, nanos| Amount::try_from(money(code, units, nanos));
+        assert_eq!(amount("EUR", 10, 500_000_000), Ok(eur!(10.50)));
+        assert_eq!(amount("EUR", -1, -750_000_000), Ok(eur!(-1.75)));
+        assert_eq!(amount("EUR", 0, -1), Ok(eur!(-0.000000001)));
+        assert_eq!(amount("JPY", 12, 0), Ok(jpy!(12)));
+        assert_eq!(amount("EUR", 10, 500_000_000).unwrap().value().scale(), 2);
+        assert_eq!(
+            amount("XYZ", 1, 0),
+            Err(GoogleMoneyError::UnknownCurrency("XYZ".to_owned()))
+        );
+        assert_eq!(
+            amount("EUR", 1, 1_000_000_000),
+            Err(GoogleMoneyError::NanosOutOfRange(1_000_000_000))
+        );
+        assert_eq!(
+            amount("EUR", -1, 500_000_000),
+            Err(GoogleMoneyError::SignMismatch {
+                units: -1,
+                nanos: 500_000_000
+            })
+        );
+        assert_eq!(
+            amount("EUR", 1, -500_000_000),
+            Err(GoogleMoneyError::SignMismatch {
+                units: 1,
+                nanos: -500_000_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for amount in [eur!(0), eur!(1234.56), eur!(-0.5), bhd!(-3.125), jpy!(7)] {
+            let money = GoogleMoney::try_from(amount).unwrap();
+            assert_eq!(Amount::try_from(money), Ok(amount));
+        }
+    }
+
+    #[test]
+    fn test_display_error() {
+        assert_eq!(
+            GoogleMoneyError::SignMismatch {
+                units: 1,
+                nanos: -5
+            }
+            .to_string(),
+            "units 1 and nanos -5 have different signs"
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{Amount, AmountResult, CurrencyError, CurrencyLike, Decimal};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// `AmountIteratorExt` provides the aggregates of an iterator of
+/// [`Amount`]s or [`AmountResult`]s that reports usually need. All the
+/// aggregates check that the amounts are in the same currency, coalesce
+/// errors as the arithmetic operators do, and are a
+/// [`CurrencyError::Unknown`] if the iterator is empty.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{AmountIteratorExt, Decimal};
+/// use oxydized_money_macros::{eur, usd};
+///
+/// let expenses = [eur!(12.50), eur!(3), eur!(8.25)];
+/// assert_eq!(expenses.iter().total(), eur!(23.75));
+/// assert_eq!(expenses.iter().min_amount(), eur!(3));
+/// assert_eq!(expenses.iter().max_amount(), eur!(12.50));
+/// assert_eq!(expenses.iter().average(), eur!(7.9166666666666666666666666667));
+///
+/// assert!([eur!(1), usd!(1)].iter().max_amount().is_mismatch());
+/// assert!(Vec::<oxydized_money::Amount>::new().into_iter().total().is_unknown());
+/// ```
+pub trait AmountIteratorExt<C: CurrencyLike>: Iterator + Sized
+where
+    Self::Item: Into<AmountResult<C>>,
+{
+    /// Returns the sum of the amounts, as [`Iterator::sum`].
+    fn total(self) -> AmountResult<C> {
+        self.map(Into::into).sum()
+    }
+
+    /// Returns the smallest amount.
+    fn min_amount(self) -> AmountResult<C> {
+        extremum(self, Ordering::Less)
+    }
+
+    /// Returns the largest amount.
+    fn max_amount(self) -> AmountResult<C> {
+        extremum(self, Ordering::Greater)
+    }
+
+    /// Returns the arithmetic mean of the amounts.
+    fn average(self) -> AmountResult<C> {
+        let mut count = 0u64;
+        let total = self
+            .map(Into::into)
+            .inspect(|_| count += 1)
+            .sum::<AmountResult<C>>();
+        if count == 0 {
+            return total;
+        }
+        total / Decimal::from(count)
+    }
+
+    /// Returns the total of the amounts in each currency, so that amounts in
+    /// different currencies can be summed without a
+    /// [`CurrencyError::Mismatch`]. Unlike in a [`MoneyBag`](crate::MoneyBag),
+    /// the currencies whose amounts sum to zero are kept. Returns the first
+    /// error wrapped by an [`AmountResult`], if any, or a
+    /// [`CurrencyError::Overflow`] if a total does not fit in a [`Decimal`].
+    /// As in a [`Sum`](core::iter::Sum), [`CurrencyError::Unknown`] is
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountIteratorExt, Currency::*, Decimal};
+    /// use oxydized_money_macros::{eur, jpy, usd};
+    ///
+    /// let transactions = [eur!(10), usd!(5), eur!(-2.5), jpy!(100), jpy!(-100)];
+    /// let totals = transactions.iter().sum_by_currency().unwrap();
+    /// assert_eq!(totals.len(), 3);
+    /// assert_eq!(totals[&EUR], eur!(7.5));
+    /// assert_eq!(totals[&USD], usd!(5));
+    /// assert_eq!(totals[&JPY], jpy!(0));
+    /// ```
+    #[cfg(feature = "std")]
+    fn sum_by_currency(self) -> Result<HashMap<C, Amount<C>>, CurrencyError<C>> {
+        let mut totals = HashMap::new();
+        for item in self {
+            let amount = match item.into().into_inner() {
+                Ok(amount) => amount,
+                Err(CurrencyError::Unknown) => continue,
+                Err(error) => return Err(error),
+            };
+            let total = totals
+                .entry(amount.currency())
+                .or_insert(Amount(Decimal::ZERO, amount.currency()));
+            total.0 = total
+                .value()
+                .checked_add(amount.value())
+                .ok_or(CurrencyError::Overflow)?;
+        }
+        Ok(totals)
+    }
+
+    /// Collects the amounts, grouped by currency code and sorted by value
+    /// within each currency as with [`sort_amounts`](crate::sort_amounts).
+    /// Returns the first error wrapped by an [`AmountResult`], if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountIteratorExt, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let transactions = [usd!(3), eur!(10), usd!(-5), eur!(2)];
+    /// assert_eq!(
+    ///     transactions.iter().sorted_by_value_within_currency(),
+    ///     Ok(vec![eur!(2), eur!(10), usd!(-5), usd!(3)])
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sorted_by_value_within_currency(self) -> Result<Vec<Amount<C>>, CurrencyError<C>> {
+        let mut amounts = self
+            .map(|item| item.into().into_inner())
+            .collect::<Result<Vec<_>, _>>()?;
+        crate::sort_amounts(&mut amounts);
+        Ok(amounts)
+    }
+}
+
+impl<I, C> AmountIteratorExt<C> for I
+where
+    I: Iterator,
+    I::Item: Into<AmountResult<C>>,
+    C: CurrencyLike,
+{
+}
+
+/// Returns the amount that compares as `ordering` to all the others.
+fn extremum<I, C>(iter: I, ordering: Ordering) -> AmountResult<C>
+where
+    I: Iterator,
+    I::Item: Into<AmountResult<C>>,
+    C: CurrencyLike,
+{
+    let mut iter = iter.map(Into::into);
+    let Some(first) = iter.next() else {
+        return AmountResult::from(CurrencyError::Unknown);
+    };
+    iter.fold(first, |a, b| {
+        a.zip_with(b, |a: Amount<C>, b: Amount<C>| {
+            if a.currency() != b.currency() {
+                AmountResult::mismatch(a.currency(), b.currency())
+            } else if b.value().cmp(&a.value()) == ordering {
+                AmountResult::from(b)
+            } else {
+                AmountResult::from(a)
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::AmountIteratorExt;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, AmountResult, Currency::*, CurrencyError::*, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy, usd};
+
+    #[test]
+    fn test_total() {
+        assert_eq!([eur!(1), eur!(2.5)].iter().total(), eur!(3.5));
+        assert_eq!([eur!(1), eur!(2.5)].into_iter().total(), eur!(3.5));
+        assert_eq!(
+            [eur!(1), usd!(2)].iter().total().into_inner(),
+            Err(Mismatch(EUR, USD))
+        );
+        assert!(std::iter::empty::<Amount>().total().is_unknown());
+    }
+
+    #[test]
+    fn test_min_max() {
+        let amounts = [eur!(3), eur!(-1), eur!(7.5), eur!(2)];
+        assert_eq!(amounts.iter().min_amount(), eur!(-1));
+        assert_eq!(amounts.iter().max_amount(), eur!(7.5));
+        assert_eq!([jpy!(5)].iter().max_amount(), jpy!(5));
+        assert_eq!(
+            [eur!(1), eur!(5), usd!(2)].iter().max_amount().into_inner(),
+            Err(Mismatch(EUR, USD))
+        );
+        assert!(std::iter::empty::<Amount>().min_amount().is_unknown());
+    }
+
+    #[test]
+    fn test_average() {
+        assert_eq!([eur!(1), eur!(2), eur!(6)].iter().average(), eur!(3));
+        assert_eq!([eur!(1), eur!(2)].iter().average(), eur!(1.5));
+        assert!([eur!(1), usd!(2)].iter().average().is_mismatch());
+        assert!(std::iter::empty::<Amount>().average().is_unknown());
+    }
+
+    #[test]
+    fn test_sum_by_currency() {
+        let totals = [eur!(1), usd!(2), eur!(3.5)]
+            .into_iter()
+            .sum_by_currency()
+            .unwrap();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[&EUR], eur!(4.5));
+        assert_eq!(totals[&USD], usd!(2));
+        assert!(std::iter::empty::<Amount>()
+            .sum_by_currency()
+            .unwrap()
+            .is_empty());
+
+        let results = [AmountResult::from(eur!(1)), eur!(1) / dec!(0)];
+        assert_eq!(results.iter().sum_by_currency(), Err(DivideByZero));
+        let amounts = [Amount(Decimal::MAX, EUR), usd!(1), eur!(1)];
+        assert_eq!(amounts.iter().sum_by_currency(), Err(Overflow));
+        let results = [AmountResult::unknown(), eur!(1).into()];
+        assert_eq!(
+            results.iter().sum_by_currency(),
+            Ok([(EUR, eur!(1))].into_iter().collect())
+        );
+    }
+
+    #[test]
+    fn test_sorted_by_value_within_currency() {
+        assert_eq!(
+            [usd!(1), jpy!(2), eur!(4), eur!(-4)]
+                .into_iter()
+                .sorted_by_value_within_currency(),
+            Ok(vec![eur!(-4), eur!(4), jpy!(2), usd!(1)])
+        );
+        let results = [AmountResult::from(eur!(1)), AmountResult::unknown()];
+        assert_eq!(
+            results.iter().sorted_by_value_within_currency(),
+            Err(Unknown)
+        );
+    }
+
+    #[test]
+    fn test_amount_results() {
+        let results = [AmountResult::from(eur!(4)), eur!(2) / dec!(2)];
+        assert_eq!(results.iter().total(), eur!(5));
+        assert_eq!(results.iter().min_amount(), eur!(1));
+        assert_eq!(results.into_iter().average(), eur!(2.5));
+
+        let results = [AmountResult::from(eur!(4)), eur!(2) / dec!(0)];
+        assert!(results.iter().total().is_divide_by_zero());
+        assert!(results.iter().max_amount().is_divide_by_zero());
+        assert!(results.iter().average().is_divide_by_zero());
+    }
+}
@@ -0,0 +1,43 @@
+use crate::Amount;
+use ::juniper::{graphql_scalar, InputValue, ScalarValue, Value};
+
+/// With the `juniper` feature, amounts are GraphQL scalars in their
+/// canonical string form, e.g. `"10.50 EUR"`.
+#[graphql_scalar(
+    name = "Amount",
+    description = "Amount of money in a specific currency, e.g. \"10.50 EUR\"",
+    to_output_with = to_output,
+    from_input_with = from_input,
+    parse_token(String)
+)]
+type JuniperAmount = Amount;
+
+fn to_output<S: ScalarValue>(amount: &Amount) -> Value<S> {
+    Value::scalar(amount.canonical())
+}
+
+fn from_input<S: ScalarValue>(input: &InputValue<S>) -> Result<Amount, String> {
+    let string = input
+        .as_string_value()
+        .ok_or_else(|| format!("Expected `String`, found: {}", input))?;
+    Amount::parse_canonical(string).ok_or_else(|| format!("invalid amount '{}'", string))
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use ::juniper::{DefaultScalarValue, FromInputValue, InputValue, ToInputValue};
+    use oxydized_money::Amount;
+    use oxydized_money_macros::eur;
+
+    #[test]
+    fn test_scalar() {
+        let input: InputValue<DefaultScalarValue> = eur!(10.50).to_input_value();
+        assert_eq!(input, InputValue::scalar("10.50 EUR".to_owned()));
+        assert_eq!(Amount::from_input_value(&input), Ok(eur!(10.50)));
+        let input = InputValue::<DefaultScalarValue>::scalar("10.50".to_owned());
+        assert!(Amount::from_input_value(&input).is_err());
+        let input = InputValue::<DefaultScalarValue>::scalar(10);
+        assert!(Amount::from_input_value(&input).is_err());
+    }
+}
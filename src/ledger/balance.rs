@@ -0,0 +1,281 @@
+use crate::{Amount, Currency, CurrencyLike, Decimal};
+use core::fmt::Display;
+
+/// `Side` represents the side of an account on which an amount is
+/// recorded in double-entry bookkeeping.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "with_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    /// The left side of an account, increasing assets and expenses.
+    Debit,
+    /// The right side of an account, increasing liabilities, equity and
+    /// income.
+    Credit,
+}
+
+impl Side {
+    /// Returns the other side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::Side;
+    ///
+    /// assert_eq!(Side::Debit.opposite(), Side::Credit);
+    /// assert_eq!(Side::Credit.opposite(), Side::Debit);
+    /// ```
+    pub fn opposite(&self) -> Self {
+        match self {
+            Side::Debit => Side::Credit,
+            Side::Credit => Side::Debit,
+        }
+    }
+}
+
+impl Display for Side {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Side::Debit => write!(f, "DR"),
+            Side::Credit => write!(f, "CR"),
+        }
+    }
+}
+
+/// `Balance` represents an amount of money recorded on an explicit
+/// [`Side`] of an account, rather than relying on a sign convention.
+///
+/// The [amount](Balance::amount) of a balance is never negative: creating
+/// a balance from a negative amount records its absolute value on the
+/// opposite side, and a zero balance is always on the debit side. Adding
+/// and subtracting balances are currency-checked and output a
+/// [`Result`](crate::Result) whose error is a
+/// [`CurrencyError::Mismatch`](crate::CurrencyError::Mismatch). As for
+/// [`Amount`]s, the currency is a [`Currency`] by default, or any other
+/// type implementing [`CurrencyLike`].
+///
+/// With the `with_serde` feature, balances are serialized as a structure
+/// with an `amount` and a `side` field, e.g.
+/// `{"amount":{"value":"30","currency":"EUR"},"side":"Credit"}`. They are
+/// deserialized through [`Balance::new`], so that a negative amount is
+/// recorded on the opposite side.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Balance, Side, Decimal};
+/// use oxydized_money_macros::{eur, usd};
+///
+/// let balance = (Balance::debit(eur!(100)) + Balance::credit(eur!(130))).unwrap();
+/// assert_eq!(balance.side(), Side::Credit);
+/// assert_eq!(balance.amount(), eur!(30));
+/// assert_eq!(balance.net(), eur!(-30));
+/// assert_eq!(format!("{}", balance), "€ 30.00 CR");
+/// assert!((balance + Balance::debit(usd!(1))).is_err());
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "with_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        from = "BalanceRepr<C>",
+        into = "BalanceRepr<C>",
+        bound(
+            serialize = "C: CurrencyLike + serde::Serialize",
+            deserialize = "C: CurrencyLike + serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct Balance<C = Currency> {
+    amount: Amount<C>,
+    side: Side,
+}
+
+#[cfg(feature = "with_serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(
+    rename = "Balance",
+    bound(
+        serialize = "C: Clone + serde::Serialize",
+        deserialize = "C: serde::Deserialize<'de>"
+    )
+)]
+struct BalanceRepr<C> {
+    amount: Amount<C>,
+    side: Side,
+}
+
+#[cfg(feature = "with_serde")]
+impl<C: CurrencyLike> From<BalanceRepr<C>> for Balance<C> {
+    fn from(repr: BalanceRepr<C>) -> Self {
+        Balance::new(repr.amount, repr.side)
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl<C> From<Balance<C>> for BalanceRepr<C> {
+    fn from(Balance { amount, side }: Balance<C>) -> Self {
+        BalanceRepr { amount, side }
+    }
+}
+
+impl<C: CurrencyLike> Balance<C> {
+    /// Creates a [`Balance`] recording `amount` on the provided `side`. If
+    /// `amount` is negative, its absolute value is recorded on the opposite
+    /// side.
+    pub fn new(amount: Amount<C>, side: Side) -> Self {
+        if amount.is_zero() {
+            Self::zero(amount.currency())
+        } else if amount.is_sign_negative() {
+            Self {
+                amount: amount.abs(),
+                side: side.opposite(),
+            }
+        } else {
+            Self { amount, side }
+        }
+    }
+
+    /// Creates a [`Balance`] recording `amount` on the debit side.
+    pub fn debit(amount: Amount<C>) -> Self {
+        Self::new(amount, Side::Debit)
+    }
+
+    /// Creates a [`Balance`] recording `amount` on the credit side.
+    pub fn credit(amount: Amount<C>) -> Self {
+        Self::new(amount, Side::Credit)
+    }
+
+    /// Creates a zero [`Balance`] in the provided currency.
+    pub fn zero(currency: C) -> Self {
+        Self {
+            amount: Amount(Decimal::ZERO, currency),
+            side: Side::Debit,
+        }
+    }
+
+    /// Returns the side on which the balance is recorded.
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// Returns the amount of the balance, which is never negative.
+    pub fn amount(&self) -> Amount<C> {
+        self.amount
+    }
+
+    /// Returns the currency of the balance.
+    pub fn currency(&self) -> C {
+        self.amount.currency()
+    }
+
+    /// Returns `true` if the balance is zero.
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    /// Returns the balance as a signed amount, debits being positive and
+    /// credits negative. Use [`Balance::net_for`] to look at the balance
+    /// from the perspective of a credit-normal account.
+    pub fn net(&self) -> Amount<C> {
+        self.net_for(Side::Debit)
+    }
+
+    /// Returns the balance as a signed amount from the perspective of an
+    /// account whose normal balance is on `side`: amounts recorded on
+    /// `side` are positive and amounts recorded on the opposite side are
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Balance, Side, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// let loan = Balance::credit(eur!(1000));
+    /// assert_eq!(loan.net_for(Side::Credit), eur!(1000));
+    /// assert_eq!(loan.net_for(Side::Debit), eur!(-1000));
+    /// ```
+    pub fn net_for(&self, side: Side) -> Amount<C> {
+        if self.side == side {
+            self.amount
+        } else {
+            -self.amount
+        }
+    }
+}
+
+impl<C: CurrencyLike> Display for Balance<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.amount, f)?;
+        write!(f, " {}", self.side)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Balance, Currency::*, CurrencyLike, Decimal, Side::*};
+    use oxydized_money_macros::{eur, usd};
+
+    #[test]
+    fn test_new() {
+        let balance = Balance::new(eur!(10), Credit);
+        assert_eq!(balance.side(), Credit);
+        assert_eq!(balance.amount(), eur!(10));
+        assert_eq!(balance.currency(), EUR);
+
+        let balance = Balance::debit(eur!(-10));
+        assert_eq!(balance, Balance::credit(eur!(10)));
+        assert_eq!(Balance::credit(usd!(-0)), Balance::zero(USD));
+        assert_eq!(Balance::credit(usd!(0)).side(), Debit);
+        assert!(Balance::zero(USD).is_zero());
+    }
+
+    #[test]
+    fn test_net() {
+        assert_eq!(Balance::debit(eur!(10)).net(), eur!(10));
+        assert_eq!(Balance::credit(eur!(10)).net(), eur!(-10));
+        assert_eq!(Balance::credit(eur!(10)).net_for(Credit), eur!(10));
+        assert_eq!(Balance::debit(eur!(10)).net_for(Credit), eur!(-10));
+        assert_eq!(Balance::zero(EUR).net_for(Credit), eur!(0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Balance::debit(eur!(10))), "€ 10.00 DR");
+        assert_eq!(format!("{:.1}", Balance::credit(usd!(2))), "$ 2.0 CR");
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    struct Points;
+
+    impl CurrencyLike for Points {
+        fn code(&self) -> &'static str {
+            "PTS"
+        }
+    }
+
+    #[test]
+    fn test_custom_currency() {
+        let balance = Balance::new(Amount(Decimal::from(-5), Points), Debit);
+        assert_eq!(balance.side(), Credit);
+        assert_eq!(balance.currency(), Points);
+        assert_eq!(balance.net(), Amount(Decimal::from(-5), Points));
+        assert_eq!(-balance, Balance::debit(Amount(Decimal::from(5), Points)));
+        assert_eq!(format!("{}", balance), "PTS 5.00 CR");
+    }
+
+    #[test]
+    #[cfg(feature = "with_serde")]
+    fn test_serde() {
+        let balance = Balance::credit(eur!(30));
+        let json = r#"{"amount":{"value":"30","currency":"EUR"},"side":"Credit"}"#;
+        assert_eq!(serde_json::to_string(&balance).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Balance>(json).unwrap(), balance);
+
+        let json = r#"{"amount":{"value":"-30","currency":"EUR"},"side":"Debit"}"#;
+        assert_eq!(serde_json::from_str::<Balance>(json).unwrap(), balance);
+        let json = r#"{"amount":{"value":"0","currency":"EUR"},"side":"Credit"}"#;
+        assert_eq!(serde_json::from_str::<Balance>(json).unwrap().side(), Debit);
+    }
+}
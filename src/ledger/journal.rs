@@ -0,0 +1,272 @@
+use crate::{Amount, Balance, Currency, Side};
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+/// `JournalError` represents the reasons why a [`JournalEntry`] is not
+/// valid.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub enum JournalError {
+    /// The journal entry does not have any line.
+    Empty,
+
+    /// The debits and credits of the journal entry do not balance out in
+    /// one of the currencies. The difference between the debits and the
+    /// credits in that currency is provided.
+    Unbalanced(Balance),
+
+    /// The total of the debits or of the credits in the provided currency
+    /// does not fit in a [`Decimal`](crate::Decimal).
+    Overflow(Currency),
+}
+
+impl Error for JournalError {}
+
+impl Display for JournalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use JournalError::*;
+        match self {
+            Empty => write!(f, "empty journal entry"),
+            Unbalanced(balance) => write!(f, "unbalanced journal entry ({})", balance),
+            Overflow(currency) => write!(f, "overflowing total in {}", currency.code()),
+        }
+    }
+}
+
+/// `JournalLine` is a single line of a [`JournalEntry`], recording an
+/// [`Amount`] on one [`Side`] of an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct JournalLine<A> {
+    account: A,
+    amount: Amount,
+    side: Side,
+}
+
+impl<A> JournalLine<A> {
+    /// Creates a new [`JournalLine`] recording `amount` on the provided
+    /// `side` of `account`.
+    pub fn new(account: A, amount: Amount, side: Side) -> Self {
+        Self {
+            account,
+            amount,
+            side,
+        }
+    }
+
+    /// Returns the account affected by the line.
+    pub fn account(&self) -> &A {
+        &self.account
+    }
+
+    /// Returns the amount recorded by the line.
+    pub fn amount(&self) -> Amount {
+        self.amount
+    }
+
+    /// Returns the side on which the amount is recorded.
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// Returns the line as a [`Balance`].
+    pub fn balance(&self) -> Balance {
+        Balance::new(self.amount, self.side)
+    }
+}
+
+/// `JournalEntry` represents a double-entry bookkeeping transaction made
+/// of several [lines](JournalLine), each recording an amount on the debit
+/// or credit side of an account. Accounts can be identified by any type
+/// (e.g. `&str`, `u32` or a custom enum).
+///
+/// A journal entry is [valid](JournalEntry::validate) if, for each
+/// currency, the total of the debits equals the total of the credits.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{JournalEntry, JournalError, Balance, Decimal};
+/// use oxydized_money_macros::{eur, usd};
+///
+/// let sale = JournalEntry::new()
+///     .debit("cash", eur!(120))
+///     .credit("sales", eur!(100))
+///     .credit("vat", eur!(20));
+/// assert_eq!(sale.validate(), Ok(()));
+///
+/// let oops = JournalEntry::new()
+///     .debit("cash", eur!(120))
+///     .credit("sales", usd!(120));
+/// assert_eq!(oops.validate(), Err(JournalError::Unbalanced(Balance::debit(eur!(120)))));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct JournalEntry<A> {
+    lines: Vec<JournalLine<A>>,
+}
+
+impl<A> JournalEntry<A> {
+    /// Creates a new [`JournalEntry`] without any line.
+    pub fn new() -> Self {
+        Self { lines: Vec::new() }
+    }
+
+    /// Adds a line recording `amount` on the debit side of `account`.
+    pub fn debit(self, account: A, amount: Amount) -> Self {
+        self.line(JournalLine::new(account, amount, Side::Debit))
+    }
+
+    /// Adds a line recording `amount` on the credit side of `account`.
+    pub fn credit(self, account: A, amount: Amount) -> Self {
+        self.line(JournalLine::new(account, amount, Side::Credit))
+    }
+
+    /// Adds the provided line to the journal entry.
+    pub fn line(mut self, line: JournalLine<A>) -> Self {
+        self.push(line);
+        self
+    }
+
+    /// Adds the provided line to the journal entry in place.
+    pub fn push(&mut self, line: JournalLine<A>) {
+        self.lines.push(line);
+    }
+
+    /// Returns the lines of the journal entry.
+    pub fn lines(&self) -> &[JournalLine<A>] {
+        &self.lines
+    }
+
+    /// Returns, for each currency used in the journal entry, the
+    /// difference between the debits and the credits, sorted by currency
+    /// code. Returns a [`JournalError::Overflow`] if a total does not fit in
+    /// a [`Decimal`](crate::Decimal).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{JournalEntry, Balance, Decimal, Currency::EUR};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let entry = JournalEntry::new()
+    ///     .debit(1, eur!(10))
+    ///     .credit(2, eur!(10))
+    ///     .credit(3, usd!(5));
+    /// assert_eq!(entry.totals(), Ok(vec![Balance::zero(EUR), Balance::credit(usd!(5))]));
+    /// ```
+    pub fn totals(&self) -> Result<Vec<Balance>, JournalError> {
+        let mut totals: HashMap<Currency, Balance> = HashMap::new();
+        for line in &self.lines {
+            let balance = line.balance();
+            let total = totals
+                .entry(balance.currency())
+                .or_insert_with(|| Balance::zero(balance.currency()));
+            *total = (*total + balance).map_err(|_| JournalError::Overflow(balance.currency()))?;
+        }
+        let mut totals: Vec<Balance> = totals.into_values().collect();
+        totals.sort_by_key(|balance| balance.currency().code());
+        Ok(totals)
+    }
+
+    /// Checks that the journal entry has at least one line and that, for
+    /// each currency, the total of the debits equals the total of the
+    /// credits. Otherwise, returns a [`JournalError`], reporting the first
+    /// unbalanced currency in the order of their codes, or the overflow of
+    /// a total (see [`JournalEntry::totals`]).
+    pub fn validate(&self) -> Result<(), JournalError> {
+        if self.lines.is_empty() {
+            return Err(JournalError::Empty);
+        }
+        match self.totals()?.into_iter().find(|total| !total.is_zero()) {
+            Some(total) => Err(JournalError::Unbalanced(total)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<A> Default for JournalEntry<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> FromIterator<JournalLine<A>> for JournalEntry<A> {
+    fn from_iter<I: IntoIterator<Item = JournalLine<A>>>(iter: I) -> Self {
+        Self {
+            lines: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        Amount, Balance, Currency::*, Decimal, JournalEntry, JournalError::*, JournalLine, Side::*,
+    };
+    use oxydized_money_macros::{eur, usd};
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(JournalEntry::<&str>::new().validate(), Err(Empty));
+
+        let entry = JournalEntry::new()
+            .debit("expenses", eur!(10))
+            .credit("cash", eur!(4))
+            .credit("cash", eur!(6));
+        assert_eq!(entry.validate(), Ok(()));
+
+        let entry = entry.debit("expenses", usd!(3)).credit("cash", usd!(2));
+        assert_eq!(entry.validate(), Err(Unbalanced(Balance::debit(usd!(1)))));
+
+        let entry = JournalEntry::new()
+            .debit("a", eur!(1))
+            .credit("b", eur!(1))
+            .debit("c", usd!(0));
+        assert_eq!(entry.validate(), Ok(()));
+
+        let entry = JournalEntry::new().debit("a", eur!(-5)).debit("b", eur!(5));
+        assert_eq!(entry.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut entry: JournalEntry<u32> = [
+            JournalLine::new(512, eur!(100), Debit),
+            JournalLine::new(706, eur!(100), Credit),
+        ]
+        .into_iter()
+        .collect();
+        entry.push(JournalLine::new(411, eur!(-0.5), Credit));
+
+        let lines = entry.lines();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].account(), &512);
+        assert_eq!(lines[0].amount(), eur!(100));
+        assert_eq!(lines[0].side(), Debit);
+        assert_eq!(lines[2].balance(), Balance::debit(eur!(0.5)));
+        assert_eq!(entry.totals(), Ok(vec![Balance::debit(eur!(0.5))]));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let entry = JournalEntry::new()
+            .debit("a", Amount::max_in(EUR))
+            .debit("b", eur!(1))
+            .credit("c", usd!(1));
+        assert_eq!(entry.totals(), Err(Overflow(EUR)));
+        assert_eq!(entry.validate(), Err(Overflow(EUR)));
+
+        let entry = JournalEntry::new()
+            .debit("a", Amount::max_in(EUR))
+            .credit("b", Amount::max_in(EUR));
+        assert_eq!(entry.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Empty), "empty journal entry");
+        assert_eq!(
+            format!("{}", Unbalanced(Balance::credit(eur!(2)))),
+            "unbalanced journal entry (€ 2.00 CR)"
+        );
+        assert_eq!(format!("{}", Overflow(EUR)), "overflowing total in EUR");
+    }
+}
@@ -0,0 +1,11 @@
+mod balance;
+#[cfg(feature = "std")]
+mod journal;
+#[cfg(feature = "std")]
+mod trial;
+
+pub use balance::{Balance, Side};
+#[cfg(feature = "std")]
+pub use journal::{JournalEntry, JournalError, JournalLine};
+#[cfg(feature = "std")]
+pub use trial::{Imbalance, TrialBalance};
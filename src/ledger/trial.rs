@@ -0,0 +1,283 @@
+use crate::{Balance, Currency, JournalEntry, JournalError};
+use std::{collections::HashMap, error::Error, fmt::Display, hash::Hash};
+
+/// `Imbalance` reports a currency in which the debits and credits posted
+/// to a [`TrialBalance`] do not balance out.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Imbalance<A> {
+    difference: Balance,
+    accounts: Vec<A>,
+}
+
+impl<A> Imbalance<A> {
+    /// Returns the currency that does not balance out.
+    pub fn currency(&self) -> Currency {
+        self.difference.currency()
+    }
+
+    /// Returns the difference between the debits and the credits in
+    /// [that currency](Imbalance::currency).
+    pub fn difference(&self) -> Balance {
+        self.difference
+    }
+
+    /// Returns the accounts affected, in that currency, by the unbalanced
+    /// journal entries, in the order in which they were posted.
+    pub fn accounts(&self) -> &[A] {
+        &self.accounts
+    }
+}
+
+impl<A: Display> Display for Imbalance<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "book is off by {} (accounts: ", self.difference)?;
+        for (index, account) in self.accounts.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", account)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<A: Display + std::fmt::Debug> Error for Imbalance<A> {}
+
+/// `TrialBalance` folds many [journal entries](JournalEntry) into
+/// per-account, per-currency [balances](Balance), and verifies that the
+/// whole book balances out.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{TrialBalance, JournalEntry, Balance, Decimal, Currency::EUR};
+/// use oxydized_money_macros::eur;
+///
+/// let entries = [
+///     JournalEntry::new().debit("cash", eur!(100)).credit("capital", eur!(100)),
+///     JournalEntry::new().debit("rent", eur!(30)).credit("cash", eur!(30)),
+///     JournalEntry::new().debit("rent", eur!(5)).credit("cash", eur!(4)),
+/// ];
+/// let trial: TrialBalance<&str> = entries.iter().collect::<Result<_, _>>().unwrap();
+/// assert_eq!(trial.balance(&"cash", EUR), Balance::debit(eur!(66)));
+/// assert_eq!(trial.balance(&"rent", EUR), Balance::debit(eur!(35)));
+///
+/// let imbalances = trial.verify().unwrap_err();
+/// assert_eq!(imbalances[0].difference(), Balance::debit(eur!(1)));
+/// assert_eq!(imbalances[0].accounts(), ["rent", "cash"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrialBalance<A: Eq + Hash> {
+    balances: HashMap<A, HashMap<Currency, Balance>>,
+    imbalances: HashMap<Currency, Imbalance<A>>,
+}
+
+impl<A: Clone + Eq + Hash> TrialBalance<A> {
+    /// Creates a new empty [`TrialBalance`].
+    pub fn new() -> Self {
+        Self {
+            balances: HashMap::new(),
+            imbalances: HashMap::new(),
+        }
+    }
+
+    /// Posts all the lines of `entry` to their respective accounts. Entries
+    /// that do not balance out are accepted, but remembered to be reported
+    /// by [`TrialBalance::verify`]. Returns a [`JournalError::Overflow`],
+    /// leaving the trial balance untouched, if the balance of an account
+    /// or the difference in a currency does not fit in a
+    /// [`Decimal`](crate::Decimal).
+    pub fn post(&mut self, entry: &JournalEntry<A>) -> Result<(), JournalError> {
+        let mut balances: HashMap<(A, Currency), Balance> = HashMap::new();
+        for line in entry.lines() {
+            let balance = line.balance();
+            let currency = balance.currency();
+            let current = self.balance(line.account(), currency);
+            let total = balances
+                .entry((line.account().clone(), currency))
+                .or_insert(current);
+            *total = (*total + balance).map_err(|_| JournalError::Overflow(currency))?;
+        }
+
+        let mut differences = Vec::new();
+        for total in entry.totals()?.into_iter().filter(|t| !t.is_zero()) {
+            let currency = total.currency();
+            let difference = self
+                .imbalances
+                .get(&currency)
+                .map_or_else(|| Balance::zero(currency), |imbalance| imbalance.difference);
+            let difference = (difference + total).map_err(|_| JournalError::Overflow(currency))?;
+            differences.push(difference);
+        }
+
+        for ((account, currency), balance) in balances {
+            self.balances
+                .entry(account)
+                .or_default()
+                .insert(currency, balance);
+        }
+        for difference in differences {
+            let currency = difference.currency();
+            let imbalance = self
+                .imbalances
+                .entry(currency)
+                .or_insert_with(|| Imbalance {
+                    difference,
+                    accounts: Vec::new(),
+                });
+            imbalance.difference = difference;
+            for line in entry.lines() {
+                if line.amount().currency() == currency
+                    && !imbalance.accounts.contains(line.account())
+                {
+                    imbalance.accounts.push(line.account().clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the balance of `account` in `currency`, which is zero if
+    /// nothing was posted to that account in that currency.
+    pub fn balance(&self, account: &A, currency: Currency) -> Balance {
+        self.balances
+            .get(account)
+            .and_then(|balances| balances.get(&currency))
+            .copied()
+            .unwrap_or_else(|| Balance::zero(currency))
+    }
+
+    /// Returns an iterator over all the accounts to which something was
+    /// posted, along with their balance in each currency, in no particular
+    /// order.
+    pub fn balances(&self) -> impl Iterator<Item = (&A, Balance)> {
+        self.balances.iter().flat_map(|(account, balances)| {
+            balances.values().map(move |balance| (account, *balance))
+        })
+    }
+
+    /// Checks that, in each currency, the total of the debits equals the
+    /// total of the credits across the whole book. Otherwise, returns one
+    /// [`Imbalance`] per currency that does not balance out, sorted by
+    /// currency code.
+    pub fn verify(&self) -> Result<(), Vec<Imbalance<A>>> {
+        let mut imbalances: Vec<Imbalance<A>> = self
+            .imbalances
+            .values()
+            .filter(|imbalance| !imbalance.difference.is_zero())
+            .cloned()
+            .collect();
+        if imbalances.is_empty() {
+            Ok(())
+        } else {
+            imbalances.sort_by_key(|imbalance| imbalance.currency().code());
+            Err(imbalances)
+        }
+    }
+}
+
+impl<A: Clone + Eq + Hash> Default for TrialBalance<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Posts all the journal entries to a new [`TrialBalance`], stopping at
+/// the first [`JournalError::Overflow`] (see [`TrialBalance::post`]).
+impl<'a, A: Clone + Eq + Hash + 'a> FromIterator<&'a JournalEntry<A>>
+    for Result<TrialBalance<A>, JournalError>
+{
+    fn from_iter<I: IntoIterator<Item = &'a JournalEntry<A>>>(iter: I) -> Self {
+        let mut trial = TrialBalance::new();
+        for entry in iter {
+            trial.post(entry)?;
+        }
+        Ok(trial)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        Amount, Balance, Currency::*, Decimal, JournalEntry, JournalError::*, TrialBalance,
+    };
+    use oxydized_money_macros::{eur, usd};
+
+    #[test]
+    fn test_balanced() {
+        let mut trial = TrialBalance::new();
+        let entries = [
+            JournalEntry::new().debit(1, eur!(10)).credit(2, eur!(10)),
+            JournalEntry::new().debit(2, usd!(3)).credit(3, usd!(3)),
+            JournalEntry::new().debit(2, eur!(4)).credit(1, eur!(4)),
+        ];
+        for entry in &entries {
+            assert_eq!(trial.post(entry), Ok(()));
+        }
+        assert_eq!(trial.verify(), Ok(()));
+        assert_eq!(trial.balance(&1, EUR), Balance::debit(eur!(6)));
+        assert_eq!(trial.balance(&2, EUR), Balance::credit(eur!(6)));
+        assert_eq!(trial.balance(&2, USD), Balance::debit(usd!(3)));
+        assert_eq!(trial.balance(&3, EUR), Balance::zero(EUR));
+        assert_eq!(trial.balance(&4, USD), Balance::zero(USD));
+
+        let mut balances: Vec<_> = trial.balances().collect();
+        balances.sort_by_key(|(account, balance)| (**account, balance.currency().code()));
+        assert_eq!(
+            balances,
+            vec![
+                (&1, Balance::debit(eur!(6))),
+                (&2, Balance::credit(eur!(6))),
+                (&2, Balance::debit(usd!(3))),
+                (&3, Balance::credit(usd!(3))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unbalanced() {
+        let entries = [
+            JournalEntry::new()
+                .debit("a", eur!(10))
+                .credit("b", eur!(9)),
+            JournalEntry::new().debit("c", usd!(3)).credit("d", usd!(5)),
+            JournalEntry::new().debit("a", usd!(1)).credit("e", usd!(1)),
+            JournalEntry::new().debit("b", usd!(1)).credit("f", eur!(1)),
+        ];
+        let trial: TrialBalance<_> = entries.iter().collect::<Result<_, _>>().unwrap();
+        let imbalances = trial.verify().unwrap_err();
+        assert_eq!(imbalances.len(), 1);
+        assert_eq!(imbalances[0].currency(), USD);
+        assert_eq!(imbalances[0].difference(), Balance::credit(usd!(1)));
+        assert_eq!(imbalances[0].accounts(), ["c", "d", "b"]);
+        assert_eq!(
+            imbalances[0].to_string(),
+            "book is off by $ 1.00 CR (accounts: c, d, b)"
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = Amount::max_in(EUR);
+        let mut trial = TrialBalance::new();
+        let entry = JournalEntry::new().debit("a", max).credit("b", max);
+        assert_eq!(trial.post(&entry), Ok(()));
+        assert_eq!(trial.post(&entry), Err(Overflow(EUR)));
+        assert_eq!(trial.balance(&"a", EUR), Balance::debit(max));
+        assert_eq!(trial.balance(&"b", EUR), Balance::credit(max));
+
+        let unbalanced = JournalEntry::new().debit("c", max).credit("d", usd!(1));
+        assert_eq!(trial.post(&unbalanced), Ok(()));
+        assert_eq!(trial.post(&unbalanced), Err(Overflow(EUR)));
+        assert_eq!(trial.balance(&"c", EUR), Balance::debit(max));
+        assert_eq!(trial.balance(&"d", USD), Balance::credit(usd!(1)));
+        assert_eq!(
+            trial.verify().unwrap_err()[0].difference(),
+            Balance::debit(max)
+        );
+
+        let trial: Result<TrialBalance<_>, _> = [entry.clone(), entry].iter().collect();
+        assert_eq!(trial, Err(Overflow(EUR)));
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{Amount, Currency, CurrencyLike, Decimal};
+
+/// `LegacyCurrency` represents retired currencies that were replaced by
+/// another currency at a fixed, legally defined conversion rate, such as
+/// the national currencies of the eurozone or the currencies that were
+/// redenominated (e.g. the old Turkish lira).
+///
+/// These currencies are no longer part of the ISO 4217 list, but importing
+/// historical accounting data requires converting them using their exact
+/// legal rates rather than market rates. Amounts in legacy currencies are
+/// expressed as `Amount<LegacyCurrency>` (see [`CurrencyLike`]) and can
+/// be converted to their successor using [`Amount::redenominated`].
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Decimal, LegacyCurrency};
+/// use oxydized_money_macros::{dec, eur};
+///
+/// let price = Amount(dec!(19.5583), LegacyCurrency::DEM);
+/// assert_eq!(price.redenominated(), eur!(10));
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "with_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LegacyCurrency {
+    /// Austrian schilling, replaced by the euro in 1999.
+    ATS,
+    /// Belgian franc, replaced by the euro in 1999.
+    BEF,
+    /// Cypriot pound, replaced by the euro in 2008.
+    CYP,
+    /// German mark, replaced by the euro in 1999.
+    DEM,
+    /// Estonian kroon, replaced by the euro in 2011.
+    EEK,
+    /// Spanish peseta, replaced by the euro in 1999.
+    ESP,
+    /// Finnish markka, replaced by the euro in 1999.
+    FIM,
+    /// French franc, replaced by the euro in 1999.
+    FRF,
+    /// Greek drachma, replaced by the euro in 2001.
+    GRD,
+    /// Irish pound, replaced by the euro in 1999.
+    IEP,
+    /// Italian lira, replaced by the euro in 1999.
+    ITL,
+    /// Lithuanian litas, replaced by the euro in 2015.
+    LTL,
+    /// Luxembourg franc, replaced by the euro in 1999.
+    LUF,
+    /// Latvian lats, replaced by the euro in 2014.
+    LVL,
+    /// Maltese lira, replaced by the euro in 2008.
+    MTL,
+    /// Dutch guilder, replaced by the euro in 1999.
+    NLG,
+    /// Portuguese escudo, replaced by the euro in 1999.
+    PTE,
+    /// Slovenian tolar, replaced by the euro in 2007.
+    SIT,
+    /// Slovak koruna, replaced by the euro in 2009.
+    SKK,
+    /// Turkish lira, redenominated into the new Turkish lira in 2005.
+    TRL,
+    /// Zimbabwean dollar (third), redenominated in 2009.
+    ZWR,
+}
+
+impl LegacyCurrency {
+    /// Returns the legacy currency matching the provided code, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::LegacyCurrency;
+    ///
+    /// assert_eq!(LegacyCurrency::from_code("FRF"), Some(LegacyCurrency::FRF));
+    /// assert_eq!(LegacyCurrency::from_code("EUR"), None);
+    /// ```
+    pub fn from_code(code: &str) -> Option<Self> {
+        use LegacyCurrency::*;
+        [
+            ATS, BEF, CYP, DEM, EEK, ESP, FIM, FRF, GRD, IEP, ITL, LTL, LUF, LVL, MTL, NLG, PTE,
+            SIT, SKK, TRL, ZWR,
+        ]
+        .into_iter()
+        .find(|currency| currency.code() == code)
+    }
+
+    /// Returns the currency that replaced `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Currency, LegacyCurrency};
+    ///
+    /// assert_eq!(LegacyCurrency::ITL.successor(), Currency::EUR);
+    /// assert_eq!(LegacyCurrency::TRL.successor(), Currency::TRY);
+    /// ```
+    pub fn successor(&self) -> Currency {
+        use LegacyCurrency::*;
+        match self {
+            TRL => Currency::TRY,
+            ZWR => Currency::ZWL,
+            _ => Currency::EUR,
+        }
+    }
+
+    /// Returns the fixed conversion rate between `self` and its
+    /// [successor](LegacyCurrency::successor), expressed as the number of
+    /// units of `self` in one unit of the successor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Decimal, LegacyCurrency};
+    /// use oxydized_money_macros::dec;
+    ///
+    /// assert_eq!(LegacyCurrency::FRF.conversion_rate(), dec!(6.55957));
+    /// assert_eq!(LegacyCurrency::TRL.conversion_rate(), dec!(1_000_000));
+    /// ```
+    pub fn conversion_rate(&self) -> Decimal {
+        use LegacyCurrency::*;
+        let (mantissa, scale) = match self {
+            ATS => (137603, 4),
+            BEF => (403399, 4),
+            CYP => (585274, 6),
+            DEM => (195583, 5),
+            EEK => (156466, 4),
+            ESP => (166386, 3),
+            FIM => (594573, 5),
+            FRF => (655957, 5),
+            GRD => (340750, 3),
+            IEP => (787564, 6),
+            ITL => (193627, 2),
+            LTL => (345280, 5),
+            LUF => (403399, 4),
+            LVL => (702804, 6),
+            MTL => (429300, 6),
+            NLG => (220371, 5),
+            PTE => (200482, 3),
+            SIT => (239640, 3),
+            SKK => (301260, 4),
+            TRL => (1_000_000, 0),
+            ZWR => (1_000_000_000_000, 0),
+        };
+        Decimal::new(mantissa, scale)
+    }
+}
+
+impl CurrencyLike for LegacyCurrency {
+    fn code(&self) -> &'static str {
+        use LegacyCurrency::*;
+        match self {
+            ATS => "ATS",
+            BEF => "BEF",
+            CYP => "CYP",
+            DEM => "DEM",
+            EEK => "EEK",
+            ESP => "ESP",
+            FIM => "FIM",
+            FRF => "FRF",
+            GRD => "GRD",
+            IEP => "IEP",
+            ITL => "ITL",
+            LTL => "LTL",
+            LUF => "LUF",
+            LVL => "LVL",
+            MTL => "MTL",
+            NLG => "NLG",
+            PTE => "PTE",
+            SIT => "SIT",
+            SKK => "SKK",
+            TRL => "TRL",
+            ZWR => "ZWR",
+        }
+    }
+}
+
+impl Amount<LegacyCurrency> {
+    /// Returns `self` converted into the [successor](LegacyCurrency::successor)
+    /// of its legacy currency using the fixed legal
+    /// [conversion rate](LegacyCurrency::conversion_rate). The result is not
+    /// rounded (see [`Amount::rounded`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Decimal, LegacyCurrency::{FRF, TRL}};
+    /// use oxydized_money_macros::{dec, eur, r#try};
+    ///
+    /// assert_eq!(Amount(dec!(65.5957), FRF).redenominated(), eur!(10));
+    /// assert_eq!(Amount(dec!(2_500_000), TRL).redenominated(), r#try!(2.5));
+    /// ```
+    pub fn redenominated(&self) -> Amount {
+        let currency = self.currency();
+        Amount(
+            self.value() / currency.conversion_rate(),
+            currency.successor(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        Amount, CurrencyLike, Decimal, LegacyCurrency, LegacyCurrency::*, RoundingStrategy,
+    };
+    use oxydized_money_macros::{dec, eur, zwl};
+
+    #[test]
+    fn test_redenominated() {
+        assert_eq!(Amount(dec!(1.95583), DEM).redenominated(), eur!(1));
+        assert_eq!(Amount(dec!(1936.27), ITL).redenominated(), eur!(1));
+        assert_eq!(Amount(dec!(-40.3399), BEF).redenominated(), eur!(-1));
+        assert_eq!(
+            Amount(dec!(3_000_000_000_000), ZWR).redenominated(),
+            zwl!(3)
+        );
+        assert_eq!(
+            Amount(dec!(100), DEM)
+                .redenominated()
+                .rounded(RoundingStrategy::MidpointAwayFromZero),
+            eur!(51.13)
+        );
+    }
+
+    #[test]
+    fn test_codes() {
+        for code in ["ATS", "DEM", "FRF", "ITL", "TRL", "ZWR"] {
+            assert_eq!(LegacyCurrency::from_code(code).unwrap().code(), code);
+        }
+        assert_eq!(LegacyCurrency::from_code("USD"), None);
+        assert_eq!(format!("{}", Amount(dec!(10), NLG)), "NLG 10.00");
+    }
+}
@@ -1,17 +1,174 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(
     missing_docs,
     rustdoc::missing_crate_level_docs,
     rustdoc::broken_intra_doc_links
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod amount;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "async-graphql")]
+mod async_graphql;
+#[cfg(feature = "apache-avro")]
+mod avro;
+#[cfg(feature = "std")]
+mod bag;
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+mod budget;
+#[cfg(feature = "compact-amount")]
+mod compact;
+mod country;
+mod currency;
+#[cfg(any(feature = "diesel-postgres", feature = "diesel-mysql"))]
+mod diesel;
+mod encoding;
 mod error;
+mod exchange;
+#[cfg(feature = "fake")]
+mod fake;
+#[cfg(feature = "fast-amount")]
+mod fast;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "garde")]
+pub mod garde;
+#[cfg(feature = "google-money")]
+mod google;
+mod iter;
+#[cfg(feature = "juniper")]
+mod juniper;
+mod ledger;
+mod legacy;
+#[cfg(feature = "alloc")]
+mod map;
+#[cfg(feature = "bson")]
+mod mongodb;
+mod newtype;
+mod nonzero;
 mod ops;
+mod ordered;
+#[cfg(feature = "postgres-types")]
+mod postgres_types;
+pub mod prelude;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+mod rayon;
 mod result;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "rusty-money")]
+mod rusty_money;
+#[cfg(feature = "alloc")]
+mod same_currency;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+#[cfg(feature = "sea-query")]
+mod sea_query;
+#[cfg(feature = "with_serde")]
+pub mod serde;
+mod slice;
+#[cfg(feature = "alloc")]
+mod sort;
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+mod sqlx;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "testing")]
+#[doc(hidden)]
+pub mod testing;
+mod tolerance;
+mod typed;
+#[cfg(feature = "utoipa")]
+mod utoipa;
+#[cfg(feature = "zeroize")]
+mod zeroize;
 
+#[cfg(feature = "diesel-postgres")]
+pub use self::diesel::MoneyAmount;
+#[cfg(feature = "fake")]
+pub use self::fake::{amount_in, AmountIn};
+#[cfg(feature = "rand")]
+pub use self::rand::{UniformAmount, UniformAmountError};
+#[cfg(feature = "rayon")]
+pub use self::rayon::ParallelAmountIteratorExt;
+#[cfg(feature = "rusqlite")]
+pub use self::rusqlite::MinorUnitsValue;
+#[cfg(feature = "sea-query")]
+pub use self::sea_query::AmountColumns;
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+pub use self::sqlx::ScaledValue;
 pub use amount::Amount;
-pub use error::{CurrencyError, Result};
+#[cfg(feature = "arrow")]
+pub use arrow::{AmountArray, AmountArrayBuilder};
+#[cfg(feature = "apache-avro")]
+pub use avro::{AvroAmountError, AVRO_SCALE};
+#[cfg(feature = "std")]
+pub use bag::MoneyBag;
+pub use budget::Envelope;
+#[cfg(feature = "compact-amount")]
+pub use compact::CompactAmount;
+pub use country::{currency_for_country, default_currency_for_locale};
+pub use currency::CurrencyLike;
+pub use encoding::BinaryAmountError;
+pub use error::{CurrencyError, Operation, Result};
+#[cfg(feature = "async")]
+pub use exchange::AsyncRateProvider;
+#[cfg(feature = "ecb")]
+pub use exchange::EcbRates;
+#[cfg(feature = "openexchangerates")]
+pub use exchange::OpenExchangeRates;
+#[cfg(any(feature = "ecb", feature = "openexchangerates"))]
+pub use exchange::RateSourceError;
+#[cfg(feature = "alloc")]
+pub use exchange::StaticRates;
+#[cfg(feature = "std")]
+pub use exchange::{
+    CacheError, CachingRateProvider, DateFallback, DatedRates, HistoricalRateProvider,
+};
+pub use exchange::{Converter, ExchangeRate, RateProvider};
+#[cfg(feature = "fast-amount")]
+pub use fast::FastAmount;
+#[cfg(feature = "google-money")]
+pub use google::{GoogleMoney, GoogleMoneyError};
 pub use iso_currency::Currency;
+pub use iter::AmountIteratorExt;
+pub use ledger::{Balance, Side};
+#[cfg(feature = "std")]
+pub use ledger::{Imbalance, JournalEntry, JournalError, JournalLine, TrialBalance};
+pub use legacy::LegacyCurrency;
+#[cfg(feature = "alloc")]
+pub use map::AmountMapExt;
+#[cfg(feature = "bson")]
+pub use mongodb::BsonAmountError;
+pub use newtype::MoneyNewtype;
+pub use nonzero::NonZeroAmount;
+pub use ordered::OrderedAmount;
+pub use oxydized_money_macros::{markers, CurrencyMarker};
 pub use result::AmountResult;
-pub use rust_decimal::Decimal;
+pub use rust_decimal::{Decimal, RoundingStrategy};
+#[cfg(feature = "alloc")]
+pub use same_currency::SameCurrency;
+pub use slice::{convert_slice, scale_slice, sum_slice};
+#[cfg(feature = "alloc")]
+pub use sort::{sort_amounts, sort_amounts_by_abs};
+pub use tolerance::Tolerance;
+pub use typed::TypedAmount;
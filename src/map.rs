@@ -0,0 +1,104 @@
+use crate::{Amount, CurrencyLike, Result};
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::{collections::HashMap, hash::BuildHasher};
+
+/// `AmountMapExt` accumulates amounts in maps of per-currency totals, such
+/// as a [`HashMap<Currency, Amount>`](HashMap) or a
+/// [`BTreeMap<Currency, Amount>`](BTreeMap), inserting the total of a
+/// currency the first time an amount in that currency is accumulated.
+///
+/// Both methods return the new total of the currency of the amount. On
+/// error, the map is left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{AmountMapExt, Currency, Amount, Decimal, Currency::*};
+/// use oxydized_money_macros::{eur, usd};
+/// use std::collections::HashMap;
+///
+/// let mut totals: HashMap<Currency, Amount> = HashMap::new();
+/// assert_eq!(totals.add_amount(eur!(10)), Ok(eur!(10)));
+/// assert_eq!(totals.add_amount(usd!(5)), Ok(usd!(5)));
+/// assert_eq!(totals.sub_amount(eur!(2.5)), Ok(eur!(7.5)));
+/// assert_eq!(totals[&EUR], eur!(7.5));
+/// assert_eq!(totals[&USD], usd!(5));
+/// ```
+pub trait AmountMapExt<C: CurrencyLike> {
+    /// Adds `amount` to the total of its currency. Fails with a
+    /// [`CurrencyError::Mismatch`](crate::CurrencyError::Mismatch) if that
+    /// total is in another currency, or with a
+    /// [`CurrencyError::Overflow`](crate::CurrencyError::Overflow) if the
+    /// new total does not fit in a [`Decimal`](crate::Decimal).
+    fn add_amount(&mut self, amount: Amount<C>) -> Result<Amount<C>, C>;
+
+    /// Subtracts `amount` from the total of its currency, which is
+    /// considered to be zero if the map does not hold it yet. Fails as
+    /// [`add_amount`](AmountMapExt::add_amount) does.
+    fn sub_amount(&mut self, amount: Amount<C>) -> Result<Amount<C>, C> {
+        self.add_amount(-amount)
+    }
+}
+
+fn added<C: CurrencyLike>(total: Option<&Amount<C>>, amount: Amount<C>) -> Result<Amount<C>, C> {
+    match total {
+        Some(total) => (*total + amount).into_inner(),
+        None => Ok(amount),
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: CurrencyLike, S: BuildHasher> AmountMapExt<C> for HashMap<C, Amount<C>, S> {
+    fn add_amount(&mut self, amount: Amount<C>) -> Result<Amount<C>, C> {
+        let total = added(self.get(&amount.currency()), amount)?;
+        self.insert(amount.currency(), total);
+        Ok(total)
+    }
+}
+
+impl<C: CurrencyLike + Ord> AmountMapExt<C> for BTreeMap<C, Amount<C>> {
+    fn add_amount(&mut self, amount: Amount<C>) -> Result<Amount<C>, C> {
+        let total = added(self.get(&amount.currency()), amount)?;
+        self.insert(amount.currency(), total);
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AmountMapExt;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency, Currency::*, CurrencyError::*, Decimal};
+    use oxydized_money_macros::{eur, jpy, usd};
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_hash_map() {
+        let mut totals: HashMap<Currency, Amount> = HashMap::new();
+        assert_eq!(totals.sub_amount(eur!(2)), Ok(eur!(-2)));
+        assert_eq!(totals.add_amount(eur!(5)), Ok(eur!(3)));
+        assert_eq!(totals.add_amount(jpy!(100)), Ok(jpy!(100)));
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[&EUR], eur!(3));
+
+        totals.insert(USD, eur!(1));
+        assert_eq!(totals.add_amount(usd!(1)), Err(Mismatch(EUR, USD)));
+        assert_eq!(totals[&USD], eur!(1));
+
+        totals.insert(EUR, Amount(Decimal::MAX, EUR));
+        assert_eq!(totals.add_amount(eur!(1)), Err(Overflow));
+        assert_eq!(totals[&EUR], Amount(Decimal::MAX, EUR));
+    }
+
+    #[test]
+    fn test_btree_map() {
+        let mut totals: BTreeMap<Currency, Amount> = BTreeMap::new();
+        for amount in [usd!(1), eur!(2), usd!(3)] {
+            totals.add_amount(amount).unwrap();
+        }
+        assert_eq!(totals.sub_amount(eur!(0.5)), Ok(eur!(1.5)));
+        let totals: Vec<Amount> = totals.into_values().collect();
+        assert_eq!(totals, [eur!(1.5), usd!(4)]);
+    }
+}
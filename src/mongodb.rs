@@ -0,0 +1,206 @@
+use crate::{Amount, Currency, Decimal};
+use bson::{doc, Bson, Decimal128};
+use std::{error::Error, fmt::Display};
+
+const EXPONENT_BIAS: i32 = 6176;
+const MAX_SCALE: i32 = 28;
+const MAX_MANTISSA: u128 = (1 << 96) - 1;
+
+/// `BsonAmountError` represents the reasons why a BSON value is not a valid
+/// [`Amount`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BsonAmountError {
+    /// The value is not a document with a `Decimal128` field named `value`
+    /// and a string field named `currency`.
+    InvalidDocument,
+
+    /// The currency is not a known ISO 4217 code.
+    UnknownCurrency(String),
+
+    /// The `Decimal128` value is not finite or does not fit in a [`Decimal`].
+    InvalidValue,
+}
+
+impl Error for BsonAmountError {}
+
+impl Display for BsonAmountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use BsonAmountError::*;
+        match self {
+            InvalidDocument => write!(f, "invalid amount document"),
+            UnknownCurrency(code) => write!(f, "unknown currency code '{}'", code),
+            InvalidValue => write!(f, "invalid amount value"),
+        }
+    }
+}
+
+/// With the `bson` feature, amounts are converted to BSON documents with a
+/// `Decimal128` value and a string currency code, e.g.
+/// `{"value": NumberDecimal("10.50"), "currency": "EUR"}`, so that MongoDB
+/// stores and compares their values exactly. Structures holding amounts
+/// can use the [`serde::bson`](crate::serde::bson) adapter to the same
+/// effect.
+///
+/// # Examples
+///
+/// ```
+/// use bson::Bson;
+/// use oxydized_money::{Amount, Decimal};
+/// use oxydized_money_macros::eur;
+///
+/// let bson = Bson::from(eur!(10.50));
+/// assert_eq!(bson.as_document().unwrap().get_str("currency").unwrap(), "EUR");
+/// assert_eq!(Amount::try_from(bson), Ok(eur!(10.50)));
+/// ```
+impl From<Amount> for Bson {
+    fn from(amount: Amount) -> Self {
+        Bson::Document(doc! {
+            "value": to_decimal128(amount.value()),
+            "currency": amount.currency().code(),
+        })
+    }
+}
+
+impl TryFrom<Bson> for Amount {
+    type Error = BsonAmountError;
+
+    fn try_from(bson: Bson) -> Result<Self, BsonAmountError> {
+        let Bson::Document(document) = bson else {
+            return Err(BsonAmountError::InvalidDocument);
+        };
+        let value = document
+            .get_decimal128("value")
+            .map_err(|_| BsonAmountError::InvalidDocument)?;
+        let code = document
+            .get_str("currency")
+            .map_err(|_| BsonAmountError::InvalidDocument)?;
+        let currency = Currency::from_code(code)
+            .ok_or_else(|| BsonAmountError::UnknownCurrency(code.to_owned()))?;
+        let value = from_decimal128(value).ok_or(BsonAmountError::InvalidValue)?;
+        Ok(Amount(value, currency))
+    }
+}
+
+/// Encodes a decimal as a BSON `Decimal128`, i.e. an IEEE 754 decimal128
+/// number in the binary integer decimal encoding, keeping its scale.
+pub(crate) fn to_decimal128(value: Decimal) -> Decimal128 {
+    let mantissa = value.mantissa();
+    let sign = u128::from(mantissa < 0) << 127;
+    let exponent = ((EXPONENT_BIAS - value.scale() as i32) as u128) << 113;
+    Decimal128::from_bytes((sign | exponent | mantissa.unsigned_abs()).to_le_bytes())
+}
+
+/// Decodes a BSON `Decimal128`, returning `None` if it is not finite or
+/// does not fit in a decimal without losing precision.
+pub(crate) fn from_decimal128(value: &Decimal128) -> Option<Decimal> {
+    let bits = u128::from_le_bytes(value.bytes());
+    if (bits >> 125) & 0b11 == 0b11 {
+        // infinities, NaNs and coefficients too large for a decimal
+        return None;
+    }
+    let negative = bits >> 127 == 1;
+    let mut exponent = ((bits >> 113) & 0x3fff) as i32 - EXPONENT_BIAS;
+    let mut coefficient = bits & ((1 << 113) - 1);
+    while exponent < -MAX_SCALE {
+        if coefficient % 10 != 0 {
+            return None;
+        }
+        coefficient /= 10;
+        exponent += 1;
+    }
+    while exponent > 0 {
+        coefficient = coefficient.checked_mul(10)?;
+        exponent -= 1;
+    }
+    if coefficient > MAX_MANTISSA {
+        return None;
+    }
+    let mantissa = if negative {
+        -(coefficient as i128)
+    } else {
+        coefficient as i128
+    };
+    Some(Decimal::from_i128_with_scale(mantissa, (-exponent) as u32))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_decimal128, to_decimal128, BsonAmountError};
+    use crate as oxydized_money;
+    use bson::{doc, Bson, Decimal128};
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy};
+
+    fn decimal128(bits: u128) -> Decimal128 {
+        Decimal128::from_bytes(bits.to_le_bytes())
+    }
+
+    #[test]
+    fn test_to_decimal128() {
+        assert_eq!(
+            to_decimal128(dec!(1)),
+            decimal128(0x3040_0000_0000_0000_0000_0000_0000_0001)
+        );
+        assert_eq!(
+            to_decimal128(dec!(-1.5)),
+            decimal128(0xB03E_0000_0000_0000_0000_0000_0000_000F)
+        );
+    }
+
+    #[test]
+    fn test_from_decimal128() {
+        for value in [dec!(0), dec!(10.50), dec!(-1.5), Decimal::MAX, Decimal::MIN] {
+            let decoded = from_decimal128(&to_decimal128(value)).unwrap();
+            assert_eq!(decoded, value);
+            assert_eq!(decoded.scale(), value.scale());
+        }
+        let exponent = |exponent: i32| ((6176 + exponent) as u128) << 113;
+        assert_eq!(
+            from_decimal128(&decimal128(exponent(3) | 1)),
+            Some(dec!(1000))
+        );
+        assert_eq!(
+            from_decimal128(&decimal128(exponent(-30) | 100)),
+            Some(dec!(0.0000000000000000000000000001))
+        );
+        assert_eq!(from_decimal128(&decimal128(exponent(-30) | 1)), None);
+        assert_eq!(from_decimal128(&decimal128(exponent(40) | 1)), None);
+        assert_eq!(from_decimal128(&decimal128(0x7C00 << 112)), None);
+    }
+
+    #[test]
+    fn test_bson() {
+        for amount in [eur!(10.50), jpy!(-1200), eur!(0)] {
+            assert_eq!(Amount::try_from(Bson::from(amount)), Ok(amount));
+        }
+        let bson = Bson::from(eur!(10.50));
+        let document = bson.as_document().unwrap();
+        let value = document.get_decimal128("value").unwrap();
+        assert_eq!(from_decimal128(value), Some(dec!(10.50)));
+        assert_eq!(document.get_str("currency").unwrap(), "EUR");
+    }
+
+    #[test]
+    fn test_bson_errors() {
+        assert_eq!(
+            Amount::try_from(Bson::String("10.50 EUR".to_owned())),
+            Err(BsonAmountError::InvalidDocument)
+        );
+        let document = doc! { "value": "10.50", "currency": "EUR" };
+        assert_eq!(
+            Amount::try_from(Bson::Document(document)),
+            Err(BsonAmountError::InvalidDocument)
+        );
+        let document = doc! { "value": to_decimal128(dec!(1)), "currency": "XYZ" };
+        assert_eq!(
+            Amount::try_from(Bson::Document(document)),
+            Err(BsonAmountError::UnknownCurrency("XYZ".to_owned()))
+        );
+        let nan = decimal128(0x7C00 << 112);
+        let document = doc! { "value": nan, "currency": "EUR" };
+        assert_eq!(
+            Amount::try_from(Bson::Document(document)),
+            Err(BsonAmountError::InvalidValue)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{Amount, Currency, CurrencyLike};
+
+/// `MoneyNewtype` is implemented by newtypes wrapping an [`Amount`] to give
+/// it a distinct meaning, e.g. a `Price`, a `Fee` or a `Tax`, so that they
+/// cannot be mixed up with each other. It is usually derived with
+/// `#[derive(MoneyNewtype)]` from `oxydized-money-macros`, which also
+/// forwards the operations of amounts to the newtype:
+///
+/// * [`Display`](std::fmt::Display), and [`From`] conversions from and to
+///   the wrapped amount,
+/// * [`Neg`](std::ops::Neg), and [`Mul<Decimal>`](std::ops::Mul) returning
+///   the newtype,
+/// * [`Add`](std::ops::Add) and [`Sub`](std::ops::Sub) between values of
+///   the newtype, and [`Div<Decimal>`](std::ops::Div), returning a
+///   [`Result`](crate::Result) of the newtype.
+///
+/// With the `#[money_newtype(serde)]` attribute, the newtype is also
+/// serialized and deserialized as the wrapped amount.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Currency::*, CurrencyError, Decimal, MoneyNewtype};
+/// use oxydized_money_macros::{dec, eur, usd, MoneyNewtype};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, MoneyNewtype)]
+/// struct Price(Amount);
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, MoneyNewtype)]
+/// struct Fee(Amount);
+///
+/// let price = Price(eur!(10)) * dec!(3);
+/// assert_eq!(price.to_string(), "€ 30.00");
+/// assert_eq!(price - Price(eur!(5)), Ok(Price(eur!(25))));
+/// assert_eq!(price + Price(usd!(5)), Err(CurrencyError::Mismatch(EUR, USD)));
+///
+/// // fees and prices cannot be added, unless explicitly converted
+/// let fee = Fee(eur!(1.50));
+/// assert_eq!(price + Price::from(fee.amount()), Ok(Price(eur!(31.50))));
+/// ```
+pub trait MoneyNewtype<C: CurrencyLike = Currency>: From<Amount<C>> {
+    /// Returns the wrapped amount.
+    fn amount(&self) -> Amount<C>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::MoneyNewtype;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, CurrencyLike, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy, usd, MoneyNewtype};
+
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, MoneyNewtype)]
+    #[cfg_attr(feature = "with_serde", money_newtype(serde))]
+    struct Price(Amount);
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    struct Token;
+
+    impl CurrencyLike for Token {
+        fn code(&self) -> &'static str {
+            "TKN"
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, MoneyNewtype)]
+    struct Reward<C: CurrencyLike>(Amount<C>);
+
+    #[test]
+    fn test_conversions() {
+        let price = Price::from(eur!(10));
+        assert_eq!(price, Price(eur!(10)));
+        assert_eq!(price.amount(), eur!(10));
+        assert_eq!(Amount::from(price), eur!(10));
+        assert_eq!(format!("{:.1}", Price(usd!(2.5))), "$ 2.5");
+    }
+
+    #[test]
+    fn test_operations() {
+        assert_eq!(-Price(eur!(10)), Price(eur!(-10)));
+        assert_eq!(Price(eur!(10)) * dec!(1.5), Price(eur!(15)));
+        assert_eq!(Price(eur!(10)) + Price(eur!(5)), Ok(Price(eur!(15))));
+        assert_eq!(Price(eur!(10)) - Price(eur!(5)), Ok(Price(eur!(5))));
+        assert_eq!(Price(eur!(10)) / dec!(4), Ok(Price(eur!(2.5))));
+        assert_eq!(Price(eur!(10)) + Price(jpy!(5)), Err(Mismatch(EUR, JPY)));
+        assert_eq!(Price(eur!(10)) - Price(usd!(5)), Err(Mismatch(EUR, USD)));
+        assert_eq!(Price(eur!(10)) / dec!(0), Err(DivideByZero));
+        assert!(Price(eur!(10)) > Price(eur!(5)));
+    }
+
+    #[test]
+    fn test_generics() {
+        let reward = Reward(Amount(Decimal::TEN, Token));
+        assert_eq!(reward.amount(), Amount(Decimal::TEN, Token));
+        assert_eq!(reward + reward, Ok(Reward(Amount(dec!(20), Token))));
+        assert_eq!(reward.to_string(), "TKN 10.00");
+    }
+
+    #[test]
+    #[cfg(feature = "with_serde")]
+    fn test_serde() {
+        let json = serde_json::to_string(&Price(eur!(10.50))).unwrap();
+        assert_eq!(json, serde_json::to_string(&eur!(10.50)).unwrap());
+        assert_eq!(
+            serde_json::from_str::<Price>(&json).unwrap(),
+            Price(eur!(10.50))
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{Amount, Currency, CurrencyLike, Decimal};
+use core::{cmp::Ordering, fmt::Display};
+
+/// `NonZeroAmount` is an [`Amount`] that is known not to be zero, in the
+/// same way as [`NonZeroU32`](std::num::NonZeroU32) for integers. It can
+/// be used to express a meaningful price, or as a divisor: dividing an
+/// amount by a `NonZeroAmount` of the same currency cannot fail with a
+/// [`CurrencyError::DivideByZero`](crate::CurrencyError::DivideByZero).
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{NonZeroAmount, Amount, CurrencyError, Decimal, Currency::{EUR, USD}};
+/// use oxydized_money_macros::{eur, usd, dec};
+///
+/// let price = NonZeroAmount::new(eur!(2.5)).unwrap();
+/// assert_eq!(eur!(10) / price, Ok(dec!(4)));
+/// assert_eq!(usd!(10) / price, Err(CurrencyError::Mismatch(USD, EUR)));
+/// assert_eq!(Amount::from(price), eur!(2.5));
+/// assert_eq!(NonZeroAmount::new(eur!(0)), None);
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct NonZeroAmount<C = Currency>(Amount<C>);
+
+impl<C: CurrencyLike> NonZeroAmount<C> {
+    /// Creates a [`NonZeroAmount`] if `amount` is not zero.
+    pub fn new(amount: Amount<C>) -> Option<Self> {
+        if amount.is_zero() {
+            None
+        } else {
+            Some(Self(amount))
+        }
+    }
+
+    /// Returns the underlying [`Amount`].
+    pub fn get(&self) -> Amount<C> {
+        self.0
+    }
+
+    /// Returns the quantity of money, which is never zero.
+    pub fn value(&self) -> Decimal {
+        self.0.value()
+    }
+
+    /// Returns the currency in which [`value`](NonZeroAmount::value) is
+    /// measured.
+    pub fn currency(&self) -> C {
+        self.0.currency()
+    }
+}
+
+impl<C: CurrencyLike> Display for NonZeroAmount<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<C: CurrencyLike> PartialOrd<NonZeroAmount<C>> for NonZeroAmount<C> {
+    fn partial_cmp(&self, other: &NonZeroAmount<C>) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<C: CurrencyLike> From<NonZeroAmount<C>> for Amount<C> {
+    fn from(amount: NonZeroAmount<C>) -> Self {
+        amount.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, Decimal, NonZeroAmount};
+    use oxydized_money_macros::{eur, jpy};
+
+    #[test]
+    fn test_new() {
+        assert_eq!(NonZeroAmount::new(eur!(0)), None);
+        assert_eq!(NonZeroAmount::new(eur!(0.00)), None);
+        assert_eq!(NonZeroAmount::new(-eur!(0)), None);
+
+        let amount = NonZeroAmount::new(jpy!(-3)).unwrap();
+        assert_eq!(amount.get(), jpy!(-3));
+        assert_eq!(amount.value(), Decimal::from(-3));
+        assert_eq!(amount.currency(), JPY);
+        assert_eq!(Amount::from(amount), jpy!(-3));
+        assert_eq!(format!("{}", amount), "¥ -3.00");
+    }
+}
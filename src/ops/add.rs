@@ -1,73 +1,149 @@
-use std::ops::{Add, AddAssign};
+use core::ops::{Add, AddAssign};
 
-use crate::{Amount, AmountResult, CurrencyError::*};
+#[cfg(feature = "std")]
+use crate::MoneyBag;
+use crate::{
+    Amount, AmountResult, Balance, CurrencyError::*, CurrencyLike, CurrencyMarker, Operation,
+    Result, TypedAmount,
+};
 
-impl Add<Amount> for Amount {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Add<Amount<C>> for Amount<C> {
+    type Output = AmountResult<C>;
 
-    fn add(self, rhs: Amount) -> Self::Output {
+    fn add(self, rhs: Amount<C>) -> Self::Output {
         if self.currency() == rhs.currency() {
-            Amount(self.value() + rhs.value(), self.currency()).into()
+            match self.value().checked_add(rhs.value()) {
+                Some(value) => Amount(value, self.currency()).into(),
+                None => AmountResult::failed_in(Operation::Add, Overflow),
+            }
         } else {
-            Mismatch(self.currency(), rhs.currency()).into()
+            AmountResult::mismatch_in(Operation::Add, self, rhs)
         }
     }
 }
 
-impl Add<AmountResult> for Amount {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Add<AmountResult<C>> for Amount<C> {
+    type Output = AmountResult<C>;
 
-    fn add(self, rhs: AmountResult) -> Self::Output {
+    fn add(self, rhs: AmountResult<C>) -> Self::Output {
         match rhs.0 {
             Ok(amount) => self + amount,
             Err(Unknown) => self.into(),
-            Err(error) => error.into(),
+            Err(_) => rhs,
         }
     }
 }
 
-impl Add<AmountResult> for AmountResult {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Add<AmountResult<C>> for AmountResult<C> {
+    type Output = AmountResult<C>;
 
-    fn add(self, rhs: AmountResult) -> Self::Output {
+    fn add(self, rhs: AmountResult<C>) -> Self::Output {
         match self.0 {
             Ok(amount) => amount + rhs,
             Err(Unknown) => rhs,
-            Err(error) => error.into(),
+            Err(_) => self,
         }
     }
 }
 
-impl Add<Amount> for AmountResult {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Add<Amount<C>> for AmountResult<C> {
+    type Output = AmountResult<C>;
 
-    fn add(self, rhs: Amount) -> Self::Output {
+    fn add(self, rhs: Amount<C>) -> Self::Output {
         match self.0 {
             Ok(amount) => amount + rhs,
             Err(Unknown) => rhs.into(),
-            Err(error) => error.into(),
+            Err(_) => self,
         }
     }
 }
 
-impl AddAssign<Amount> for AmountResult {
-    fn add_assign(&mut self, rhs: Amount) {
+impl<C: CurrencyLike> AddAssign<Amount<C>> for AmountResult<C> {
+    fn add_assign(&mut self, rhs: Amount<C>) {
+        *self = *self + rhs
+    }
+}
+
+impl<C: CurrencyLike> AddAssign<AmountResult<C>> for AmountResult<C> {
+    fn add_assign(&mut self, rhs: AmountResult<C>) {
         *self = *self + rhs
     }
 }
 
-impl AddAssign<AmountResult> for AmountResult {
-    fn add_assign(&mut self, rhs: AmountResult) {
+#[cfg(feature = "std")]
+impl Add<MoneyBag> for Amount {
+    type Output = MoneyBag;
+
+    fn add(self, rhs: MoneyBag) -> Self::Output {
+        rhs + self
+    }
+}
+
+#[cfg(feature = "std")]
+impl Add<Amount> for MoneyBag {
+    type Output = MoneyBag;
+
+    fn add(mut self, rhs: Amount) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl Add<MoneyBag> for MoneyBag {
+    type Output = MoneyBag;
+
+    fn add(mut self, rhs: MoneyBag) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl AddAssign<Amount> for MoneyBag {
+    fn add_assign(&mut self, rhs: Amount) {
+        self.try_add(rhs).expect("overflow")
+    }
+}
+
+#[cfg(feature = "std")]
+impl AddAssign<MoneyBag> for MoneyBag {
+    fn add_assign(&mut self, rhs: MoneyBag) {
+        for (&currency, &value) in &rhs.totals {
+            self.accumulate(value, currency).expect("overflow")
+        }
+    }
+}
+
+impl<C: CurrencyMarker> Add<TypedAmount<C>> for TypedAmount<C> {
+    type Output = TypedAmount<C>;
+
+    fn add(self, rhs: TypedAmount<C>) -> Self::Output {
+        TypedAmount::new(self.value() + rhs.value())
+    }
+}
+
+impl<C: CurrencyMarker> AddAssign<TypedAmount<C>> for TypedAmount<C> {
+    fn add_assign(&mut self, rhs: TypedAmount<C>) {
         *self = *self + rhs
     }
 }
 
+impl<C: CurrencyLike> Add<Balance<C>> for Balance<C> {
+    type Output = Result<Balance<C>, C>;
+
+    fn add(self, rhs: Balance<C>) -> Self::Output {
+        (self.net() + rhs.net()).into_inner().map(Balance::debit)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate as oxydized_money;
+    use oxydized_money::markers;
     use oxydized_money::Decimal;
-    use oxydized_money::{Currency::*, CurrencyError::*};
-    use oxydized_money_macros::{eur, usd};
+    use oxydized_money::{Amount, Balance, Currency::*, CurrencyError::*, MoneyBag, TypedAmount};
+    use oxydized_money_macros::{dec, eur, usd};
 
     #[test]
     #[allow(clippy::op_ref)]
@@ -76,6 +152,15 @@ mod test {
         assert_eq!(eur!(3) + usd!(5), Mismatch(EUR, USD));
     }
 
+    #[test]
+    fn amount_add_amount_overflow() {
+        assert_eq!(eur!(3) + Amount(Decimal::MAX, EUR), Overflow);
+        assert_eq!(
+            Amount(Decimal::MAX, EUR) + eur!(-3),
+            Amount(Decimal::MAX - dec!(3), EUR)
+        );
+    }
+
     #[test]
     #[allow(clippy::op_ref)]
     fn amount_add_amount_result() {
@@ -120,4 +205,49 @@ mod test {
         accum += W!(usd!(1));
         assert_eq!(accum, W!(Mismatch(EUR, USD)));
     }
+
+    #[test]
+    fn money_bag_add() {
+        let bag = MoneyBag::new() + eur!(2) + usd!(3) + eur!(1);
+        assert_eq!(bag.get(EUR), eur!(3));
+        assert_eq!(bag.get(USD), usd!(3));
+        assert_eq!(
+            eur!(1) + bag.clone(),
+            MoneyBag::from_iter([eur!(4), usd!(3)])
+        );
+        assert_eq!(bag.clone() + bag, MoneyBag::from_iter([eur!(6), usd!(6)]));
+    }
+
+    #[test]
+    fn money_bag_add_assign() {
+        let mut bag = MoneyBag::new();
+        bag += eur!(2);
+        bag += usd!(3);
+        bag += MoneyBag::from(eur!(-2));
+        assert_eq!(bag, MoneyBag::from(usd!(3)));
+    }
+
+    #[test]
+    fn typed_amount_add_typed_amount() {
+        let mut amount = TypedAmount::<markers::EUR>::new(dec!(3)) + TypedAmount::new(dec!(5));
+        assert_eq!(amount, TypedAmount::new(dec!(8)));
+        amount += TypedAmount::new(dec!(-10));
+        assert_eq!(amount, TypedAmount::new(dec!(-2)));
+        assert_eq!(
+            TypedAmount::<markers::JPY>::new(dec!(1)) + TypedAmount::new(dec!(1)),
+            TypedAmount::new(dec!(2))
+        );
+    }
+
+    #[test]
+    fn balance_add_balance() {
+        let balance = Balance::debit(eur!(3)) + Balance::debit(eur!(5));
+        assert_eq!(balance, Ok(Balance::debit(eur!(8))));
+        let balance = Balance::debit(eur!(3)) + Balance::credit(eur!(5));
+        assert_eq!(balance, Ok(Balance::credit(eur!(2))));
+        let balance = Balance::credit(eur!(3)) + Balance::debit(eur!(3));
+        assert_eq!(balance, Ok(Balance::zero(EUR)));
+        let balance = Balance::credit(eur!(3)) + Balance::debit(usd!(3));
+        assert_eq!(balance, Err(Mismatch(EUR, USD)));
+    }
 }
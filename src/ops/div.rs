@@ -1,25 +1,59 @@
-use crate::{Amount, AmountResult, CurrencyError::DivideByZero, Decimal};
-use std::ops::Div;
+use crate::{
+    Amount, AmountResult,
+    CurrencyError::{DivideByZero, Mismatch, Overflow},
+    CurrencyLike, CurrencyMarker, Decimal, NonZeroAmount, Operation, Result, TypedAmount,
+};
+use core::ops::Div;
 
-impl Div<Decimal> for Amount {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Div<Decimal> for Amount<C> {
+    type Output = AmountResult<C>;
 
     fn div(self, rhs: Decimal) -> Self::Output {
         if rhs.is_zero() {
-            DivideByZero.into()
+            AmountResult::failed_in(Operation::Div, DivideByZero)
         } else {
-            Amount(self.value() / rhs, self.currency()).into()
+            match self.value().checked_div(rhs) {
+                Some(value) => Amount(value, self.currency()).into(),
+                None => AmountResult::failed_in(Operation::Div, Overflow),
+            }
         }
     }
 }
 
-impl Div<Decimal> for AmountResult {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Div<Decimal> for AmountResult<C> {
+    type Output = AmountResult<C>;
 
     fn div(self, rhs: Decimal) -> Self::Output {
         match self.0 {
             Ok(amount) => amount / rhs,
-            Err(error) => error.into(),
+            Err(_) => self,
+        }
+    }
+}
+
+impl<C: CurrencyMarker> Div<Decimal> for TypedAmount<C> {
+    type Output = Result<TypedAmount<C>>;
+
+    fn div(self, rhs: Decimal) -> Self::Output {
+        if rhs.is_zero() {
+            Err(DivideByZero)
+        } else {
+            self.value()
+                .checked_div(rhs)
+                .map(TypedAmount::new)
+                .ok_or(Overflow)
+        }
+    }
+}
+
+impl<C: CurrencyLike> Div<NonZeroAmount<C>> for Amount<C> {
+    type Output = Result<Decimal, C>;
+
+    fn div(self, rhs: NonZeroAmount<C>) -> Self::Output {
+        if self.currency() == rhs.currency() {
+            self.value().checked_div(rhs.value()).ok_or(Overflow)
+        } else {
+            Err(Mismatch(self.currency(), rhs.currency()))
         }
     }
 }
@@ -27,9 +61,10 @@ impl Div<Decimal> for AmountResult {
 #[cfg(test)]
 mod test {
     use crate as oxydized_money;
+    use oxydized_money::markers;
     use oxydized_money::Decimal;
-    use oxydized_money::{Currency::*, CurrencyError::*};
-    use oxydized_money_macros::{dec, eur};
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, NonZeroAmount, TypedAmount};
+    use oxydized_money_macros::{dec, eur, usd};
 
     #[test]
     #[allow(clippy::op_ref)]
@@ -40,6 +75,11 @@ mod test {
         assert_eq!(eur!(6.3) / dec!(0), W!(DivideByZero));
     }
 
+    #[test]
+    fn amount_div_decimal_overflow() {
+        assert_eq!(Amount(Decimal::MAX, EUR) / dec!(0.5), Overflow);
+    }
+
     #[test]
     #[allow(clippy::op_ref)]
     fn amount_result_div_decimal() {
@@ -49,4 +89,23 @@ mod test {
         assert_eq!(W!(Unknown) / dec!(3), W!(Unknown));
         assert_eq!(W!(DivideByZero) / dec!(3), W!(DivideByZero));
     }
+
+    #[test]
+    fn typed_amount_div_decimal() {
+        let amount = TypedAmount::<markers::EUR>::new(dec!(3));
+        assert_eq!(amount / dec!(2), Ok(TypedAmount::new(dec!(1.5))));
+        assert_eq!(amount / dec!(0), Err(DivideByZero));
+        let max = TypedAmount::<markers::EUR>::new(Decimal::MAX);
+        assert_eq!(max / dec!(0.5), Err(Overflow));
+    }
+
+    #[test]
+    fn amount_div_non_zero_amount() {
+        let divisor = NonZeroAmount::new(eur!(4)).unwrap();
+        assert_eq!(eur!(3) / divisor, Ok(dec!(0.75)));
+        assert_eq!(eur!(0) / divisor, Ok(dec!(0)));
+        assert_eq!(usd!(3) / divisor, Err(Mismatch(USD, EUR)));
+        let divisor = NonZeroAmount::new(eur!(0.5)).unwrap();
+        assert_eq!(Amount::max_in(EUR) / divisor, Err(Overflow));
+    }
 }
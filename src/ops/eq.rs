@@ -1,7 +1,9 @@
-use crate::{Amount, AmountResult, CurrencyError};
+use crate::{
+    Amount, AmountResult, Currency, CurrencyError, CurrencyLike, CurrencyMarker, TypedAmount,
+};
 
-impl PartialEq<AmountResult> for Amount {
-    fn eq(&self, other: &AmountResult) -> bool {
+impl<C: CurrencyLike> PartialEq<AmountResult<C>> for Amount<C> {
+    fn eq(&self, other: &AmountResult<C>) -> bool {
         match other.0 {
             Ok(amount) => *self == amount,
             Err(_) => false,
@@ -9,8 +11,8 @@ impl PartialEq<AmountResult> for Amount {
     }
 }
 
-impl PartialEq<Amount> for AmountResult {
-    fn eq(&self, other: &Amount) -> bool {
+impl<C: CurrencyLike> PartialEq<Amount<C>> for AmountResult<C> {
+    fn eq(&self, other: &Amount<C>) -> bool {
         match self.0 {
             Ok(amount) => amount == *other,
             Err(_) => false,
@@ -18,8 +20,8 @@ impl PartialEq<Amount> for AmountResult {
     }
 }
 
-impl PartialEq<AmountResult> for CurrencyError {
-    fn eq(&self, other: &AmountResult) -> bool {
+impl<C: CurrencyLike> PartialEq<AmountResult<C>> for CurrencyError<C> {
+    fn eq(&self, other: &AmountResult<C>) -> bool {
         match other.0 {
             Ok(_) => false,
             Err(error) => *self == error,
@@ -27,8 +29,8 @@ impl PartialEq<AmountResult> for CurrencyError {
     }
 }
 
-impl PartialEq<CurrencyError> for AmountResult {
-    fn eq(&self, other: &CurrencyError) -> bool {
+impl<C: CurrencyLike> PartialEq<CurrencyError<C>> for AmountResult<C> {
+    fn eq(&self, other: &CurrencyError<C>) -> bool {
         match self.0 {
             Ok(_) => false,
             Err(error) => error == *other,
@@ -36,12 +38,25 @@ impl PartialEq<CurrencyError> for AmountResult {
     }
 }
 
+impl<C: CurrencyMarker> PartialEq<Amount<Currency>> for TypedAmount<C> {
+    fn eq(&self, other: &Amount<Currency>) -> bool {
+        Amount::from(*self) == *other
+    }
+}
+
+impl<C: CurrencyMarker> PartialEq<TypedAmount<C>> for Amount<Currency> {
+    fn eq(&self, other: &TypedAmount<C>) -> bool {
+        *self == Amount::from(*other)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate as oxydized_money;
+    use oxydized_money::markers;
     use oxydized_money::Decimal;
-    use oxydized_money::{Currency::*, CurrencyError::*};
-    use oxydized_money_macros::{eur, usd};
+    use oxydized_money::{Currency::*, CurrencyError::*, TypedAmount};
+    use oxydized_money_macros::{dec, eur, usd};
 
     #[test]
     #[allow(clippy::op_ref)]
@@ -102,4 +117,13 @@ mod test {
         assert!(Mismatch(EUR, USD) == W!(Mismatch(EUR, USD)));
         assert!(Mismatch(USD, EUR) != W!(Mismatch(EUR, USD)));
     }
+
+    #[test]
+    fn typed_amount_eq_amount() {
+        let amount = TypedAmount::<markers::EUR>::new(dec!(3));
+        assert!(amount == eur!(3));
+        assert!(eur!(3) == amount);
+        assert!(amount != eur!(4));
+        assert!(usd!(3) != amount);
+    }
 }
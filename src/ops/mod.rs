@@ -1,7 +1,7 @@
 #[cfg(test)]
 macro_rules! W {
     ($expr:expr) => {
-        $crate::AmountResult::from($expr)
+        $crate::AmountResult::new($expr)
     };
 }
 
@@ -10,4 +10,5 @@ mod div;
 mod eq;
 mod mul;
 mod neg;
+mod ord;
 mod sub;
@@ -1,30 +1,60 @@
-use crate::{Amount, AmountResult, Decimal};
-use std::ops::Mul;
+use crate::{
+    Amount, AmountResult, CurrencyError::Overflow, CurrencyLike, CurrencyMarker, Decimal,
+    Operation, TypedAmount,
+};
+use core::ops::Mul;
 
-impl Mul<Decimal> for Amount {
-    type Output = Amount;
+/// Multiplies an amount by a decimal.
+///
+/// # Panics
+///
+/// Unlike the other operations, which report a
+/// [`CurrencyError::Overflow`](crate::CurrencyError::Overflow), the
+/// multiplication of an amount panics if the result does not fit in a
+/// [`Decimal`], as it outputs an [`Amount`]. Multiply an [`AmountResult`]
+/// instead to get the error, e.g. `AmountResult::from(amount) * rhs`.
+impl<C: CurrencyLike> Mul<Decimal> for Amount<C> {
+    type Output = Amount<C>;
 
     fn mul(self, rhs: Decimal) -> Self::Output {
         Amount(self.value() * rhs, self.currency())
     }
 }
 
-impl Mul<Decimal> for AmountResult {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Mul<Decimal> for AmountResult<C> {
+    type Output = AmountResult<C>;
 
     fn mul(self, rhs: Decimal) -> Self::Output {
         match self.0 {
-            Ok(amount) => (amount * rhs).into(),
-            Err(error) => error.into(),
+            Ok(amount) => match amount.value().checked_mul(rhs) {
+                Some(value) => Amount(value, amount.currency()).into(),
+                None => AmountResult::failed_in(Operation::Mul, Overflow),
+            },
+            Err(_) => self,
         }
     }
 }
 
+/// Multiplies a typed amount by a decimal.
+///
+/// # Panics
+///
+/// Panics if the result does not fit in a [`Decimal`], as for
+/// [`Amount`]s.
+impl<C: CurrencyMarker> Mul<Decimal> for TypedAmount<C> {
+    type Output = TypedAmount<C>;
+
+    fn mul(self, rhs: Decimal) -> Self::Output {
+        TypedAmount::new(self.value() * rhs)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate as oxydized_money;
+    use oxydized_money::markers;
     use oxydized_money::Decimal;
-    use oxydized_money::{Currency::*, CurrencyError::*};
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, Operation, TypedAmount};
     use oxydized_money_macros::{dec, eur};
 
     #[test]
@@ -34,12 +64,31 @@ mod test {
         assert_eq!(eur!(-2) * dec!(3), eur!(-6));
     }
 
+    #[test]
+    #[should_panic]
+    fn amount_mul_decimal_overflow() {
+        let _ = Amount::max_in(EUR) * dec!(2);
+    }
+
     #[test]
     #[allow(clippy::op_ref)]
     fn amount_result_mul_decimal() {
         assert_eq!(W!(eur!(2)) * dec!(3), eur!(6));
+        assert_eq!(W!(Amount::max_in(EUR)) * dec!(2), Overflow);
+        assert_eq!(
+            (W!(Amount::max_in(EUR)) * dec!(2)).operation(),
+            Some(Operation::Mul)
+        );
         assert_eq!(W!(Mismatch(USD, EUR)) * dec!(3), W!(Mismatch(USD, EUR)));
         assert_eq!(W!(Unknown) * dec!(3), W!(Unknown));
         assert_eq!(W!(DivideByZero) * dec!(3), W!(DivideByZero));
     }
+
+    #[test]
+    fn typed_amount_mul_decimal() {
+        let amount = TypedAmount::<markers::EUR>::new(dec!(1.2));
+        assert_eq!(amount * dec!(2), TypedAmount::new(dec!(2.4)));
+        assert_eq!(amount * -dec!(1), TypedAmount::new(dec!(-1.2)));
+        assert_eq!(amount * dec!(0), TypedAmount::new(dec!(0)));
+    }
 }
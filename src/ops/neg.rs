@@ -1,30 +1,47 @@
-use crate::{Amount, AmountResult};
-use std::ops::Neg;
+use crate::{Amount, AmountResult, Balance, CurrencyLike, CurrencyMarker, TypedAmount};
+use core::ops::Neg;
 
-impl Neg for Amount {
-    type Output = Amount;
+impl<C: CurrencyLike> Neg for Amount<C> {
+    type Output = Amount<C>;
 
     fn neg(self) -> Self::Output {
         Amount(-self.value(), self.currency())
     }
 }
 
-impl Neg for AmountResult {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Neg for AmountResult<C> {
+    type Output = AmountResult<C>;
     fn neg(self) -> Self::Output {
         match self.0 {
             Ok(amount) => (-amount).into(),
-            Err(error) => error.into(),
+            Err(_) => self,
         }
     }
 }
 
+impl<C: CurrencyMarker> Neg for TypedAmount<C> {
+    type Output = TypedAmount<C>;
+
+    fn neg(self) -> Self::Output {
+        TypedAmount::new(-self.value())
+    }
+}
+
+impl<C: CurrencyLike> Neg for Balance<C> {
+    type Output = Balance<C>;
+
+    fn neg(self) -> Self::Output {
+        Balance::new(self.amount(), self.side().opposite())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate as oxydized_money;
+    use oxydized_money::markers;
     use oxydized_money::Decimal;
-    use oxydized_money::{Currency::*, CurrencyError::*};
-    use oxydized_money_macros::{eur, usd};
+    use oxydized_money::{Balance, Currency::*, CurrencyError::*, TypedAmount};
+    use oxydized_money_macros::{dec, eur, usd};
 
     #[test]
     fn neg_amount() {
@@ -40,4 +57,18 @@ mod test {
         assert_eq!(-W!(Unknown), W!(Unknown));
         assert_eq!(-W!(DivideByZero), W!(DivideByZero));
     }
+
+    #[test]
+    fn neg_typed_amount() {
+        let amount = TypedAmount::<markers::EUR>::new(dec!(3));
+        assert_eq!(-amount, TypedAmount::new(dec!(-3)));
+        assert_eq!(-(-amount), amount);
+    }
+
+    #[test]
+    fn neg_balance() {
+        assert_eq!(-Balance::debit(eur!(3)), Balance::credit(eur!(3)));
+        assert_eq!(-Balance::credit(eur!(3)), Balance::debit(eur!(3)));
+        assert_eq!(-Balance::zero(EUR), Balance::zero(EUR));
+    }
 }
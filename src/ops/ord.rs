@@ -0,0 +1,51 @@
+use core::cmp::Ordering;
+
+use crate::{Amount, AmountResult, CurrencyLike};
+
+impl<C: CurrencyLike> PartialOrd<AmountResult<C>> for Amount<C> {
+    fn partial_cmp(&self, other: &AmountResult<C>) -> Option<Ordering> {
+        match other.0 {
+            Ok(amount) => self.partial_cmp(&amount),
+            Err(_) => None,
+        }
+    }
+}
+
+impl<C: CurrencyLike> PartialOrd<Amount<C>> for AmountResult<C> {
+    fn partial_cmp(&self, other: &Amount<C>) -> Option<Ordering> {
+        match self.0 {
+            Ok(amount) => amount.partial_cmp(other),
+            Err(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::Decimal;
+    use oxydized_money::{Currency::*, CurrencyError::*};
+    use oxydized_money_macros::{eur, usd};
+    use std::cmp::Ordering::*;
+
+    #[test]
+    fn amount_cmp_amount_result() {
+        assert_eq!(eur!(10).partial_cmp(&W!(eur!(12))), Some(Less));
+        assert_eq!(eur!(10).partial_cmp(&W!(eur!(10))), Some(Equal));
+        assert_eq!(eur!(10).partial_cmp(&W!(eur!(8))), Some(Greater));
+        assert_eq!(eur!(10).partial_cmp(&W!(usd!(10))), None);
+        assert_eq!(eur!(10).partial_cmp(&W!(Unknown)), None);
+        assert_eq!(eur!(10).partial_cmp(&W!(Mismatch(EUR, USD))), None);
+        assert!(eur!(100) < eur!(60) + eur!(50));
+    }
+
+    #[test]
+    fn amount_result_cmp_amount() {
+        assert_eq!(W!(eur!(12)).partial_cmp(&eur!(10)), Some(Greater));
+        assert_eq!(W!(eur!(10)).partial_cmp(&eur!(10)), Some(Equal));
+        assert_eq!(W!(eur!(8)).partial_cmp(&eur!(10)), Some(Less));
+        assert_eq!(W!(usd!(10)).partial_cmp(&eur!(10)), None);
+        assert_eq!(W!(DivideByZero).partial_cmp(&eur!(10)), None);
+        assert!(eur!(60) + eur!(50) > eur!(100));
+    }
+}
@@ -1,72 +1,139 @@
-use crate::{Amount, AmountResult, CurrencyError::*};
-use std::ops::{Sub, SubAssign};
-
-impl Sub<Amount> for Amount {
-    type Output = AmountResult;
-
-    fn sub(self, rhs: Amount) -> Self::Output {
+#[cfg(feature = "std")]
+use crate::MoneyBag;
+use crate::{
+    Amount, AmountResult, Balance, CurrencyError::*, CurrencyLike, CurrencyMarker, Operation,
+    Result, TypedAmount,
+};
+use core::ops::{Sub, SubAssign};
+
+impl<C: CurrencyLike> Sub<Amount<C>> for Amount<C> {
+    type Output = AmountResult<C>;
+
+    fn sub(self, rhs: Amount<C>) -> Self::Output {
         if self.currency() == rhs.currency() {
-            Amount(self.value() - rhs.value(), self.currency()).into()
+            match self.value().checked_sub(rhs.value()) {
+                Some(value) => Amount(value, self.currency()).into(),
+                None => AmountResult::failed_in(Operation::Sub, Overflow),
+            }
         } else {
-            Mismatch(self.currency(), rhs.currency()).into()
+            AmountResult::mismatch_in(Operation::Sub, self, rhs)
         }
     }
 }
 
-impl Sub<AmountResult> for Amount {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Sub<AmountResult<C>> for Amount<C> {
+    type Output = AmountResult<C>;
 
-    fn sub(self, rhs: AmountResult) -> Self::Output {
+    fn sub(self, rhs: AmountResult<C>) -> Self::Output {
         match rhs.0 {
             Ok(amount) => self - amount,
             Err(Unknown) => self.into(),
-            Err(error) => error.into(),
+            Err(_) => rhs,
         }
     }
 }
 
-impl Sub<AmountResult> for AmountResult {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Sub<AmountResult<C>> for AmountResult<C> {
+    type Output = AmountResult<C>;
 
-    fn sub(self, rhs: AmountResult) -> Self::Output {
+    fn sub(self, rhs: AmountResult<C>) -> Self::Output {
         match self.0 {
             Ok(amount) => amount - rhs,
             Err(Unknown) => -rhs,
-            Err(error) => error.into(),
+            Err(_) => self,
         }
     }
 }
 
-impl Sub<Amount> for AmountResult {
-    type Output = AmountResult;
+impl<C: CurrencyLike> Sub<Amount<C>> for AmountResult<C> {
+    type Output = AmountResult<C>;
 
-    fn sub(self, rhs: Amount) -> Self::Output {
+    fn sub(self, rhs: Amount<C>) -> Self::Output {
         match self.0 {
             Ok(amount) => amount - rhs,
             Err(Unknown) => (-rhs).into(),
-            Err(error) => error.into(),
+            Err(_) => self,
         }
     }
 }
 
-impl SubAssign<Amount> for AmountResult {
-    fn sub_assign(&mut self, rhs: Amount) {
+impl<C: CurrencyLike> SubAssign<Amount<C>> for AmountResult<C> {
+    fn sub_assign(&mut self, rhs: Amount<C>) {
+        *self = *self - rhs
+    }
+}
+
+impl<C: CurrencyLike> SubAssign<AmountResult<C>> for AmountResult<C> {
+    fn sub_assign(&mut self, rhs: AmountResult<C>) {
         *self = *self - rhs
     }
 }
 
-impl SubAssign<AmountResult> for AmountResult {
-    fn sub_assign(&mut self, rhs: AmountResult) {
+#[cfg(feature = "std")]
+impl Sub<Amount> for MoneyBag {
+    type Output = MoneyBag;
+
+    fn sub(mut self, rhs: Amount) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl Sub<MoneyBag> for MoneyBag {
+    type Output = MoneyBag;
+
+    fn sub(mut self, rhs: MoneyBag) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl SubAssign<Amount> for MoneyBag {
+    fn sub_assign(&mut self, rhs: Amount) {
+        self.try_sub(rhs).expect("overflow")
+    }
+}
+
+#[cfg(feature = "std")]
+impl SubAssign<MoneyBag> for MoneyBag {
+    fn sub_assign(&mut self, rhs: MoneyBag) {
+        for (&currency, &value) in &rhs.totals {
+            self.accumulate(-value, currency).expect("overflow")
+        }
+    }
+}
+
+impl<C: CurrencyMarker> Sub<TypedAmount<C>> for TypedAmount<C> {
+    type Output = TypedAmount<C>;
+
+    fn sub(self, rhs: TypedAmount<C>) -> Self::Output {
+        TypedAmount::new(self.value() - rhs.value())
+    }
+}
+
+impl<C: CurrencyMarker> SubAssign<TypedAmount<C>> for TypedAmount<C> {
+    fn sub_assign(&mut self, rhs: TypedAmount<C>) {
         *self = *self - rhs
     }
 }
 
+impl<C: CurrencyLike> Sub<Balance<C>> for Balance<C> {
+    type Output = Result<Balance<C>, C>;
+
+    fn sub(self, rhs: Balance<C>) -> Self::Output {
+        (self.net() - rhs.net()).into_inner().map(Balance::debit)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate as oxydized_money;
+    use oxydized_money::markers;
     use oxydized_money::Decimal;
-    use oxydized_money::{Currency::*, CurrencyError::*};
-    use oxydized_money_macros::{eur, usd};
+    use oxydized_money::{Amount, Balance, Currency::*, CurrencyError::*, MoneyBag, TypedAmount};
+    use oxydized_money_macros::{dec, eur, usd};
 
     #[test]
     #[allow(clippy::op_ref)]
@@ -75,6 +142,11 @@ mod test {
         assert_eq!(eur!(3) - usd!(5), Mismatch(EUR, USD));
     }
 
+    #[test]
+    fn amount_sub_amount_overflow() {
+        assert_eq!(Amount(Decimal::MIN, EUR) - eur!(3), Overflow);
+    }
+
     #[test]
     #[allow(clippy::op_ref)]
     fn amount_sub_amount_result() {
@@ -119,4 +191,44 @@ mod test {
         accum -= W!(usd!(1));
         assert_eq!(accum, W!(Mismatch(EUR, USD)));
     }
+
+    #[test]
+    fn money_bag_sub() {
+        let bag = MoneyBag::new() - eur!(2) + usd!(3);
+        assert_eq!(bag.get(EUR), eur!(-2));
+        assert_eq!(bag.clone() - usd!(3), MoneyBag::from(eur!(-2)));
+        assert_eq!(bag.clone() - bag, MoneyBag::new());
+    }
+
+    #[test]
+    fn money_bag_sub_assign() {
+        let mut bag = MoneyBag::from(eur!(5));
+        bag -= eur!(2);
+        bag -= MoneyBag::from_iter([eur!(1), usd!(1)]);
+        assert_eq!(bag, MoneyBag::from_iter([eur!(2), usd!(-1)]));
+    }
+
+    #[test]
+    fn typed_amount_sub_typed_amount() {
+        let mut amount = TypedAmount::<markers::EUR>::new(dec!(3)) - TypedAmount::new(dec!(5));
+        assert_eq!(amount, TypedAmount::new(dec!(-2)));
+        amount -= TypedAmount::new(dec!(-10));
+        assert_eq!(amount, TypedAmount::new(dec!(8)));
+        assert_eq!(
+            TypedAmount::<markers::JPY>::new(dec!(1)) - TypedAmount::new(dec!(1)),
+            TypedAmount::new(dec!(0))
+        );
+    }
+
+    #[test]
+    fn balance_sub_balance() {
+        let balance = Balance::debit(eur!(3)) - Balance::debit(eur!(5));
+        assert_eq!(balance, Ok(Balance::credit(eur!(2))));
+        let balance = Balance::credit(eur!(3)) - Balance::credit(eur!(5));
+        assert_eq!(balance, Ok(Balance::debit(eur!(2))));
+        let balance = Balance::credit(eur!(3)) - Balance::debit(eur!(5));
+        assert_eq!(balance, Ok(Balance::credit(eur!(8))));
+        let balance = Balance::credit(eur!(3)) - Balance::debit(usd!(3));
+        assert_eq!(balance, Err(Mismatch(EUR, USD)));
+    }
 }
@@ -0,0 +1,97 @@
+use crate::{Amount, Currency, CurrencyLike};
+use core::{cmp::Ordering, fmt::Display};
+
+/// `OrderedAmount` wraps an [`Amount`] to give it a total order, following
+/// [`Amount::total_cmp`], i.e. by currency code, then by value. Unlike
+/// amounts, whose [`PartialOrd`] cannot compare amounts in different
+/// currencies, ordered amounts can be sorted or used as keys of a
+/// [`BTreeMap`](std::collections::BTreeMap) or a
+/// [`BTreeSet`](std::collections::BTreeSet).
+///
+/// This order is not a monetary comparison, and should only be used to
+/// arrange amounts in a deterministic order.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Decimal, OrderedAmount};
+/// use oxydized_money_macros::{eur, usd};
+/// use std::collections::BTreeSet;
+///
+/// let amounts: BTreeSet<_> = [usd!(1), eur!(5), eur!(-2), eur!(5.00)]
+///     .into_iter()
+///     .map(OrderedAmount)
+///     .collect();
+/// let amounts: Vec<_> = amounts.into_iter().map(|amount| amount.0).collect();
+/// assert_eq!(amounts, [eur!(-2), eur!(5), usd!(1)]);
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct OrderedAmount<C = Currency>(pub Amount<C>);
+
+impl<C: CurrencyLike> Ord for OrderedAmount<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<C: CurrencyLike> PartialOrd for OrderedAmount<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: CurrencyLike> Display for OrderedAmount<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<C> From<Amount<C>> for OrderedAmount<C> {
+    fn from(amount: Amount<C>) -> Self {
+        Self(amount)
+    }
+}
+
+impl<C> From<OrderedAmount<C>> for Amount<C> {
+    fn from(amount: OrderedAmount<C>) -> Self {
+        amount.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Decimal, OrderedAmount};
+    use oxydized_money_macros::{eur, jpy, usd};
+    use std::{cmp::Ordering::*, collections::BTreeMap};
+
+    #[test]
+    fn test_total_cmp() {
+        assert_eq!(eur!(1).total_cmp(&eur!(1.00)), Equal);
+        assert_eq!(eur!(-1).total_cmp(&eur!(1)), Less);
+        assert_eq!(eur!(1000).total_cmp(&jpy!(1)), Less);
+        assert_eq!(usd!(1).total_cmp(&jpy!(1000)), Greater);
+    }
+
+    #[test]
+    fn test_ordered_amount() {
+        let mut amounts: Vec<OrderedAmount> = [usd!(2), jpy!(5), eur!(3), usd!(-1)]
+            .into_iter()
+            .map(OrderedAmount::from)
+            .collect();
+        amounts.sort();
+        let amounts: Vec<Amount> = amounts.into_iter().map(Amount::from).collect();
+        assert_eq!(amounts, [eur!(3), jpy!(5), usd!(-1), usd!(2)]);
+
+        let mut counts = BTreeMap::new();
+        for amount in [eur!(1), usd!(1), eur!(1.0)] {
+            *counts.entry(OrderedAmount(amount)).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&OrderedAmount(eur!(1))], 2);
+        assert_eq!(counts.len(), 2);
+        assert_eq!(
+            OrderedAmount(eur!(1)).partial_cmp(&OrderedAmount(usd!(1))),
+            Some(Less)
+        );
+    }
+}
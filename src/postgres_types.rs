@@ -0,0 +1,189 @@
+use crate::{Amount, Currency, Decimal};
+use ::postgres_types::{to_sql_checked, FromSql, IsNull, Kind, ToSql, Type};
+use bytes::{BufMut, BytesMut};
+use std::error::Error;
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+const TYPE_NAME: &str = "money_amount";
+
+/// With the `postgres-types` feature, amounts are bound to and read from
+/// PostgreSQL columns of the `money_amount` composite type by the
+/// `postgres` and `tokio-postgres` clients:
+///
+/// ```sql
+/// CREATE TYPE money_amount AS (value NUMERIC, currency CHAR(3));
+/// ```
+///
+/// ```ignore
+/// client.execute("INSERT INTO invoices (id, total) VALUES ($1, $2)", &[&1, &eur!(10.50)])?;
+/// let row = client.query_one("SELECT total FROM invoices WHERE id = $1", &[&1])?;
+/// let total: Amount = row.get("total");
+/// ```
+///
+/// The feature also enables the `NUMERIC` support of [`Decimal`], so that
+/// amounts can be stored in a `NUMERIC` and a `CHAR(3)` column instead, by
+/// binding their value and their currency code:
+///
+/// ```ignore
+/// client.execute(
+///     "INSERT INTO invoices (id, total_value, total_currency) VALUES ($1, $2, $3)",
+///     &[&1, &total.value(), &total.currency().code()],
+/// )?;
+/// ```
+impl ToSql for Amount {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        let [value_type, currency_type] = fields(ty).ok_or("not a money_amount type")?;
+        out.put_i32(2);
+        write_field(value_type, &self.value(), out)?;
+        write_field(currency_type, &self.currency().code(), out)?;
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        fields(ty).is_some()
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Amount {
+    fn from_sql(ty: &Type, mut raw: &'a [u8]) -> Result<Self, BoxError> {
+        let [value_type, currency_type] = fields(ty).ok_or("not a money_amount type")?;
+        if read_i32(&mut raw)? != 2 {
+            return Err("invalid number of fields in money_amount".into());
+        }
+        let value: Decimal = read_field(value_type, &mut raw)?;
+        let code: &str = read_field(currency_type, &mut raw)?;
+        let currency = Currency::from_code(code.trim())
+            .ok_or_else(|| format!("unknown currency '{}'", code))?;
+        Ok(Amount(value, currency))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        fields(ty).is_some()
+    }
+}
+
+/// Returns the types of the value and currency fields of the `money_amount`
+/// composite type.
+fn fields(ty: &Type) -> Option<[&Type; 2]> {
+    match ty.kind() {
+        Kind::Composite(fields) if ty.name() == TYPE_NAME => match fields.as_slice() {
+            [value, currency]
+                if <Decimal as ToSql>::accepts(value.type_())
+                    && <&str as ToSql>::accepts(currency.type_()) =>
+            {
+                Some([value.type_(), currency.type_()])
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn write_field<T: ToSql>(ty: &Type, value: &T, out: &mut BytesMut) -> Result<(), BoxError> {
+    out.put_u32(ty.oid());
+    let start = out.len();
+    out.put_i32(0);
+    value.to_sql(ty, out)?;
+    let len = i32::try_from(out.len() - start - 4)?;
+    out[start..start + 4].copy_from_slice(&len.to_be_bytes());
+    Ok(())
+}
+
+fn read_field<'a, T: FromSql<'a>>(ty: &Type, raw: &mut &'a [u8]) -> Result<T, BoxError> {
+    read_i32(raw)?;
+    let Ok(len) = usize::try_from(read_i32(raw)?) else {
+        return T::from_sql_null(ty);
+    };
+    if raw.len() < len {
+        return Err("unexpected end of money_amount".into());
+    }
+    let (field, rest) = raw.split_at(len);
+    *raw = rest;
+    T::from_sql(ty, field)
+}
+
+fn read_i32(raw: &mut &[u8]) -> Result<i32, BoxError> {
+    let (bytes, rest) = raw
+        .split_first_chunk()
+        .ok_or("unexpected end of money_amount")?;
+    *raw = rest;
+    Ok(i32::from_be_bytes(*bytes))
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use bytes::BytesMut;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy, usd};
+    use postgres_types::{Field, FromSql, Kind, ToSql, Type};
+
+    fn money_amount(currency: Type) -> Type {
+        let fields = vec![
+            Field::new("value".to_owned(), Type::NUMERIC),
+            Field::new("currency".to_owned(), currency),
+        ];
+        Type::new(
+            "money_amount".to_owned(),
+            100_000,
+            Kind::Composite(fields),
+            "public".to_owned(),
+        )
+    }
+
+    #[test]
+    fn test_accepts() {
+        assert!(<Amount as ToSql>::accepts(&money_amount(Type::BPCHAR)));
+        assert!(<Amount as FromSql>::accepts(&money_amount(Type::TEXT)));
+        assert!(!<Amount as ToSql>::accepts(&money_amount(Type::INT4)));
+        assert!(!<Amount as ToSql>::accepts(&Type::NUMERIC));
+        assert!(!<Amount as FromSql>::accepts(&Type::TEXT));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let ty = money_amount(Type::BPCHAR);
+        for amount in [eur!(10.50), jpy!(-1200), eur!(0)] {
+            let mut buffer = BytesMut::new();
+            amount.to_sql_checked(&ty, &mut buffer).unwrap();
+            let decoded = Amount::from_sql(&ty, &buffer).unwrap();
+            assert_eq!(decoded, amount);
+            assert_eq!(decoded.value().scale(), amount.value().scale());
+        }
+        assert!(eur!(1)
+            .to_sql_checked(&Type::NUMERIC, &mut BytesMut::new())
+            .is_err());
+    }
+
+    #[test]
+    fn test_from_sql() {
+        let ty = money_amount(Type::BPCHAR);
+        let mut raw = vec![0, 0, 0, 2];
+        let mut value = BytesMut::new();
+        dec!(1.5).to_sql(&Type::NUMERIC, &mut value).unwrap();
+        raw.extend(Type::NUMERIC.oid().to_be_bytes());
+        raw.extend((value.len() as i32).to_be_bytes());
+        raw.extend(&value);
+        raw.extend(Type::BPCHAR.oid().to_be_bytes());
+        raw.extend(3i32.to_be_bytes());
+        raw.extend(b"USD");
+        assert_eq!(Amount::from_sql(&ty, &raw).unwrap(), usd!(1.5));
+
+        let mut unknown = raw.clone();
+        unknown.truncate(raw.len() - 3);
+        unknown.extend(b"XYZ");
+        assert_eq!(
+            Amount::from_sql(&ty, &unknown).unwrap_err().to_string(),
+            "unknown currency 'XYZ'"
+        );
+        assert!(Amount::from_sql(&ty, &raw[..raw.len() - 1]).is_err());
+        assert!(Amount::from_sql(&ty, &[0, 0, 0, 1]).is_err());
+
+        let mut null = raw[..raw.len() - 7].to_vec();
+        null.extend((-1i32).to_be_bytes());
+        assert!(Amount::from_sql(&ty, &null).is_err());
+    }
+}
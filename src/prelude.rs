@@ -0,0 +1,120 @@
+//! Opt-in prelude, bringing the most common types in scope along with the
+//! [`AmountLiteral`] extension trait, which constructs amounts with method
+//! syntax rather than with macros, e.g. where the hygiene of the currency
+//! macros gets in the way of downstream macros.
+//!
+//! # Examples
+//!
+//! ```
+//! use oxydized_money::prelude::*;
+//! use oxydized_money_macros::dec;
+//!
+//! assert_eq!(10.eur(), Amount(dec!(10), Currency::EUR));
+//! assert_eq!(dec!(10.5).usd(), Amount(dec!(10.5), Currency::USD));
+//! assert_eq!(1200.in_currency(Currency::JPY), Amount(dec!(1200), Currency::JPY));
+//! ```
+
+pub use crate::{Amount, AmountResult, Currency, CurrencyError, CurrencyLike, Decimal};
+
+macro_rules! currency_methods {
+    ($($method:ident => $currency:ident,)*) => {
+        $(
+            #[doc = concat!("Returns an amount of `self` in `", stringify!($currency), "`.")]
+            fn $method(self) -> Amount {
+                self.in_currency(Currency::$currency)
+            }
+        )*
+    };
+}
+
+/// `AmountLiteral` constructs amounts from integers and decimals, either in
+/// any currency with [`in_currency`](AmountLiteral::in_currency), or in one
+/// of the major currencies with a method named after its code, e.g.
+/// `10.eur()`.
+pub trait AmountLiteral: Sized {
+    /// Returns an amount of `self` in `currency`.
+    fn in_currency<C: CurrencyLike>(self, currency: C) -> Amount<C>;
+
+    currency_methods! {
+        aud => AUD,
+        brl => BRL,
+        cad => CAD,
+        chf => CHF,
+        cny => CNY,
+        czk => CZK,
+        dkk => DKK,
+        eur => EUR,
+        gbp => GBP,
+        hkd => HKD,
+        huf => HUF,
+        inr => INR,
+        jpy => JPY,
+        krw => KRW,
+        mxn => MXN,
+        nok => NOK,
+        nzd => NZD,
+        pln => PLN,
+        sek => SEK,
+        sgd => SGD,
+        r#try => TRY,
+        usd => USD,
+        zar => ZAR,
+    }
+}
+
+macro_rules! impl_amount_literal {
+    ($($type:ty),*) => {
+        $(
+            impl AmountLiteral for $type {
+                fn in_currency<C: CurrencyLike>(self, currency: C) -> Amount<C> {
+                    Amount(Decimal::from(self), currency)
+                }
+            }
+        )*
+    };
+}
+
+impl_amount_literal!(Decimal, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate as oxydized_money;
+    use oxydized_money_macros::{chf, dec, eur, jpy, r#try, usd};
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(10.eur(), eur!(10));
+        assert_eq!((-3).usd(), usd!(-3));
+        assert_eq!(0u8.chf(), chf!(0));
+        assert_eq!(
+            i64::MAX.jpy(),
+            Amount(Decimal::from(i64::MAX), Currency::JPY)
+        );
+        assert_eq!(
+            usize::MAX.r#try(),
+            Amount(Decimal::from(usize::MAX), Currency::TRY)
+        );
+        assert_eq!(5.r#try(), r#try!(5));
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_eq!(dec!(10.50).eur(), eur!(10.50));
+        assert_eq!(dec!(10.50).eur().value().scale(), 2);
+        assert_eq!(
+            dec!(-0.001).in_currency(Currency::BHD).value(),
+            dec!(-0.001)
+        );
+    }
+
+    #[test]
+    fn test_in_currency() {
+        let currency = Currency::JPY;
+        assert_eq!(1200.in_currency(currency), jpy!(1200));
+        assert_eq!(
+            dec!(1.5).in_currency(Currency::XAU).currency(),
+            Currency::XAU
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{Amount, Currency, Decimal};
+use ::quickcheck::{Arbitrary, Gen};
+use iso_currency::IntoEnumIterator;
+
+/// With the `quickcheck` feature, arbitrary amounts are in any ISO 4217
+/// currency, with up to two more decimal places than their currency.
+///
+/// Amounts shrink toward zero and toward fewer decimal places, keeping
+/// their currency, so that the counterexamples of failing properties are
+/// as simple as possible, e.g. `1 EUR` rather than `-8137.1049 EUR`.
+impl Arbitrary for Amount {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let currencies: Vec<Currency> = Currency::iter().collect();
+        let currency = *g.choose(&currencies).expect("there are currencies");
+        let max_scale = u32::from(currency.exponent().unwrap_or_default()) + 2;
+        let scale = u32::arbitrary(g) % (max_scale + 1);
+        Amount(Decimal::new(i32::arbitrary(g).into(), scale), currency)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let Amount(value, currency) = *self;
+        let mut candidates = Vec::new();
+        let mut push = |candidate: Decimal| {
+            if candidate != value && !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        };
+        push(Decimal::ZERO);
+        if value.is_sign_negative() {
+            push(value.abs());
+        }
+        for scale in 0..value.scale() {
+            push(value.trunc_with_scale(scale));
+        }
+        push((value / Decimal::TWO).trunc_with_scale(value.scale()));
+        Box::new(
+            candidates
+                .into_iter()
+                .map(move |value| Amount(value, currency)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{eur, jpy};
+    use quickcheck::{Arbitrary, Gen, QuickCheck, TestResult};
+
+    #[test]
+    fn test_arbitrary() {
+        let mut g = Gen::new(100);
+        for _ in 0..1000 {
+            let amount = Amount::arbitrary(&mut g);
+            let exponent = amount.currency().exponent().unwrap_or_default();
+            assert!(amount.value().scale() <= u32::from(exponent) + 2);
+        }
+    }
+
+    #[test]
+    fn test_shrink() {
+        let shrunk: Vec<Amount> = eur!(-12.345).shrink().collect();
+        assert_eq!(
+            shrunk,
+            [
+                eur!(0),
+                eur!(12.345),
+                eur!(-12),
+                eur!(-12.3),
+                eur!(-12.34),
+                eur!(-6.172)
+            ]
+        );
+        assert_eq!(jpy!(0).shrink().count(), 0);
+        assert_eq!(jpy!(1).shrink().collect::<Vec<_>>(), [jpy!(0)]);
+    }
+
+    #[test]
+    fn test_minimal_counterexample() {
+        fn property(amount: Amount) -> TestResult {
+            TestResult::from_bool(amount.value() < Decimal::ONE)
+        }
+        let result = std::panic::catch_unwind(|| {
+            QuickCheck::new().quickcheck(property as fn(Amount) -> TestResult)
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("Amount(1, "), "{}", message);
+    }
+}
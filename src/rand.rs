@@ -0,0 +1,165 @@
+use crate::{Amount, Currency, CurrencyError, Decimal};
+use ::rand::{distr::Distribution, Rng};
+use std::{error::Error, fmt::Display};
+
+/// `UniformAmountError` represents the reasons why a [`UniformAmount`]
+/// cannot be created from two bounds.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub enum UniformAmountError {
+    /// The bounds are in different currencies
+    /// ([`CurrencyError::Mismatch`]), have more decimal places than their
+    /// currency ([`CurrencyError::InvalidPrecision`]), or do not fit in a
+    /// [`Decimal`] once expressed with the decimal places of their currency
+    /// ([`CurrencyError::Overflow`]).
+    Currency(CurrencyError),
+
+    /// The range between the bounds is empty.
+    EmptyRange,
+}
+
+impl Error for UniformAmountError {}
+
+impl Display for UniformAmountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UniformAmountError::Currency(error) => Display::fmt(error, f),
+            UniformAmountError::EmptyRange => write!(f, "empty range of amounts"),
+        }
+    }
+}
+
+impl From<CurrencyError> for UniformAmountError {
+    fn from(error: CurrencyError) -> Self {
+        UniformAmountError::Currency(error)
+    }
+}
+
+/// `UniformAmount` is a [`Distribution`] sampling amounts uniformly between
+/// two bounds in the same currency, e.g. for simulations or load tests. The
+/// sampled amounts have the number of decimal places of their currency.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Decimal, UniformAmount};
+/// use oxydized_money_macros::eur;
+/// use rand::Rng;
+///
+/// let distribution = UniformAmount::new(eur!(1), eur!(1000)).unwrap();
+/// let amount = rand::rng().sample(distribution);
+/// assert!(amount >= eur!(1) && amount < eur!(1000));
+/// assert_eq!(amount.value().scale(), 2);
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct UniformAmount {
+    currency: Currency,
+    scale: u32,
+    low: i128,
+    high: i128,
+}
+
+impl UniformAmount {
+    /// Creates a distribution of amounts in `[low, high)`. Fails with
+    /// [`UniformAmountError::EmptyRange`] if `low >= high`, or as
+    /// [`UniformAmount::new_inclusive`].
+    pub fn new(low: Amount, high: Amount) -> Result<Self, UniformAmountError> {
+        let mut distribution = Self::new_inclusive(low, high)?;
+        if distribution.low == distribution.high {
+            return Err(UniformAmountError::EmptyRange);
+        }
+        distribution.high -= 1;
+        Ok(distribution)
+    }
+
+    /// Creates a distribution of amounts in `[low, high]`. Fails with
+    /// [`UniformAmountError::EmptyRange`] if `low > high`, or with a
+    /// [`UniformAmountError::Currency`] if the bounds are in different
+    /// currencies, have more decimal places than their currency or do not
+    /// fit in a [`Decimal`] with the decimal places of their currency.
+    pub fn new_inclusive(low: Amount, high: Amount) -> Result<Self, UniformAmountError> {
+        let currency = low.currency();
+        if high.currency() != currency {
+            return Err(CurrencyError::Mismatch(currency, high.currency()).into());
+        }
+        let scale = Amount::minor_scale(currency);
+        let distribution = UniformAmount {
+            currency,
+            scale,
+            low: low.scaled_units(scale)?,
+            high: high.scaled_units(scale)?,
+        };
+        if distribution.low > distribution.high {
+            return Err(UniformAmountError::EmptyRange);
+        }
+        Ok(distribution)
+    }
+}
+
+impl Distribution<Amount> for UniformAmount {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Amount {
+        let minor_units = rng.random_range(self.low..=self.high);
+        Amount(
+            Decimal::from_i128_with_scale(minor_units, self.scale),
+            self.currency,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{UniformAmount, UniformAmountError};
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, Decimal};
+    use oxydized_money_macros::{eur, jpy, usd};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn test_sample() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let distribution = UniformAmount::new(eur!(-1), eur!(1)).unwrap();
+        let amounts: Vec<Amount> = (&mut rng).sample_iter(distribution).take(1000).collect();
+        assert!(amounts.iter().all(|a| *a >= eur!(-1) && *a <= eur!(0.99)));
+        assert!(amounts.iter().all(|a| a.value().scale() == 2));
+        assert!(amounts.iter().any(|a| *a < eur!(0)));
+
+        let distribution = UniformAmount::new_inclusive(jpy!(10), jpy!(12)).unwrap();
+        for _ in 0..100 {
+            let amount = rng.sample(distribution);
+            assert!([jpy!(10), jpy!(11), jpy!(12)].contains(&amount));
+        }
+        let distribution = UniformAmount::new_inclusive(eur!(5.5), eur!(5.50)).unwrap();
+        assert_eq!(rng.sample(distribution), eur!(5.50));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            UniformAmount::new(eur!(1), usd!(2)),
+            Err(Mismatch(EUR, USD).into())
+        );
+        assert_eq!(
+            UniformAmount::new(eur!(1.001), eur!(2)),
+            Err(InvalidPrecision(EUR).into())
+        );
+        assert_eq!(
+            UniformAmount::new_inclusive(jpy!(1), jpy!(1.5)),
+            Err(InvalidPrecision(JPY).into())
+        );
+        assert_eq!(
+            UniformAmount::new(eur!(1), Amount::max_in(EUR)),
+            Err(Overflow.into())
+        );
+        assert_eq!(
+            UniformAmount::new(eur!(1), eur!(1)),
+            Err(UniformAmountError::EmptyRange)
+        );
+        assert_eq!(
+            UniformAmount::new_inclusive(eur!(1), eur!(0.99)),
+            Err(UniformAmountError::EmptyRange)
+        );
+        assert_eq!(
+            UniformAmountError::EmptyRange.to_string(),
+            "empty range of amounts"
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{Amount, AmountResult, CurrencyError, CurrencyLike, Decimal};
+use ::rayon::iter::ParallelIterator;
+use std::collections::HashMap;
+
+/// `ParallelAmountIteratorExt` sums rayon parallel iterators of
+/// [`Amount`]s or [`AmountResult`]s, with the `rayon` feature.
+///
+/// Summing a parallel iterator with [`ParallelIterator::sum`] combines
+/// partial sums in an order that depends on how the work was split, so
+/// that the error reported for amounts in several currencies may vary from
+/// one run to another. The methods of this trait keep the order of the
+/// items instead, and report the same error as a sequential
+/// [`Sum`](std::iter::Sum), i.e. the first of:
+///
+/// - an error wrapped by an [`AmountResult`] of the iterator, except
+///   [`CurrencyError::Unknown`] which is ignored;
+/// - a [`CurrencyError::Mismatch`] between the currency of the first
+///   amount and the next other currency;
+/// - a [`CurrencyError::Overflow`] if a partial sum does not fit in a
+///   [`Decimal`].
+///
+/// The partial sums are those of the parts the work was split into, rather
+/// than those of the successive prefixes of the iterator: whether a sum
+/// overflows may therefore depend on the split, e.g. `MAX + 1 - 1`
+/// overflows sequentially, but not when computed as `MAX + (1 - 1)`.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Currency::*, Decimal, ParallelAmountIteratorExt};
+/// use rayon::prelude::*;
+///
+/// let amounts: Vec<Amount> = (1..=1000).map(|i| Amount(Decimal::from(i), EUR)).collect();
+/// assert_eq!(amounts.par_iter().total(), Amount(Decimal::from(500500), EUR));
+///
+/// let totals = amounts.par_iter().sum_by_currency().unwrap();
+/// assert_eq!(totals[&EUR], Amount(Decimal::from(500500), EUR));
+/// ```
+pub trait ParallelAmountIteratorExt<C>: ParallelIterator
+where
+    C: CurrencyLike + Send,
+    Self::Item: Into<AmountResult<C>>,
+{
+    /// Returns the sum of the amounts, or a [`CurrencyError::Unknown`] if
+    /// the iterator is empty.
+    fn total(self) -> AmountResult<C> {
+        self.map(|item| Partial::from(item.into()))
+            .reduce(|| Partial::Empty, Partial::combine)
+            .into()
+    }
+
+    /// Returns the total of the amounts in each currency, as
+    /// [`AmountIteratorExt::sum_by_currency`](crate::AmountIteratorExt::sum_by_currency).
+    fn sum_by_currency(self) -> Result<HashMap<C, Amount<C>>, CurrencyError<C>> {
+        self.fold(
+            || Ok(HashMap::new()),
+            |totals, item| {
+                let mut totals = totals?;
+                match item.into().into_inner() {
+                    Ok(amount) => accumulate(&mut totals, amount)?,
+                    Err(CurrencyError::Unknown) => {}
+                    Err(error) => return Err(error),
+                }
+                Ok(totals)
+            },
+        )
+        .reduce(
+            || Ok(HashMap::new()),
+            |left, right| {
+                let mut left = left?;
+                for amount in right?.into_values() {
+                    accumulate(&mut left, amount)?;
+                }
+                Ok(left)
+            },
+        )
+    }
+}
+
+impl<I, C> ParallelAmountIteratorExt<C> for I
+where
+    I: ParallelIterator,
+    I::Item: Into<AmountResult<C>>,
+    C: CurrencyLike + Send,
+{
+}
+
+/// Adds `amount` to the total of its currency.
+fn accumulate<C: CurrencyLike>(
+    totals: &mut HashMap<C, Amount<C>>,
+    amount: Amount<C>,
+) -> Result<(), CurrencyError<C>> {
+    let currency = amount.currency();
+    let total = totals
+        .entry(currency)
+        .or_insert(Amount(Decimal::ZERO, currency));
+    total.0 = total
+        .value()
+        .checked_add(amount.value())
+        .ok_or(CurrencyError::Overflow)?;
+    Ok(())
+}
+
+/// The sum of a contiguous part of a parallel iterator.
+enum Partial<C> {
+    /// No amount.
+    Empty,
+    /// Amounts in a single currency.
+    Total(C, Decimal),
+    /// Amounts ending with an error, where `first` is the currency of the
+    /// first amount before the error, if any.
+    Failed {
+        first: Option<C>,
+        error: CurrencyError<C>,
+    },
+}
+
+impl<C: CurrencyLike> Partial<C> {
+    /// Returns the error of a part starting with an amount in `first`.
+    fn failed(first: C, error: CurrencyError<C>) -> Self {
+        Partial::Failed {
+            first: Some(first),
+            error,
+        }
+    }
+
+    /// Returns the sum of `self` followed by `right`.
+    fn combine(self, right: Self) -> Self {
+        match (self, right) {
+            (Partial::Empty, partial) | (partial, Partial::Empty) => partial,
+            (left @ Partial::Failed { .. }, _) => left,
+            (Partial::Total(first, left), Partial::Total(other, right)) => {
+                if other != first {
+                    Partial::failed(first, CurrencyError::Mismatch(first, other))
+                } else if let Some(total) = left.checked_add(right) {
+                    Partial::Total(first, total)
+                } else {
+                    Partial::failed(first, CurrencyError::Overflow)
+                }
+            }
+            (
+                Partial::Total(first, _),
+                Partial::Failed {
+                    first: other,
+                    error,
+                },
+            ) => match other {
+                Some(other) if other != first => {
+                    Partial::failed(first, CurrencyError::Mismatch(first, other))
+                }
+                _ => Partial::failed(first, error),
+            },
+        }
+    }
+}
+
+impl<C: CurrencyLike> From<AmountResult<C>> for Partial<C> {
+    fn from(result: AmountResult<C>) -> Self {
+        match result.into_inner() {
+            Ok(amount) => Partial::Total(amount.currency(), amount.value()),
+            Err(CurrencyError::Unknown) => Partial::Empty,
+            Err(error) => Partial::Failed { first: None, error },
+        }
+    }
+}
+
+impl<C: CurrencyLike> From<Partial<C>> for AmountResult<C> {
+    fn from(partial: Partial<C>) -> Self {
+        match partial {
+            Partial::Empty => AmountResult::from(CurrencyError::Unknown),
+            Partial::Total(currency, value) => AmountResult::from(Amount(value, currency)),
+            Partial::Failed { error, .. } => AmountResult::from(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ParallelAmountIteratorExt;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, AmountResult, Currency::*, CurrencyError::*, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy, usd};
+    use rayon::prelude::*;
+
+    fn amounts(len: i64) -> Vec<Amount> {
+        (1..=len).map(|i| Amount(Decimal::new(i, 2), EUR)).collect()
+    }
+
+    #[test]
+    fn test_total() {
+        let amounts = amounts(100_000);
+        assert_eq!(amounts.par_iter().total(), eur!(50000500));
+        assert_eq!(amounts.into_par_iter().total(), eur!(50000500));
+        assert!(Vec::<Amount>::new().par_iter().total().is_unknown());
+    }
+
+    #[test]
+    fn test_total_errors() {
+        for len in [3, 10, 1000, 100_000] {
+            let mut amounts = amounts(len);
+            amounts[len as usize / 2] = usd!(1);
+            amounts[len as usize - 1] = jpy!(1);
+            for _ in 0..10 {
+                assert_eq!(
+                    amounts.par_iter().total().into_inner(),
+                    Err(Mismatch(EUR, USD))
+                );
+            }
+        }
+
+        let mut results: Vec<AmountResult> = amounts(10_000).into_iter().map(Into::into).collect();
+        results[0] = AmountResult::unknown();
+        assert_eq!(
+            results.par_iter().total(),
+            amounts(10_000)[1..].iter().sum::<AmountResult>()
+        );
+        results[10] = usd!(1).into();
+        results[500] = eur!(1) / dec!(0);
+        results[9000] = Amount(Decimal::MAX, EUR).into();
+        let sum = results.iter().sum::<AmountResult>();
+        assert_eq!(sum.into_inner(), Err(Mismatch(EUR, USD)));
+        for _ in 0..10 {
+            assert_eq!(results.par_iter().total(), sum);
+        }
+        results[5] = eur!(1) / dec!(0);
+        for _ in 0..10 {
+            assert!(results.par_iter().total().is_divide_by_zero());
+        }
+
+        let amounts = [Amount(Decimal::MAX, EUR), eur!(1)];
+        assert!(amounts.par_iter().total().is_overflow());
+    }
+
+    #[test]
+    fn test_sum_by_currency() {
+        let mut amounts = amounts(10_000);
+        amounts.extend((0..10_000).map(|_| usd!(0.5)));
+        let totals = amounts.par_iter().sum_by_currency().unwrap();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[&EUR], eur!(500050));
+        assert_eq!(totals[&USD], usd!(5000));
+
+        let results = [AmountResult::from(eur!(1)), eur!(1) / dec!(0)];
+        assert_eq!(results.par_iter().sum_by_currency(), Err(DivideByZero));
+        let amounts = [Amount(Decimal::MAX, EUR), usd!(1), eur!(1)];
+        assert_eq!(amounts.par_iter().sum_by_currency(), Err(Overflow));
+        let results = [AmountResult::unknown(), eur!(1).into()];
+        assert_eq!(
+            results.par_iter().sum_by_currency(),
+            Ok([(EUR, eur!(1))].into_iter().collect())
+        );
+    }
+}
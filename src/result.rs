@@ -1,10 +1,11 @@
-use std::{
+use core::{
     fmt::Display,
+    hash::{Hash, Hasher},
     iter::Sum,
     ops::{Deref, DerefMut},
 };
 
-use crate::{Amount, Currency, CurrencyError, Decimal, Result};
+use crate::{Amount, Currency, CurrencyError, CurrencyLike, Decimal, Operation, Result};
 
 /// `AmountResult` represents the result of a computation involving
 /// [amounts](Amount) of money. It can therefore either be an [`Amount`]
@@ -17,12 +18,120 @@ use crate::{Amount, Currency, CurrencyError, Decimal, Result};
 /// can/should be checked at the very end of the computation.
 ///
 /// Note that [`AmountResult`] is nothing but a wrapper around a
-/// [`std::result::Result<Amount, CurrencyError>`](Result).
+/// [`std::result::Result<Amount, CurrencyError>`](Result), along with
+/// optional information describing where the error originated (see
+/// [`AmountResult::context`] and [`AmountResult::operation`]). This
+/// information is not taken into account when comparing results.
 ///
-#[derive(Clone, Debug, Copy, Eq, PartialEq, Hash)]
-pub struct AmountResult(pub(crate) Result<Amount>);
+/// The currency type defaults to [`Currency`] in type annotations, but is
+/// inferred in expressions: `AmountResult::from(CurrencyError::Unknown)`
+/// does not compile on its own, as the error does not tell the currency
+/// type. Use [`AmountResult::new`], [`AmountResult::unknown`] or an
+/// annotation such as `AmountResult::<Currency>::from` instead.
+///
+/// With the `mismatch-operands` feature, the amounts that caused a
+/// [`CurrencyError::Mismatch`] are recorded as well (see
+/// [`AmountResult::mismatch_operands`]). This feature is opt-in because
+/// it doubles the size of an [`AmountResult`].
+///
+/// With the `with_serde` feature, results are serialized as an externally
+/// tagged structure holding either an `amount` or an `error`, e.g.
+/// `{"amount":{"value":"10.50","currency":"EUR"}}` or
+/// `{"error":{"type":"mismatch","left":"EUR","right":"USD"}}` (see
+/// [`Amount`] and [`CurrencyError`] for their own format). The context,
+/// operation and operands describing where an error originated are not
+/// serialized.
+///
+#[derive(Clone, Debug, Copy)]
+#[cfg_attr(
+    feature = "with_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        from = "AmountResultRepr<C>",
+        into = "AmountResultRepr<C>",
+        bound(
+            serialize = "C: Clone + serde::Serialize",
+            deserialize = "C: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct AmountResult<C = Currency>(pub(crate) Result<Amount<C>, C>, pub(crate) Origin<C>);
+
+#[cfg(feature = "with_serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(
+    rename = "AmountResult",
+    rename_all = "snake_case",
+    bound(
+        serialize = "C: Clone + serde::Serialize",
+        deserialize = "C: serde::Deserialize<'de>"
+    )
+)]
+enum AmountResultRepr<C> {
+    Amount(Amount<C>),
+    Error(CurrencyError<C>),
+}
+
+#[cfg(feature = "with_serde")]
+impl<C> From<AmountResultRepr<C>> for AmountResult<C> {
+    fn from(repr: AmountResultRepr<C>) -> Self {
+        match repr {
+            AmountResultRepr::Amount(amount) => Self(Ok(amount), Origin::default()),
+            AmountResultRepr::Error(error) => Self(Err(error), Origin::default()),
+        }
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl<C> From<AmountResult<C>> for AmountResultRepr<C> {
+    fn from(result: AmountResult<C>) -> Self {
+        match result.0 {
+            Ok(amount) => AmountResultRepr::Amount(amount),
+            Err(error) => AmountResultRepr::Error(error),
+        }
+    }
+}
+
+/// Where the error wrapped by an [`AmountResult`] originated.
+#[derive(Clone, Debug, Copy)]
+pub(crate) struct Origin<C> {
+    context: Option<&'static str>,
+    operation: Option<Operation>,
+    #[cfg(feature = "mismatch-operands")]
+    operands: Option<(Amount<C>, Amount<C>)>,
+    #[cfg(not(feature = "mismatch-operands"))]
+    operands: core::marker::PhantomData<C>,
+}
+
+impl<C> Default for Origin<C> {
+    fn default() -> Self {
+        Self {
+            context: None,
+            operation: None,
+            operands: Default::default(),
+        }
+    }
+}
 
 impl AmountResult {
+    /// Creates a [`AmountResult`] in the default [`Currency`] from anything
+    /// it can be converted from. Unlike [`AmountResult::from`], the type of
+    /// the currency does not need to be inferred, so that the errors
+    /// without any currency can be given as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, CurrencyError::*, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// assert_eq!(AmountResult::new(eur!(1)), eur!(1));
+    /// assert!(AmountResult::new(DivideByZero).is_divide_by_zero());
+    /// ```
+    pub fn new(value: impl Into<AmountResult>) -> Self {
+        value.into()
+    }
+
     /// Creates a [`AmountResult`] around a [`CurrencyError::Unknown`]
     ///
     /// # Examples
@@ -37,6 +146,22 @@ impl AmountResult {
         Self::from(CurrencyError::Unknown)
     }
 
+    /// Creates a [`AmountResult`] around a [`CurrencyError::DivideByZero`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, CurrencyError, Currency::{USD,EUR}};
+    ///
+    /// assert!(AmountResult::divide_by_zero().is_err());
+    /// assert_eq!(AmountResult::divide_by_zero().unwrap_err(), CurrencyError::DivideByZero);
+    /// ```
+    pub fn divide_by_zero() -> Self {
+        Self::from(CurrencyError::DivideByZero)
+    }
+}
+
+impl<C: CurrencyLike> AmountResult<C> {
     /// Creates a [`AmountResult`] around a [`CurrencyError::Mismatch`]
     ///
     /// # Examples
@@ -47,22 +172,51 @@ impl AmountResult {
     /// assert!(AmountResult::mismatch(USD,EUR).is_err());
     /// assert_eq!(AmountResult::mismatch(EUR,USD).unwrap_err(), CurrencyError::Mismatch(EUR,USD));
     /// ```
-    pub fn mismatch(c1: Currency, c2: Currency) -> Self {
+    pub fn mismatch(c1: C, c2: C) -> Self {
         Self::from(CurrencyError::Mismatch(c1, c2))
     }
 
-    /// Creates a [`AmountResult`] around a [`CurrencyError::DivideByZero`]
+    /// Creates a [`AmountResult`] around a [`CurrencyError::MissingRate`]
     ///
     /// # Examples
     ///
     /// ```
     /// use oxydized_money::{AmountResult, CurrencyError, Currency::{USD,EUR}};
     ///
-    /// assert!(AmountResult::divide_by_zero().is_err());
-    /// assert_eq!(AmountResult::divide_by_zero().unwrap_err(), CurrencyError::DivideByZero);
+    /// assert!(AmountResult::missing_rate(USD,EUR).is_err());
+    /// assert_eq!(AmountResult::missing_rate(EUR,USD).unwrap_err(), CurrencyError::MissingRate(EUR,USD));
     /// ```
-    pub fn divide_by_zero() -> Self {
-        Self::from(CurrencyError::DivideByZero)
+    pub fn missing_rate(from: C, to: C) -> Self {
+        Self::from(CurrencyError::MissingRate(from, to))
+    }
+
+    /// Sums an iterator of fallible amounts, such as rows fetched from a
+    /// database. Stops at the first error `E` and returns it, otherwise
+    /// returns the [`Sum`] of the amounts, in which currency errors are
+    /// coalesced as usual.
+    ///
+    /// This is equivalent to summing into a `Result<AmountResult, E>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, AmountResult, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let rows: Vec<Result<Amount, String>> = vec![Ok(eur!(1)), Ok(eur!(2))];
+    /// assert_eq!(AmountResult::try_sum(rows), Ok(AmountResult::from(eur!(3))));
+    ///
+    /// let rows: Vec<Result<Amount, String>> = vec![Ok(eur!(1)), Ok(usd!(2))];
+    /// assert!(AmountResult::try_sum(rows).unwrap().is_mismatch());
+    ///
+    /// let rows = vec![Ok(eur!(1)), Err("connection lost"), Ok(usd!(2))];
+    /// assert_eq!(AmountResult::try_sum(rows), Err("connection lost"));
+    /// ```
+    pub fn try_sum<I, E>(iter: I) -> core::result::Result<Self, E>
+    where
+        I: IntoIterator<Item = core::result::Result<Amount<C>, E>>,
+    {
+        iter.into_iter().sum()
     }
 
     /// Returns the absolute value of `self` if it wraps an [`Amount`].
@@ -80,7 +234,7 @@ impl AmountResult {
     /// ```
     ///
     pub fn abs(&self) -> Self {
-        AmountResult(self.map(|amount| amount.abs()))
+        self.map_amount(|amount| amount.abs())
     }
 
     /// Returns the value of `self` converted to the target currency if
@@ -107,8 +261,482 @@ impl AmountResult {
     /// );
     /// ```
     ///
-    pub fn converted_to(&self, target_currency: Currency, exchange_rate: Decimal) -> Self {
-        AmountResult(self.map(|amount| amount.converted_to(target_currency, exchange_rate)))
+    pub fn converted_to(&self, target_currency: C, exchange_rate: Decimal) -> Self {
+        self.map_amount(|amount| amount.converted_to(target_currency, exchange_rate))
+    }
+
+    /// Applies `f` to the wrapped [`Amount`], if any. Coalesces the error
+    /// otherwise. Unlike [`Result::map`], which is reachable through
+    /// [`Deref`], this returns an [`AmountResult`] so that it can be chained
+    /// with further arithmetic operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Decimal};
+    /// use oxydized_money_macros::{eur, usd, dec};
+    ///
+    /// let fee = |amount| amount * dec!(0.1);
+    /// assert_eq!((eur!(10) + eur!(20)).map_amount(fee) + eur!(1), eur!(4));
+    /// assert!((eur!(10) + usd!(20)).map_amount(fee).is_mismatch());
+    /// ```
+    pub fn map_amount<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Amount<C>) -> Amount<C>,
+    {
+        match self.0 {
+            Ok(amount) => f(amount).into(),
+            Err(_) => self,
+        }
+    }
+
+    /// Applies `f` to the wrapped [`Amount`], if any, and returns its result.
+    /// Coalesces the error otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Decimal};
+    /// use oxydized_money_macros::{eur, dec};
+    ///
+    /// let split = |amount| amount / dec!(2);
+    /// assert_eq!((eur!(10) + eur!(20)).and_then_amount(split), eur!(15));
+    /// assert!(AmountResult::from(eur!(10)).and_then_amount(|amount| amount / dec!(0)).is_divide_by_zero());
+    /// assert!(AmountResult::unknown().and_then_amount(split).is_unknown());
+    /// ```
+    pub fn and_then_amount<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(Amount<C>) -> R,
+        R: Into<AmountResult<C>>,
+    {
+        match self.0 {
+            Ok(amount) => f(amount).into(),
+            Err(_) => self,
+        }
+    }
+
+    /// Calls `f` with a reference to the wrapped [`Amount`], if any, and
+    /// returns `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Decimal};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// let mut seen = Vec::new();
+    /// let total = (eur!(1) + eur!(2)).inspect(|amount| seen.push(*amount)) + eur!(3);
+    /// assert_eq!(total, eur!(6));
+    /// assert_eq!(seen, vec![eur!(3)]);
+    /// ```
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Amount<C>),
+    {
+        if let Ok(amount) = &self.0 {
+            f(amount);
+        }
+        self
+    }
+
+    /// Applies `f` to the wrapped [`CurrencyError`], if any, and returns its
+    /// result. Returns `self` unchanged otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, CurrencyError, Decimal, Currency::EUR};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let fallback = |error| match error {
+    ///     CurrencyError::Unknown => AmountResult::from(eur!(0)),
+    ///     error => AmountResult::from(error),
+    /// };
+    /// assert_eq!(AmountResult::unknown().or_else_amount(fallback), eur!(0));
+    /// assert_eq!(AmountResult::from(eur!(5)).or_else_amount(fallback), eur!(5));
+    /// assert!((eur!(1) + usd!(1)).or_else_amount(fallback).is_mismatch());
+    /// ```
+    pub fn or_else_amount<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(CurrencyError<C>) -> R,
+        R: Into<AmountResult<C>>,
+    {
+        match self.0 {
+            Ok(amount) => amount.into(),
+            Err(error) => f(error).into(),
+        }
+    }
+
+    /// Returns `self` if it wraps an [`Amount`] in the `expected` currency,
+    /// or a [`CurrencyError::Mismatch`] if it wraps an [`Amount`] in another
+    /// currency (see [`Amount::expect_currency`]). Coalesces the error
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, CurrencyError, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// assert_eq!((eur!(1) + eur!(2)).expect_currency(EUR), eur!(3));
+    /// assert_eq!(
+    ///     (usd!(1) + usd!(2)).expect_currency(EUR),
+    ///     AmountResult::mismatch(EUR, USD)
+    /// );
+    /// assert!(AmountResult::unknown().expect_currency(EUR).is_unknown());
+    /// ```
+    pub fn expect_currency(self, expected: C) -> Self {
+        self.and_then_amount(|amount| amount.expect_currency(expected))
+    }
+
+    /// Returns the pair of wrapped [`Amount`]s if both `self` and `other`
+    /// wrap an [`Amount`]. Otherwise, coalesces their errors as the
+    /// arithmetic operators do: the first error that is not a
+    /// [`CurrencyError::Unknown`] is returned, if any, and
+    /// [`CurrencyError::Unknown`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, CurrencyError, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd, dec};
+    ///
+    /// let a = AmountResult::from(eur!(1));
+    /// assert_eq!(a.zip(usd!(2).into()), Ok((eur!(1), usd!(2))));
+    /// assert_eq!(a.zip(AmountResult::unknown()), Err(CurrencyError::Unknown));
+    /// assert_eq!(
+    ///     AmountResult::unknown().zip(eur!(1) / dec!(0)),
+    ///     Err(CurrencyError::DivideByZero)
+    /// );
+    /// ```
+    pub fn zip(self, other: AmountResult<C>) -> Result<(Amount<C>, Amount<C>), C> {
+        match (self.0, other.0) {
+            (Ok(left), Ok(right)) => Ok((left, right)),
+            (Err(CurrencyError::Unknown), Err(error)) => Err(error),
+            (Err(error), _) | (_, Err(error)) => Err(error),
+        }
+    }
+
+    /// Applies the binary operation `f` to the [`Amount`]s wrapped by `self`
+    /// and `other`. Coalesces their errors as in [`AmountResult::zip`]
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, AmountResult, Decimal};
+    /// use oxydized_money_macros::{eur, usd, dec};
+    ///
+    /// let max = |a: Amount, b: Amount| if a.currency() != b.currency() {
+    ///     AmountResult::mismatch(a.currency(), b.currency())
+    /// } else {
+    ///     AmountResult::from(if a > b { a } else { b })
+    /// };
+    /// let a = AmountResult::from(eur!(1));
+    /// assert_eq!(a.zip_with(eur!(2).into(), max), eur!(2));
+    /// assert!(a.zip_with(usd!(2).into(), max).is_mismatch());
+    /// assert!(a.zip_with(eur!(2) / dec!(0), max).is_divide_by_zero());
+    /// ```
+    pub fn zip_with<F, R>(self, other: AmountResult<C>, f: F) -> Self
+    where
+        F: FnOnce(Amount<C>, Amount<C>) -> R,
+        R: Into<AmountResult<C>>,
+    {
+        match (self.0, other.0) {
+            (Ok(left), Ok(right)) => f(left, right).into(),
+            (Err(CurrencyError::Unknown), Err(_)) => other,
+            (Err(_), _) => self,
+            (_, Err(_)) => other,
+        }
+    }
+
+    /// Applies `f` to the currencies of a wrapped [`CurrencyError::Mismatch`]
+    /// and returns its result, e.g. to fall back on a currency conversion.
+    /// Returns `self` unchanged otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Decimal, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd, dec};
+    ///
+    /// let total = (eur!(10) + usd!(5)).recover_mismatch(|_, _| {
+    ///     eur!(10) + usd!(5).converted_to(EUR, dec!(0.9))
+    /// });
+    /// assert_eq!(total, eur!(14.5));
+    /// assert!(AmountResult::unknown().recover_mismatch(|_, _| eur!(0)).is_unknown());
+    /// ```
+    pub fn recover_mismatch<F, R>(self, f: F) -> Self
+    where
+        F: FnOnce(C, C) -> R,
+        R: Into<AmountResult<C>>,
+    {
+        match self.0 {
+            Err(CurrencyError::Mismatch(c1, c2)) => f(c1, c2).into(),
+            _ => self,
+        }
+    }
+
+    /// Converts `self` into a [`std::result::Result`], translating the
+    /// wrapped [`CurrencyError`], if any, with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, CurrencyError, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum InvoiceError {
+    ///     MixedCurrencies,
+    ///     Other(CurrencyError),
+    /// }
+    ///
+    /// let to_invoice_error = |error| match error {
+    ///     CurrencyError::Mismatch(_, _) => InvoiceError::MixedCurrencies,
+    ///     error => InvoiceError::Other(error),
+    /// };
+    /// assert_eq!((eur!(1) + eur!(2)).map_error(to_invoice_error), Ok(eur!(3)));
+    /// assert_eq!(
+    ///     (eur!(1) + usd!(2)).map_error(to_invoice_error),
+    ///     Err(InvoiceError::MixedCurrencies)
+    /// );
+    /// ```
+    pub fn map_error<E, F>(self, f: F) -> core::result::Result<Amount<C>, E>
+    where
+        F: FnOnce(CurrencyError<C>) -> E,
+    {
+        self.0.map_err(f)
+    }
+
+    /// Replaces a [`CurrencyError::Unknown`] by `fallback`, typically to
+    /// provide a default value for the [`Sum`] of an empty collection of
+    /// amounts. Other errors, such as [`CurrencyError::Mismatch`], are
+    /// propagated unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, AmountResult, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let no_expenses: Vec<Amount> = Vec::new();
+    /// let total = no_expenses.iter().sum::<AmountResult>().or_amount(eur!(0));
+    /// assert_eq!(total + eur!(10), eur!(10));
+    /// assert!((eur!(1) + usd!(1)).or_amount(eur!(0)).is_mismatch());
+    /// ```
+    pub fn or_amount(self, fallback: Amount<C>) -> Self {
+        self.or_amount_if(fallback, |error| matches!(error, CurrencyError::Unknown))
+    }
+
+    /// Replaces the wrapped [`CurrencyError`] by `fallback` if `predicate`
+    /// returns `true` for it. Returns `self` unchanged otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, CurrencyError, Decimal};
+    /// use oxydized_money_macros::{eur, dec};
+    ///
+    /// let any_error = |_: &CurrencyError| true;
+    /// let no_error = |_: &CurrencyError| false;
+    /// assert_eq!((eur!(1) / dec!(0)).or_amount_if(eur!(0), any_error), eur!(0));
+    /// assert!((eur!(1) / dec!(0)).or_amount_if(eur!(0), no_error).is_divide_by_zero());
+    /// assert_eq!(AmountResult::from(eur!(1)).or_amount_if(eur!(0), any_error), eur!(1));
+    /// ```
+    pub fn or_amount_if<P>(self, fallback: Amount<C>, predicate: P) -> Self
+    where
+        P: FnOnce(&CurrencyError<C>) -> bool,
+    {
+        match self.0 {
+            Err(error) if predicate(&error) => fallback.into(),
+            _ => self,
+        }
+    }
+
+    /// Returns the wrapped [`Amount`], or `default` if `self` is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// assert_eq!(AmountResult::from(eur!(5)).unwrap_or(eur!(1)), eur!(5));
+    /// assert_eq!(AmountResult::unknown().unwrap_or(eur!(1)), eur!(1));
+    /// assert_eq!((eur!(5) + usd!(5)).unwrap_or(eur!(1)), eur!(1));
+    /// ```
+    pub fn unwrap_or(self, default: Amount<C>) -> Amount<C> {
+        self.0.unwrap_or(default)
+    }
+
+    /// Returns the wrapped [`Amount`], or zero in `currency` if `self` is an
+    /// error. This is typically used on the result of the [`Sum`] of a
+    /// possibly empty collection of amounts, which is a
+    /// [`CurrencyError::Unknown`]. Note that all errors are replaced by
+    /// zero: use [`AmountResult::or_else_amount`] to only replace some of
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, AmountResult, Decimal, Currency::EUR};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// let expenses: Vec<Amount> = Vec::new();
+    /// assert_eq!(expenses.iter().sum::<AmountResult>().unwrap_or_zero(EUR), eur!(0));
+    /// assert_eq!(AmountResult::from(eur!(5)).unwrap_or_zero(EUR), eur!(5));
+    /// ```
+    pub fn unwrap_or_zero(self, currency: C) -> Amount<C> {
+        self.unwrap_or(Amount(Decimal::ZERO, currency))
+    }
+
+    /// Returns an iterator over the wrapped [`Amount`], which yields one
+    /// amount if `self` wraps an [`Amount`] and none otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// assert_eq!(AmountResult::from(eur!(1)).iter().next(), Some(&eur!(1)));
+    /// assert_eq!((eur!(1) + usd!(1)).iter().next(), None);
+    ///
+    /// let results = [eur!(1) + eur!(2), eur!(1) + usd!(2), eur!(4).into()];
+    /// assert_eq!(results.iter().flatten().sum::<AmountResult>(), eur!(7));
+    /// ```
+    pub fn iter(&self) -> core::result::Iter<'_, Amount<C>> {
+        self.0.iter()
+    }
+
+    /// Attaches `context` to the wrapped [`CurrencyError`], if any. The
+    /// context is kept as the error is coalesced through further
+    /// operations and is shown when the result is displayed, which helps
+    /// locating where an error originated in a long computation. If the
+    /// error already has a context, the original one is kept. Wrapped
+    /// [`Amount`]s are returned unchanged.
+    ///
+    /// The context is dropped when the result is converted into a
+    /// [`std::result::Result`] (e.g. with [`AmountResult::into_inner`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Decimal, AmountResult};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let lines = (eur!(10) + usd!(5)).context("summing invoice lines");
+    /// let total = (lines * Decimal::TWO + eur!(1)).context("adding shipping costs");
+    /// assert_eq!(total.error_context(), Some("summing invoice lines"));
+    /// assert!(total
+    ///     .to_string()
+    ///     .starts_with("summing invoice lines: mismatch currency 'EUR' and 'USD' in addition"));
+    /// assert_eq!(AmountResult::from(eur!(1)).context("unused").error_context(), None);
+    /// ```
+    pub fn context(self, context: &'static str) -> Self {
+        match self {
+            AmountResult(Err(error), origin) if origin.context.is_none() => {
+                let context = Some(context);
+                AmountResult(Err(error), Origin { context, ..origin })
+            }
+            _ => self,
+        }
+    }
+
+    /// Returns the context attached to the wrapped [`CurrencyError`] with
+    /// [`AmountResult::context`], if any.
+    pub fn error_context(&self) -> Option<&'static str> {
+        self.1.context
+    }
+
+    /// Returns the [`Operation`] that produced the wrapped
+    /// [`CurrencyError`], if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Decimal, Operation, Currency::{EUR, USD}};
+    /// use oxydized_money_macros::{eur, usd, dec};
+    ///
+    /// let result = eur!(1) - usd!(2) + eur!(3);
+    /// assert_eq!(result.operation(), Some(Operation::Sub));
+    /// assert!(result.to_string().starts_with("mismatch currency 'EUR' and 'USD' in subtraction"));
+    ///
+    /// let result: AmountResult = [eur!(1), usd!(2)].iter().sum();
+    /// assert_eq!(result.operation(), Some(Operation::Sum));
+    /// assert_eq!((eur!(1) / dec!(0)).operation(), Some(Operation::Div));
+    /// assert_eq!(AmountResult::mismatch(EUR, USD).operation(), None);
+    /// ```
+    pub fn operation(&self) -> Option<Operation> {
+        self.1.operation
+    }
+
+    /// Returns the amounts that produced the wrapped
+    /// [`CurrencyError::Mismatch`], if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    ///
+    /// let result = eur!(12) + usd!(3.5);
+    /// assert_eq!(result.mismatch_operands(), Some((eur!(12), usd!(3.5))));
+    /// assert_eq!(
+    ///     result.to_string(),
+    ///     "mismatch currency 'EUR' and 'USD' in addition of € 12.00 and $ 3.50"
+    /// );
+    /// ```
+    #[cfg(feature = "mismatch-operands")]
+    pub fn mismatch_operands(&self) -> Option<(Amount<C>, Amount<C>)> {
+        self.1.operands
+    }
+
+    /// Creates an [`AmountResult`] around `error`, produced by `operation`.
+    ///
+    /// With the `tracing` feature, this emits an event recording the
+    /// operation and the error: at the `debug` level for the
+    /// [`CurrencyError::Unknown`] of an empty sum, and at the `warn` level
+    /// otherwise.
+    pub(crate) fn failed_in(operation: Operation, error: CurrencyError<C>) -> Self {
+        #[cfg(feature = "tracing")]
+        match error {
+            CurrencyError::Unknown => tracing::debug!(%operation, %error, "currency error"),
+            _ => tracing::warn!(%operation, %error, "currency error"),
+        }
+        AmountResult::from(error).with_operation(operation)
+    }
+
+    /// Creates an [`AmountResult`] around a [`CurrencyError::Mismatch`]
+    /// produced by applying `operation` to `left` and `right`.
+    pub(crate) fn mismatch_in(operation: Operation, left: Amount<C>, right: Amount<C>) -> Self {
+        let error = CurrencyError::Mismatch(left.currency(), right.currency());
+        #[allow(unused_mut)]
+        let mut result = Self::failed_in(operation, error);
+        #[cfg(feature = "mismatch-operands")]
+        {
+            result.1.operands = Some((left, right));
+        }
+        result
+    }
+
+    /// Records that the wrapped [`CurrencyError`], if any, was produced by
+    /// `operation`.
+    pub(crate) fn with_operation(self, operation: Operation) -> Self {
+        match self {
+            AmountResult(Err(error), origin) => {
+                let operation = Some(operation);
+                AmountResult(
+                    Err(error),
+                    Origin {
+                        operation,
+                        ..origin
+                    },
+                )
+            }
+            _ => self,
+        }
     }
 
     /// Extracts the inner part of type [`std::result::Result<Amount, CurrencyError>`].
@@ -129,10 +757,36 @@ impl AmountResult {
     ///     Ok(intermediate * dec!(2))
     /// }
     /// ```
-    pub fn into_inner(self) -> Result<Amount> {
+    pub fn into_inner(self) -> Result<Amount<C>, C> {
         self.0
     }
 
+    /// Extracts the inner [`Amount`], converting the [`CurrencyError`] into
+    /// any error type `E` that can be built from it. This makes it possible
+    /// to return an [`AmountResult`] from a function returning a
+    /// [`std::result::Result`] with a custom error type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Decimal};
+    /// use oxydized_money_macros::{eur, usd};
+    /// use std::error::Error;
+    ///
+    /// fn total(a: Amount, b: Amount) -> Result<Amount, Box<dyn Error>> {
+    ///     (a + b).ok_or_propagate()
+    /// }
+    ///
+    /// assert_eq!(total(eur!(1), eur!(2)).unwrap(), eur!(3));
+    /// assert!(total(eur!(1), usd!(2)).is_err());
+    /// ```
+    pub fn ok_or_propagate<E>(self) -> core::result::Result<Amount<C>, E>
+    where
+        E: From<CurrencyError<C>>,
+    {
+        self.0.map_err(E::from)
+    }
+
     /// Returns `true` if and only if self is an error of type
     /// [`CurrencyError::Unknown`].
     ///
@@ -177,111 +831,250 @@ impl AmountResult {
     pub fn is_divide_by_zero(&self) -> bool {
         matches!(self.0, Err(CurrencyError::DivideByZero))
     }
+
+    /// Returns `true` if and only if self is an error of type
+    /// [`CurrencyError::MissingRate`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, Currency::{EUR, USD}};
+    ///
+    /// assert!(AmountResult::missing_rate(EUR, USD).is_missing_rate());
+    /// ```
+    pub fn is_missing_rate(&self) -> bool {
+        matches!(self.0, Err(CurrencyError::MissingRate(_, _)))
+    }
+
+    /// Returns `true` if and only if self is an error of type
+    /// [`CurrencyError::Overflow`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxydized_money::{Amount, Decimal, Currency::EUR};
+    /// use oxydized_money_macros::eur;
+    ///
+    /// assert!((Amount(Decimal::MAX, EUR) + eur!(1)).is_overflow());
+    /// ```
+    pub fn is_overflow(&self) -> bool {
+        matches!(self.0, Err(CurrencyError::Overflow))
+    }
+
+    /// Returns `true` if and only if self is an error of type
+    /// [`CurrencyError::InvalidPrecision`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxydized_money::{AmountResult, CurrencyError, Currency::EUR};
+    ///
+    /// assert!(AmountResult::from(CurrencyError::InvalidPrecision(EUR)).is_invalid_precision());
+    /// ```
+    pub fn is_invalid_precision(&self) -> bool {
+        matches!(self.0, Err(CurrencyError::InvalidPrecision(_)))
+    }
 }
 
-impl Display for AmountResult {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.0 {
-            Ok(amount) => amount.fmt(f),
-            Err(error) => error.fmt(f),
+impl<C: CurrencyLike> Display for AmountResult<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let error = match self.0 {
+            Ok(amount) => return amount.fmt(f),
+            Err(error) => error,
+        };
+        if let Some(context) = self.1.context {
+            write!(f, "{}: ", context)?;
         }
+        write!(f, "{}", error)?;
+        if let Some(operation) = self.1.operation {
+            write!(f, " in {}", operation)?;
+        }
+        #[cfg(feature = "mismatch-operands")]
+        if let Some((left, right)) = self.1.operands {
+            write!(f, " of {} and {}", left, right)?;
+        }
+        Ok(())
     }
 }
 
-impl Deref for AmountResult {
-    type Target = Result<Amount>;
+impl<C: CurrencyLike> PartialEq for AmountResult<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<C: CurrencyLike> Eq for AmountResult<C> {}
+
+impl<C: CurrencyLike> Hash for AmountResult<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<C: CurrencyLike> Deref for AmountResult<C> {
+    type Target = Result<Amount<C>, C>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl DerefMut for AmountResult {
+impl<C: CurrencyLike> DerefMut for AmountResult<C> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
 
-impl From<Amount> for AmountResult {
-    fn from(amount: Amount) -> Self {
-        AmountResult(Ok(amount))
+impl<C: CurrencyLike> From<Amount<C>> for AmountResult<C> {
+    fn from(amount: Amount<C>) -> Self {
+        AmountResult(Ok(amount), Origin::default())
     }
 }
 
-impl From<&Amount> for AmountResult {
-    fn from(amount: &Amount) -> Self {
-        AmountResult(Ok(*amount))
+impl<C: CurrencyLike> From<&Amount<C>> for AmountResult<C> {
+    fn from(amount: &Amount<C>) -> Self {
+        AmountResult(Ok(*amount), Origin::default())
     }
 }
 
-impl From<&mut Amount> for AmountResult {
-    fn from(amount: &mut Amount) -> Self {
-        AmountResult(Ok(*amount))
+impl<C: CurrencyLike> From<&mut Amount<C>> for AmountResult<C> {
+    fn from(amount: &mut Amount<C>) -> Self {
+        AmountResult(Ok(*amount), Origin::default())
     }
 }
 
-impl From<&AmountResult> for AmountResult {
-    fn from(amount: &AmountResult) -> Self {
+impl<C: CurrencyLike> From<&AmountResult<C>> for AmountResult<C> {
+    fn from(amount: &AmountResult<C>) -> Self {
         *amount
     }
 }
 
-impl From<&mut AmountResult> for AmountResult {
-    fn from(amount: &mut AmountResult) -> Self {
+impl<C: CurrencyLike> From<&mut AmountResult<C>> for AmountResult<C> {
+    fn from(amount: &mut AmountResult<C>) -> Self {
         *amount
     }
 }
 
-impl From<CurrencyError> for AmountResult {
-    fn from(amount: CurrencyError) -> Self {
-        AmountResult(Err(amount))
+impl<C: CurrencyLike> From<CurrencyError<C>> for AmountResult<C> {
+    fn from(amount: CurrencyError<C>) -> Self {
+        AmountResult(Err(amount), Origin::default())
+    }
+}
+
+impl<C: CurrencyLike> From<&CurrencyError<C>> for AmountResult<C> {
+    fn from(amount: &CurrencyError<C>) -> Self {
+        AmountResult(Err(*amount), Origin::default())
     }
 }
 
-impl From<&CurrencyError> for AmountResult {
-    fn from(amount: &CurrencyError) -> Self {
-        AmountResult(Err(*amount))
+impl<C: CurrencyLike> From<&mut CurrencyError<C>> for AmountResult<C> {
+    fn from(amount: &mut CurrencyError<C>) -> Self {
+        AmountResult(Err(*amount), Origin::default())
     }
 }
 
-impl From<&mut CurrencyError> for AmountResult {
-    fn from(amount: &mut CurrencyError) -> Self {
-        AmountResult(Err(*amount))
+impl<C: CurrencyLike> From<Result<Amount<C>, C>> for AmountResult<C> {
+    fn from(result: Result<Amount<C>, C>) -> Self {
+        AmountResult(result, Origin::default())
     }
 }
 
-impl Sum<Amount> for AmountResult {
-    fn sum<I: Iterator<Item = Amount>>(mut iter: I) -> Self {
+impl<C: CurrencyLike> From<AmountResult<C>> for Result<Amount<C>, C> {
+    fn from(result: AmountResult<C>) -> Self {
+        result.0
+    }
+}
+
+impl<C: CurrencyLike> IntoIterator for AmountResult<C> {
+    type Item = Amount<C>;
+    type IntoIter = core::result::IntoIter<Amount<C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, C: CurrencyLike> IntoIterator for &'a AmountResult<C> {
+    type Item = &'a Amount<C>;
+    type IntoIter = core::result::Iter<'a, Amount<C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<C: CurrencyLike> Sum<Amount<C>> for AmountResult<C> {
+    fn sum<I: Iterator<Item = Amount<C>>>(mut iter: I) -> Self {
         if let Some(amount) = iter.next() {
-            iter.fold(AmountResult::from(amount), |a, b| a + b)
+            let sum = iter.fold(AmountResult::from(amount), |a, b| a + b);
+            sum.with_operation(Operation::Sum)
         } else {
-            CurrencyError::Unknown.into()
+            Self::failed_in(Operation::Sum, CurrencyError::Unknown)
         }
     }
 }
 
-impl<'a> Sum<&'a Amount> for AmountResult {
-    fn sum<I: Iterator<Item = &'a Amount>>(iter: I) -> Self {
+impl<'a, C: CurrencyLike + 'a> Sum<&'a Amount<C>> for AmountResult<C> {
+    fn sum<I: Iterator<Item = &'a Amount<C>>>(iter: I) -> Self {
         iter.copied().sum()
     }
 }
 
-impl Sum<AmountResult> for AmountResult {
-    fn sum<I: Iterator<Item = AmountResult>>(mut iter: I) -> Self {
+impl<C: CurrencyLike> Sum<AmountResult<C>> for AmountResult<C> {
+    fn sum<I: Iterator<Item = AmountResult<C>>>(mut iter: I) -> Self {
         if let Some(amount) = iter.next() {
-            iter.fold(amount, |a, b| a + b)
+            iter.fold(amount, |a, b| match (a.0, b.0) {
+                (Ok(_), Ok(_)) => (a + b).with_operation(Operation::Sum),
+                _ => a + b,
+            })
         } else {
-            CurrencyError::Unknown.into()
+            Self::failed_in(Operation::Sum, CurrencyError::Unknown)
         }
     }
 }
 
-impl<'a> Sum<&'a AmountResult> for AmountResult {
-    fn sum<I: Iterator<Item = &'a AmountResult>>(iter: I) -> Self {
+impl<'a, C: CurrencyLike + 'a> Sum<&'a AmountResult<C>> for AmountResult<C> {
+    fn sum<I: Iterator<Item = &'a AmountResult<C>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<C: CurrencyLike> Sum<Amount<C>> for Result<Amount<C>, C> {
+    fn sum<I: Iterator<Item = Amount<C>>>(iter: I) -> Self {
+        iter.sum::<AmountResult<C>>().into()
+    }
+}
+
+impl<'a, C: CurrencyLike + 'a> Sum<&'a Amount<C>> for Result<Amount<C>, C> {
+    fn sum<I: Iterator<Item = &'a Amount<C>>>(iter: I) -> Self {
         iter.copied().sum()
     }
 }
 
+impl<C: CurrencyLike> FromIterator<Amount<C>> for AmountResult<C> {
+    fn from_iter<I: IntoIterator<Item = Amount<C>>>(iter: I) -> Self {
+        iter.into_iter().sum()
+    }
+}
+
+impl<'a, C: CurrencyLike + 'a> FromIterator<&'a Amount<C>> for AmountResult<C> {
+    fn from_iter<I: IntoIterator<Item = &'a Amount<C>>>(iter: I) -> Self {
+        iter.into_iter().sum()
+    }
+}
+
+impl<C: CurrencyLike> FromIterator<Amount<C>> for Result<Amount<C>, C> {
+    fn from_iter<I: IntoIterator<Item = Amount<C>>>(iter: I) -> Self {
+        iter.into_iter().sum()
+    }
+}
+
+impl<'a, C: CurrencyLike + 'a> FromIterator<&'a Amount<C>> for Result<Amount<C>, C> {
+    fn from_iter<I: IntoIterator<Item = &'a Amount<C>>>(iter: I) -> Self {
+        iter.into_iter().sum()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate as oxydized_money;
@@ -291,10 +1084,32 @@ mod test {
 
     macro_rules! W {
         ($expr:expr) => {
-            AmountResult::from($expr)
+            AmountResult::new($expr)
         };
     }
 
+    #[test]
+    #[cfg(feature = "with_serde")]
+    fn test_serde() {
+        let cases = [
+            (
+                W!(eur!(10.50)),
+                r#"{"amount":{"value":"10.50","currency":"EUR"}}"#,
+            ),
+            (
+                W!(eur!(1)) + usd!(2),
+                r#"{"error":{"type":"mismatch","left":"EUR","right":"USD"}}"#,
+            ),
+            (W!(Unknown), r#"{"error":{"type":"unknown"}}"#),
+        ];
+        for (result, json) in cases {
+            assert_eq!(serde_json::to_string(&result).unwrap(), json);
+            assert_eq!(serde_json::from_str::<AmountResult>(json).unwrap(), result);
+        }
+        assert!(serde_json::from_str::<AmountResult>(r#"{"value":"1","currency":"EUR"}"#).is_err());
+        assert!(serde_json::from_str::<AmountResult>(r#"{"oops":{"type":"unknown"}}"#).is_err());
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(format!("{}", W!(eur!(2))), "€ 2.00");
@@ -314,6 +1129,121 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_context() {
+        let error = W!(Mismatch(EUR, USD)).context("first");
+        assert_eq!(error.error_context(), Some("first"));
+        assert_eq!(error.context("second").error_context(), Some("first"));
+        assert_eq!(W!(eur!(1)).context("first").error_context(), None);
+        assert_eq!(W!(Unknown).error_context(), None);
+        assert_eq!(
+            format!("{}", W!(DivideByZero).context("splitting")),
+            "splitting: divide by zero"
+        );
+        assert_eq!(format!("{}", W!(eur!(1)).context("unused")), "€ 1.00");
+
+        let chained = eur!(1) + (error * dec!(2) / dec!(3)).abs() - eur!(1);
+        assert_eq!(chained.error_context(), Some("first"));
+        let chained = (-error).map_amount(|amount| amount).and_then_amount(|a| a);
+        assert_eq!(chained.error_context(), Some("first"));
+        let chained = W!(Unknown) + error;
+        assert_eq!(chained.error_context(), Some("first"));
+        assert_eq!((error - eur!(1)).error_context(), Some("first"));
+        assert_eq!((eur!(1) - error).error_context(), Some("first"));
+        assert_eq!(
+            error.zip_with(W!(eur!(1)), |a, _| a).error_context(),
+            Some("first")
+        );
+        assert_eq!(
+            W!(eur!(1)).zip_with(error, |a, _| a).error_context(),
+            Some("first")
+        );
+        assert_eq!(error.into_inner(), Err(Mismatch(EUR, USD)));
+    }
+
+    #[test]
+    fn test_operation() {
+        use oxydized_money::Operation;
+
+        assert_eq!((eur!(1) + usd!(1)).operation(), Some(Operation::Add));
+        assert_eq!((eur!(1) - usd!(1)).operation(), Some(Operation::Sub));
+        assert_eq!((W!(eur!(1)) + usd!(1)).operation(), Some(Operation::Add));
+        assert_eq!(
+            (W!(eur!(1)) - W!(usd!(1))).operation(),
+            Some(Operation::Sub)
+        );
+        assert_eq!((eur!(1) + eur!(1)).operation(), None);
+        assert_eq!((eur!(1) / dec!(0)).operation(), Some(Operation::Div));
+        assert_eq!((W!(eur!(1)) / dec!(0)).operation(), Some(Operation::Div));
+        assert_eq!(
+            (Amount(Decimal::MAX, EUR) + eur!(1)).operation(),
+            Some(Operation::Add)
+        );
+        assert_eq!(
+            Vec::<Amount>::new()
+                .into_iter()
+                .sum::<AmountResult>()
+                .operation(),
+            Some(Operation::Sum)
+        );
+        assert_eq!(W!(Mismatch(EUR, USD)).operation(), None);
+
+        let error = eur!(1) - usd!(1);
+        assert_eq!(
+            ((error + eur!(1)) * dec!(2)).operation(),
+            Some(Operation::Sub)
+        );
+        assert_eq!(
+            [W!(eur!(1)), error]
+                .into_iter()
+                .sum::<AmountResult>()
+                .operation(),
+            Some(Operation::Sub)
+        );
+        assert_eq!(
+            [W!(eur!(1)), W!(usd!(1))]
+                .into_iter()
+                .sum::<AmountResult>()
+                .operation(),
+            Some(Operation::Sum)
+        );
+        assert_eq!(
+            [eur!(1), usd!(1)]
+                .into_iter()
+                .sum::<AmountResult>()
+                .operation(),
+            Some(Operation::Sum)
+        );
+
+        assert_eq!(error, W!(Mismatch(EUR, USD)));
+        let error = W!(Mismatch(EUR, USD)).with_operation(Operation::Sub);
+        assert_eq!(
+            format!("{}", error),
+            "mismatch currency 'EUR' and 'USD' in subtraction"
+        );
+        assert_eq!(
+            format!("{}", error.context("paying")),
+            "paying: mismatch currency 'EUR' and 'USD' in subtraction"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "mismatch-operands")]
+    fn test_mismatch_operands() {
+        let error = eur!(1) - usd!(2);
+        assert_eq!(error.mismatch_operands(), Some((eur!(1), usd!(2))));
+        let error = eur!(1) + eur!(2) + usd!(3) + eur!(4);
+        assert_eq!(error.mismatch_operands(), Some((eur!(3), usd!(3))));
+        let error: AmountResult = [eur!(1), eur!(2), usd!(3)].iter().sum();
+        assert_eq!(error.mismatch_operands(), Some((eur!(3), usd!(3))));
+        assert_eq!(
+            format!("{}", error),
+            "mismatch currency 'EUR' and 'USD' in sum of € 3.00 and $ 3.00"
+        );
+        assert_eq!(W!(Mismatch(EUR, USD)).mismatch_operands(), None);
+        assert_eq!((eur!(1) + eur!(2)).mismatch_operands(), None);
+    }
+
     #[test]
     fn test_deref() {
         assert!(W!(Mismatch(EUR, USD)).is_err());
@@ -340,6 +1270,202 @@ mod test {
         assert_eq!(res.unwrap_err(), Unknown)
     }
 
+    #[test]
+    fn test_combinators() {
+        let amount = W!(eur!(10))
+            .map_amount(|amount| amount * dec!(3))
+            .and_then_amount(|amount| amount - eur!(6))
+            .inspect(|amount| assert_eq!(*amount, eur!(24)))
+            .or_else_amount(|_| eur!(0));
+        assert_eq!(amount, eur!(24));
+
+        let amount = W!(eur!(10))
+            .and_then_amount(|amount| amount + usd!(1))
+            .map_amount(|_| unreachable!())
+            .inspect(|_| unreachable!());
+        assert_eq!(amount, W!(Mismatch(EUR, USD)));
+        assert_eq!(amount.or_else_amount(|_| Unknown), W!(Unknown));
+        assert_eq!(W!(Unknown).and_then_amount(|_| eur!(1)), W!(Unknown));
+        assert_eq!(
+            W!(eur!(1)).and_then_amount(|_| DivideByZero),
+            W!(DivideByZero)
+        );
+    }
+
+    #[test]
+    fn test_expect_currency() {
+        assert_eq!(W!(eur!(1)).expect_currency(EUR), eur!(1));
+        assert_eq!(W!(eur!(1)).expect_currency(USD), W!(Mismatch(USD, EUR)));
+        assert_eq!(W!(Unknown).expect_currency(USD), W!(Unknown));
+        assert_eq!(W!(DivideByZero).expect_currency(USD), W!(DivideByZero));
+    }
+
+    #[test]
+    fn test_zip() {
+        assert_eq!(W!(eur!(1)).zip(W!(usd!(2))), Ok((eur!(1), usd!(2))));
+        assert_eq!(W!(eur!(1)).zip(W!(Unknown)), Err(Unknown));
+        assert_eq!(W!(Unknown).zip(W!(eur!(1))), Err(Unknown));
+        assert_eq!(W!(Unknown).zip(W!(Unknown)), Err(Unknown));
+        assert_eq!(W!(Unknown).zip(W!(DivideByZero)), Err(DivideByZero));
+        assert_eq!(W!(DivideByZero).zip(W!(Unknown)), Err(DivideByZero));
+        assert_eq!(
+            W!(Mismatch(EUR, USD)).zip(W!(DivideByZero)),
+            Err(Mismatch(EUR, USD))
+        );
+        assert_eq!(W!(eur!(1)).zip(W!(DivideByZero)), Err(DivideByZero));
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let ratio = |a: Amount, b: Amount| Amount(a.value() / b.value(), a.currency());
+        assert_eq!(W!(eur!(6)).zip_with(W!(eur!(3)), ratio), eur!(2));
+        assert_eq!(W!(eur!(6)).zip_with(W!(Unknown), ratio), W!(Unknown));
+        assert_eq!(
+            W!(Mismatch(EUR, USD)).zip_with(W!(eur!(3)), ratio),
+            W!(Mismatch(EUR, USD))
+        );
+        assert_eq!(
+            W!(eur!(6)).zip_with(W!(usd!(3)), |a, b| a - b),
+            W!(Mismatch(EUR, USD))
+        );
+    }
+
+    #[test]
+    fn test_recover() {
+        let recovered = W!(Mismatch(EUR, USD)).recover_mismatch(|c1, c2| {
+            assert_eq!((c1, c2), (EUR, USD));
+            eur!(1)
+        });
+        assert_eq!(recovered, eur!(1));
+        assert_eq!(
+            W!(Mismatch(EUR, USD)).recover_mismatch(|_, _| DivideByZero),
+            W!(DivideByZero)
+        );
+        assert_eq!(W!(eur!(2)).recover_mismatch(|_, _| eur!(1)), eur!(2));
+        assert_eq!(W!(Unknown).recover_mismatch(|_, _| eur!(1)), W!(Unknown));
+
+        assert_eq!(
+            W!(eur!(2)).map_error(|error| error.to_string()),
+            Ok(eur!(2))
+        );
+        assert_eq!(
+            W!(DivideByZero).map_error(|error| error.to_string()),
+            Err("divide by zero".to_string())
+        );
+    }
+
+    #[test]
+    fn test_or_amount() {
+        assert_eq!(W!(eur!(3)).or_amount(usd!(1)), eur!(3));
+        assert_eq!(W!(Unknown).or_amount(usd!(1)), usd!(1));
+        assert_eq!(W!(DivideByZero).or_amount(usd!(1)), W!(DivideByZero));
+        assert_eq!(
+            W!(Mismatch(EUR, USD)).or_amount(usd!(1)),
+            W!(Mismatch(EUR, USD))
+        );
+        let is_mismatch = |error: &_| matches!(error, Mismatch(_, _));
+        assert_eq!(
+            W!(Mismatch(EUR, USD)).or_amount_if(usd!(1), is_mismatch),
+            usd!(1)
+        );
+        assert_eq!(W!(Unknown).or_amount_if(usd!(1), is_mismatch), W!(Unknown));
+        assert_eq!(W!(eur!(3)).or_amount_if(usd!(1), is_mismatch), eur!(3));
+    }
+
+    #[test]
+    fn test_unwrap_or() {
+        assert_eq!(W!(eur!(3)).unwrap_or(usd!(1)), eur!(3));
+        assert_eq!(W!(Unknown).unwrap_or(usd!(1)), usd!(1));
+        assert_eq!(W!(DivideByZero).unwrap_or(usd!(1)), usd!(1));
+        assert_eq!(W!(eur!(3)).unwrap_or_zero(USD), eur!(3));
+        assert_eq!(W!(Unknown).unwrap_or_zero(EUR), eur!(0));
+        assert_eq!(W!(Mismatch(EUR, USD)).unwrap_or_zero(EUR), eur!(0));
+    }
+
+    #[test]
+    fn test_from_result() {
+        let parse = |value: &str| -> oxydized_money::Result<Amount> {
+            value
+                .parse()
+                .map(|value| Amount(value, EUR))
+                .or(Err(Unknown))
+        };
+        assert_eq!(W!(parse("1.5")) + eur!(1), eur!(2.5));
+        assert_eq!(W!(parse("oops")) + eur!(1), eur!(1));
+        assert_eq!(AmountResult::from(Err(DivideByZero)), W!(DivideByZero));
+        let result: oxydized_money::Result<Amount> = W!(eur!(1)).into();
+        assert_eq!(AmountResult::from(result), eur!(1));
+    }
+
+    #[test]
+    fn test_propagate() {
+        fn double(a: Amount, b: Amount) -> oxydized_money::Result<Amount> {
+            let sum: oxydized_money::Result<Amount> = (a + b).into();
+            Ok(sum? * dec!(2))
+        }
+        assert_eq!(double(eur!(1), eur!(2)), Ok(eur!(6)));
+        assert_eq!(double(eur!(1), usd!(2)), Err(Mismatch(EUR, USD)));
+
+        let ok: Result<Amount, Box<dyn std::error::Error>> = W!(eur!(1)).ok_or_propagate();
+        assert_eq!(ok.unwrap(), eur!(1));
+        let err: Result<Amount, Box<dyn std::error::Error>> = W!(Unknown).ok_or_propagate();
+        assert_eq!(err.unwrap_err().to_string(), "unknown currency");
+    }
+
+    #[test]
+    fn test_sum_into_result() {
+        type R = oxydized_money::Result<Amount>;
+        assert_eq!([eur!(1), eur!(2)].iter().sum::<R>(), Ok(eur!(3)));
+        assert_eq!([eur!(1), eur!(2)].into_iter().sum::<R>(), Ok(eur!(3)));
+        assert_eq!(
+            [eur!(1), usd!(2)].iter().sum::<R>(),
+            Err(Mismatch(EUR, USD))
+        );
+        assert_eq!(Vec::<Amount>::new().into_iter().sum::<R>(), Err(Unknown));
+        assert_eq!([eur!(1), eur!(2)].iter().collect::<R>(), Ok(eur!(3)));
+        assert_eq!(
+            [eur!(1), usd!(2)].into_iter().collect::<R>(),
+            Err(Mismatch(EUR, USD))
+        );
+        assert_eq!([eur!(1), eur!(2)].iter().collect::<AmountResult>(), eur!(3));
+        assert_eq!(
+            [eur!(1), usd!(2)].into_iter().collect::<AmountResult>(),
+            W!(Mismatch(EUR, USD))
+        );
+    }
+
+    #[test]
+    fn test_try_sum() {
+        let rows: [Result<Amount, &str>; 2] = [Ok(eur!(1)), Ok(eur!(2))];
+        assert_eq!(AmountResult::try_sum(rows), Ok(W!(eur!(3))));
+        let rows: [Result<Amount, &str>; 0] = [];
+        assert_eq!(AmountResult::try_sum(rows), Ok(W!(Unknown)));
+        let rows = [Ok(eur!(1)), Ok(usd!(2)), Err("oops")];
+        assert_eq!(AmountResult::try_sum(rows), Err("oops"));
+        let rows: [Result<Amount, &str>; 2] = [Ok(eur!(1)), Ok(usd!(2))];
+        assert_eq!(AmountResult::try_sum(rows), Ok(W!(Mismatch(EUR, USD))));
+        assert_eq!(
+            rows.into_iter().sum::<Result<AmountResult, _>>(),
+            Ok(W!(Mismatch(EUR, USD)))
+        );
+    }
+
+    #[test]
+    fn test_into_iter() {
+        assert_eq!(W!(eur!(1)).into_iter().collect::<Vec<_>>(), vec![eur!(1)]);
+        assert_eq!(W!(Unknown).into_iter().count(), 0);
+        assert_eq!(W!(Mismatch(EUR, USD)).iter().count(), 0);
+
+        let results = [W!(eur!(1)), W!(DivideByZero), W!(eur!(2)), W!(Unknown)];
+        assert_eq!(results.iter().flatten().sum::<AmountResult>(), eur!(3));
+        assert_eq!(results.into_iter().flatten().sum::<AmountResult>(), eur!(3));
+        let mut total = eur!(0);
+        for amount in &W!(eur!(5)) {
+            total = (total + *amount).unwrap();
+        }
+        assert_eq!(total, eur!(5));
+    }
+
     #[test]
     fn test_sum() {
         let sum = [eur!(1), eur!(2)].iter().sum::<AmountResult>();
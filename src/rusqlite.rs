@@ -0,0 +1,209 @@
+use crate::{Amount, Currency, CurrencyError::Overflow, Decimal};
+use ::rusqlite::{
+    types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef},
+    Row, RowIndex, ToSql,
+};
+
+/// With the `rusqlite` feature, amounts are bound to and read from a single
+/// `TEXT` column in their canonical form, e.g. `"10.50 EUR"`, which keeps
+/// their value exact. Amounts can also be stored as an `INTEGER` number of
+/// minor units along with their currency, see
+/// [`MinorUnitsValue`](crate::MinorUnitsValue).
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Decimal};
+/// use oxydized_money_macros::eur;
+/// use rusqlite::Connection;
+///
+/// let connection = Connection::open_in_memory().unwrap();
+/// connection.execute("CREATE TABLE invoices (total TEXT)", ()).unwrap();
+/// connection.execute("INSERT INTO invoices VALUES (?1)", [eur!(10.50)]).unwrap();
+/// let total: Amount = connection
+///     .query_row("SELECT total FROM invoices", (), |row| row.get(0))
+///     .unwrap();
+/// assert_eq!(total, eur!(10.50));
+/// ```
+impl ToSql for Amount {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.canonical()))
+    }
+}
+
+impl FromSql for Amount {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let text = value.as_str()?;
+        Amount::parse_canonical(text)
+            .ok_or_else(|| FromSqlError::Other(format!("invalid amount '{}'", text).into()))
+    }
+}
+
+/// `MinorUnitsValue` binds an [`Amount`] to an `INTEGER` column as a number
+/// of minor units of its currency, e.g. `10.50 EUR` is bound as `1050`, its
+/// currency being stored in a separate `TEXT` column. Binding an amount
+/// with more decimal places than its currency allows fails rather than
+/// rounding it silently.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Decimal, MinorUnitsValue};
+/// use oxydized_money_macros::eur;
+/// use rusqlite::{params, Connection};
+///
+/// let connection = Connection::open_in_memory().unwrap();
+/// connection
+///     .execute("CREATE TABLE invoices (total INTEGER, currency TEXT)", ())
+///     .unwrap();
+/// let total = eur!(10.50);
+/// connection
+///     .execute(
+///         "INSERT INTO invoices VALUES (?1, ?2)",
+///         params![MinorUnitsValue(total), total.currency().code()],
+///     )
+///     .unwrap();
+/// let total = connection
+///     .query_row("SELECT total, currency FROM invoices", (), |row| {
+///         MinorUnitsValue::get(row, "total", "currency")
+///     })
+///     .unwrap();
+/// assert_eq!(total, eur!(10.50));
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct MinorUnitsValue(pub Amount);
+
+impl MinorUnitsValue {
+    /// Returns the amount whose number of minor units and currency code are
+    /// held by the given columns of a row.
+    pub fn get<I: RowIndex>(row: &Row<'_>, value: I, currency: I) -> rusqlite::Result<Amount> {
+        let CurrencyCode(currency) = row.get(currency)?;
+        let minor_units: i64 = row.get(value)?;
+        Ok(Amount(
+            Decimal::new(minor_units, Amount::minor_scale(currency)),
+            currency,
+        ))
+    }
+}
+
+impl ToSql for MinorUnitsValue {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        let amount = self.0;
+        amount
+            .scaled_units(Amount::minor_scale(amount.currency()))
+            .and_then(|minor_units| i64::try_from(minor_units).map_err(|_| Overflow))
+            .map(ToSqlOutput::from)
+            .map_err(|error| rusqlite::Error::ToSqlConversionFailure(error.into()))
+    }
+}
+
+struct CurrencyCode(Currency);
+
+impl FromSql for CurrencyCode {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let code = value.as_str()?;
+        Currency::from_code(code.trim())
+            .map(CurrencyCode)
+            .ok_or_else(|| FromSqlError::Other(format!("unknown currency '{}'", code).into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MinorUnitsValue;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, CurrencyError, Decimal};
+    use oxydized_money_macros::{bhd, eur, jpy};
+    use rusqlite::{params, Connection, ToSql};
+
+    fn connection() -> Connection {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute(
+                "CREATE TABLE amounts (amount TEXT, value INTEGER, currency TEXT)",
+                (),
+            )
+            .unwrap();
+        connection
+    }
+
+    #[test]
+    fn test_canonical() {
+        let connection = connection();
+        for amount in [eur!(10.50), jpy!(-1200), bhd!(0.125)] {
+            connection
+                .execute("INSERT INTO amounts (amount) VALUES (?1)", [amount])
+                .unwrap();
+        }
+        let mut statement = connection.prepare("SELECT amount FROM amounts").unwrap();
+        let amounts: Vec<Amount> = statement
+            .query_map((), |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(amounts, [eur!(10.50), jpy!(-1200), bhd!(0.125)]);
+        let text: String = connection
+            .query_row("SELECT amount FROM amounts", (), |row| row.get(0))
+            .unwrap();
+        assert_eq!(text, "10.50 EUR");
+    }
+
+    #[test]
+    fn test_canonical_errors() {
+        let connection = connection();
+        let result = connection.query_row("SELECT '10.50'", (), |row| row.get::<_, Amount>(0));
+        assert!(result.is_err());
+        let result = connection.query_row("SELECT 1050", (), |row| row.get::<_, Amount>(0));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_minor_units() {
+        let connection = connection();
+        for amount in [eur!(10.5), jpy!(-1200), bhd!(0.125)] {
+            connection
+                .execute(
+                    "INSERT INTO amounts (value, currency) VALUES (?1, ?2)",
+                    params![MinorUnitsValue(amount), amount.currency().code()],
+                )
+                .unwrap();
+        }
+        let mut statement = connection
+            .prepare("SELECT value, currency FROM amounts")
+            .unwrap();
+        let amounts: Vec<Amount> = statement
+            .query_map((), |row| MinorUnitsValue::get(row, 0, 1))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(amounts, [eur!(10.5), jpy!(-1200), bhd!(0.125)]);
+        let values: Vec<i64> = connection
+            .prepare("SELECT value FROM amounts")
+            .unwrap()
+            .query_map((), |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(values, [1050, -1200, 125]);
+    }
+
+    #[test]
+    fn test_minor_units_errors() {
+        let error = |amount| match MinorUnitsValue(amount).to_sql() {
+            Err(rusqlite::Error::ToSqlConversionFailure(error)) => error
+                .downcast::<CurrencyError>()
+                .map(|error| *error)
+                .unwrap(),
+            _ => panic!("{} should not be converted to minor units", amount),
+        };
+        assert_eq!(error(eur!(1.001)), CurrencyError::InvalidPrecision(EUR));
+        assert_eq!(error(Amount(Decimal::MAX, EUR)), CurrencyError::Overflow);
+        assert_eq!(error(eur!(92233720368547758.08)), CurrencyError::Overflow);
+
+        let connection = connection();
+        let result = connection.query_row("SELECT 1050, 'XYZ'", (), |row| {
+            MinorUnitsValue::get(row, 0, 1)
+        });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{Amount, Currency, CurrencyError};
+use ::rusty_money::{iso, Money};
+
+/// With the `rusty-money` feature, amounts are converted to
+/// [`rusty_money::Money`] in the matching ISO currency, keeping their value
+/// exact. Fails with [`CurrencyError::Unknown`] if `rusty_money` does not
+/// know the currency.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Decimal};
+/// use oxydized_money_macros::eur;
+/// use rusty_money::{iso, Money};
+///
+/// let money = Money::try_from(eur!(10.50)).unwrap();
+/// assert_eq!(money, Money::from_minor(1050, iso::EUR));
+/// assert_eq!(Amount::try_from(money), Ok(eur!(10.50)));
+/// ```
+impl TryFrom<Amount> for Money<'static, iso::Currency> {
+    type Error = CurrencyError;
+
+    fn try_from(amount: Amount) -> Result<Self, CurrencyError> {
+        let currency = iso::find(amount.currency().code()).ok_or(CurrencyError::Unknown)?;
+        Ok(Money::from_decimal(amount.value(), currency))
+    }
+}
+
+/// Converts a [`rusty_money::Money`] in an ISO currency to an amount. Fails
+/// with [`CurrencyError::Unknown`] if the currency is not a known ISO 4217
+/// currency.
+impl<'a> TryFrom<Money<'a, iso::Currency>> for Amount {
+    type Error = CurrencyError;
+
+    fn try_from(money: Money<'a, iso::Currency>) -> Result<Self, CurrencyError> {
+        Amount::try_from(&money)
+    }
+}
+
+impl<'a> TryFrom<&Money<'a, iso::Currency>> for Amount {
+    type Error = CurrencyError;
+
+    fn try_from(money: &Money<'a, iso::Currency>) -> Result<Self, CurrencyError> {
+        let currency =
+            Currency::from_code(money.currency().iso_alpha_code).ok_or(CurrencyError::Unknown)?;
+        Ok(Amount(*money.amount(), currency))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy, usd};
+    use rusty_money::{iso, Money};
+
+    #[test]
+    fn test_to_money() {
+        let money = Money::try_from(eur!(10.50)).unwrap();
+        assert_eq!(money.amount(), &dec!(10.50));
+        assert_eq!(money.currency(), iso::EUR);
+        assert_eq!(
+            Money::try_from(jpy!(-1200)).unwrap(),
+            Money::from_major(-1200, iso::JPY)
+        );
+    }
+
+    #[test]
+    fn test_from_money() {
+        assert_eq!(
+            Amount::try_from(Money::from_minor(1050, iso::USD)),
+            Ok(usd!(10.50))
+        );
+        let money = Money::from_decimal(dec!(-3.5), iso::EUR);
+        assert_eq!(Amount::try_from(&money), Ok(eur!(-3.5)));
+        for amount in [eur!(10.50), jpy!(7), usd!(0.001)] {
+            assert_eq!(
+                Amount::try_from(Money::try_from(amount).unwrap()),
+                Ok(amount)
+            );
+        }
+    }
+}
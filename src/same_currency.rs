@@ -0,0 +1,158 @@
+use crate::{Amount, Currency, CurrencyError::Mismatch, CurrencyLike, Result};
+use alloc::vec::Vec;
+
+/// `SameCurrency` is implemented by structures holding several amounts that
+/// must all be in the same currency, e.g. the subtotal, the taxes and the
+/// total of an invoice. It is usually derived with `#[derive(SameCurrency)]`
+/// from `oxydized-money-macros`, which takes all the fields whose type is
+/// named `Amount` (e.g. `Amount` or `Amount<C>`) into account, and fails to
+/// compile for a structure without any such field.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Currency::*, CurrencyError, Decimal, SameCurrency};
+/// use oxydized_money_macros::{eur, usd, SameCurrency};
+///
+/// #[derive(Debug, SameCurrency)]
+/// struct LineItem {
+///     description: String,
+///     unit_price: Amount,
+///     total: Amount,
+/// }
+///
+/// let item = LineItem {
+///     description: "coffee".to_owned(),
+///     unit_price: eur!(2.50),
+///     total: eur!(7.50),
+/// };
+/// assert_eq!(item.currency(), Ok(EUR));
+///
+/// let item = LineItem { total: usd!(7.50), ..item }.validated();
+/// assert_eq!(item.unwrap_err(), CurrencyError::Mismatch(EUR, USD));
+/// ```
+pub trait SameCurrency<C: CurrencyLike = Currency> {
+    /// Returns the amounts held by `self`, in declaration order.
+    fn amounts(&self) -> Vec<Amount<C>>;
+
+    /// Returns the currency shared by all the amounts held by `self`, or a
+    /// [`Mismatch`](crate::CurrencyError::Mismatch) between the currency of
+    /// the first amount and the first other currency.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`amounts`](SameCurrency::amounts) is empty, which never
+    /// happens with the derived implementation.
+    fn currency(&self) -> Result<C, C> {
+        let amounts = self.amounts();
+        let currency = amounts
+            .first()
+            .expect("at least one amount is required")
+            .currency();
+        match amounts.iter().find(|amount| amount.currency() != currency) {
+            Some(other) => Err(Mismatch(currency, other.currency())),
+            None => Ok(currency),
+        }
+    }
+
+    /// Checks that all the amounts held by `self` are in the same currency.
+    fn validate(&self) -> Result<(), C> {
+        self.currency().map(|_| ())
+    }
+
+    /// Returns `self` if all the amounts it holds are in the same currency,
+    /// to be chained after constructing the structure.
+    fn validated(self) -> Result<Self, C>
+    where
+        Self: Sized,
+    {
+        self.validate().map(|_| self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SameCurrency;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, CurrencyLike, Decimal};
+    use oxydized_money_macros::{eur, jpy, usd, SameCurrency};
+
+    #[derive(Debug, PartialEq, SameCurrency)]
+    struct Invoice {
+        number: u32,
+        subtotal: Amount,
+        taxes: oxydized_money::Amount,
+        total: Amount,
+    }
+
+    #[derive(Debug, SameCurrency)]
+    struct Range(Amount, Amount);
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    enum Token {
+        Gold,
+        Silver,
+    }
+
+    impl CurrencyLike for Token {
+        fn code(&self) -> &'static str {
+            match self {
+                Token::Gold => "GLD",
+                Token::Silver => "SLV",
+            }
+        }
+    }
+
+    #[derive(Debug, SameCurrency)]
+    struct Reward<T> {
+        base: Amount<Token>,
+        bonus: Amount<Token>,
+        tag: T,
+    }
+
+    #[test]
+    fn test_named_fields() {
+        let invoice = Invoice {
+            number: 1,
+            subtotal: eur!(10),
+            taxes: eur!(2.1),
+            total: eur!(12.1),
+        };
+        assert_eq!(invoice.amounts(), [eur!(10), eur!(2.1), eur!(12.1)]);
+        assert_eq!(invoice.currency(), Ok(EUR));
+        assert_eq!(invoice.validate(), Ok(()));
+
+        let invoice = Invoice {
+            taxes: usd!(2.1),
+            total: jpy!(12),
+            ..invoice
+        };
+        assert_eq!(invoice.currency(), Err(Mismatch(EUR, USD)));
+        assert_eq!(invoice.validated(), Err(Mismatch(EUR, USD)));
+    }
+
+    #[test]
+    fn test_tuple_fields() {
+        assert_eq!(Range(usd!(1), usd!(5)).currency(), Ok(USD));
+        assert_eq!(Range(usd!(1), eur!(5)).validate(), Err(Mismatch(USD, EUR)));
+    }
+
+    #[test]
+    fn test_generics() {
+        let reward = Reward {
+            base: Amount(Decimal::ONE, Token::Gold),
+            bonus: Amount(Decimal::TWO, Token::Gold),
+            tag: "weekly",
+        };
+        assert_eq!(reward.currency(), Ok(Token::Gold));
+        assert_eq!(reward.tag, "weekly");
+        let reward = Reward {
+            bonus: Amount(Decimal::TWO, Token::Silver),
+            ..reward
+        };
+        assert_eq!(
+            reward.validated().unwrap_err(),
+            Mismatch(Token::Gold, Token::Silver)
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::Amount;
+use ::sea_orm::{
+    ActiveValue, ColIdx, DbErr, IntoActiveValue, QueryResult, TryGetError, TryGetable,
+};
+
+/// With the `sea-orm` feature, amounts can be used as fields of SeaORM
+/// entity models. They are stored in a single string column in their
+/// canonical form, e.g. `"10.50 EUR"`, which keeps their value exact on
+/// every database backend:
+///
+/// ```ignore
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "invoices")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub total: Amount,
+///     pub discount: Option<Amount>,
+/// }
+/// ```
+///
+/// Their conversions to and from SeaQuery values come with the `sea-query`
+/// feature, which the `sea-orm` feature enables.
+impl TryGetable for Amount {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let text = String::try_get_by(res, index)?;
+        Amount::parse_canonical(&text)
+            .ok_or_else(|| TryGetError::DbErr(DbErr::Type(format!("invalid amount '{}'", text))))
+    }
+}
+
+impl IntoActiveValue<Amount> for Amount {
+    fn into_active_value(self) -> ActiveValue<Amount> {
+        ActiveValue::Set(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::eur;
+    use sea_orm::{ActiveValue, IntoActiveValue};
+
+    #[test]
+    fn test_active_value() {
+        assert_eq!(
+            eur!(10.50).into_active_value(),
+            ActiveValue::Set(eur!(10.50))
+        );
+        assert_eq!(
+            Some(eur!(10.50)).into_active_value(),
+            ActiveValue::Set(Some(eur!(10.50)))
+        );
+        assert_eq!(None::<Amount>.into_active_value(), ActiveValue::NotSet);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{Amount, Currency, Decimal};
+use ::sea_query::{
+    Alias, ArrayType, ColumnType, DynIden, IntoIden, Nullable, SimpleExpr, StringLen, Value,
+    ValueType, ValueTypeErr,
+};
+
+/// With the `sea-query` feature, amounts are bound to a single string
+/// column in their canonical form, e.g. `"10.50 EUR"`, which keeps their
+/// value exact on every database backend. Amounts stored in two columns are
+/// bound with [`AmountColumns`](crate::AmountColumns).
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, Decimal};
+/// use oxydized_money_macros::eur;
+/// use sea_query::Value;
+///
+/// let value = Value::from(eur!(10.50));
+/// assert_eq!(value, Value::from("10.50 EUR"));
+/// assert_eq!(Amount::try_from(value).unwrap(), eur!(10.50));
+/// ```
+impl From<Amount> for Value {
+    fn from(amount: Amount) -> Self {
+        Value::String(Some(Box::new(amount.canonical())))
+    }
+}
+
+impl TryFrom<Value> for Amount {
+    type Error = ValueTypeErr;
+
+    fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+        match value {
+            Value::String(Some(text)) => Amount::parse_canonical(&text).ok_or(ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+}
+
+impl ValueType for Amount {
+    fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+        <Amount as TryFrom<Value>>::try_from(value)
+    }
+
+    fn type_name() -> String {
+        "Amount".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::String(StringLen::None)
+    }
+}
+
+impl Nullable for Amount {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+/// `AmountColumns` names the two columns in which amounts are stored: a
+/// decimal column holding their value and a string column holding the ISO
+/// 4217 code of their currency. By convention, these columns are named
+/// `value` and `currency`, which is what [`AmountColumns::default`] returns,
+/// but any other names can be given to [`AmountColumns::new`], e.g. to store
+/// several amounts in the same table.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, AmountColumns, Decimal};
+/// use oxydized_money_macros::eur;
+/// use sea_query::{Alias, Query, Value};
+///
+/// let total = AmountColumns::new(Alias::new("total_value"), Alias::new("total_currency"));
+///
+/// let mut insert = Query::insert();
+/// insert
+///     .into_table(Alias::new("invoices"))
+///     .columns(total.columns())
+///     .values_panic(total.values(eur!(10.50)));
+///
+/// let mut update = Query::update();
+/// update
+///     .table(Alias::new("invoices"))
+///     .values(total.assignments(eur!(12)));
+///
+/// let amount = total.amount(Value::from(Decimal::new(1050, 2)), Value::from("EUR"));
+/// assert_eq!(amount.unwrap(), eur!(10.50));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AmountColumns {
+    value: DynIden,
+    currency: DynIden,
+}
+
+impl AmountColumns {
+    /// Creates the columns of amounts stored in a `value` and a `currency`
+    /// column with the given names.
+    pub fn new(value: impl IntoIden, currency: impl IntoIden) -> Self {
+        Self {
+            value: value.into_iden(),
+            currency: currency.into_iden(),
+        }
+    }
+
+    /// Returns the column holding the values of amounts.
+    pub fn value(&self) -> DynIden {
+        self.value.clone()
+    }
+
+    /// Returns the column holding the currency codes of amounts.
+    pub fn currency(&self) -> DynIden {
+        self.currency.clone()
+    }
+
+    /// Returns the value column followed by the currency column, e.g. to
+    /// select amounts or to insert them along with [`AmountColumns::values`].
+    pub fn columns(&self) -> [DynIden; 2] {
+        [self.value(), self.currency()]
+    }
+
+    /// Returns the value and the currency code of an amount, in the order of
+    /// [`AmountColumns::columns`].
+    pub fn values(&self, amount: Amount) -> [SimpleExpr; 2] {
+        [amount.value().into(), amount.currency().code().into()]
+    }
+
+    /// Returns the assignments of the value and the currency code of an
+    /// amount to their columns, e.g. to update amounts.
+    pub fn assignments(&self, amount: Amount) -> [(DynIden, SimpleExpr); 2] {
+        let [value, currency] = self.values(amount);
+        [(self.value(), value), (self.currency(), currency)]
+    }
+
+    /// Returns the amount read from the value and the currency columns.
+    pub fn amount(&self, value: Value, currency: Value) -> Result<Amount, ValueTypeErr> {
+        let value = <Decimal as ValueType>::try_from(value)?;
+        let code = <String as ValueType>::try_from(currency)?;
+        let currency = Currency::from_code(code.trim()).ok_or(ValueTypeErr)?;
+        Ok(Amount(value, currency))
+    }
+}
+
+impl Default for AmountColumns {
+    fn default() -> Self {
+        Self::new(Alias::new("value"), Alias::new("currency"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AmountColumns;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy};
+    use sea_query::{Alias, ColumnType, IntoIden, Nullable, SimpleExpr, StringLen, Value};
+
+    #[test]
+    fn test_value() {
+        for amount in [eur!(10.50), jpy!(-1200)] {
+            assert_eq!(Amount::try_from(Value::from(amount)).unwrap(), amount);
+        }
+        assert_eq!(Value::from(eur!(10.50)), Value::from("10.50 EUR"));
+        assert!(Amount::try_from(Value::from("10.50")).is_err());
+        assert!(Amount::try_from(Value::Int(Some(10))).is_err());
+        assert!(Amount::try_from(Amount::null()).is_err());
+        assert_eq!(
+            <Amount as sea_query::ValueType>::column_type(),
+            ColumnType::String(StringLen::None)
+        );
+    }
+
+    #[test]
+    fn test_columns() {
+        let columns = AmountColumns::default();
+        assert_eq!(columns.value().to_string(), "value");
+        assert_eq!(columns.currency().to_string(), "currency");
+
+        let columns = AmountColumns::new(Alias::new("total_value"), Alias::new("total_currency"));
+        let [value, currency] = columns.columns();
+        assert_eq!(value.to_string(), "total_value");
+        assert_eq!(currency.to_string(), "total_currency");
+        assert_eq!(
+            columns.values(eur!(10.50)),
+            [
+                SimpleExpr::Value(Value::from(dec!(10.50))),
+                SimpleExpr::Value(Value::from("EUR")),
+            ]
+        );
+        let [(column, value), _] = columns.assignments(jpy!(12));
+        assert_eq!(column, Alias::new("total_value").into_iden());
+        assert_eq!(value, SimpleExpr::Value(Value::from(dec!(12))));
+    }
+
+    #[test]
+    fn test_amount() {
+        let columns = AmountColumns::default();
+        assert_eq!(
+            columns
+                .amount(Value::from(dec!(10.50)), Value::from("EUR "))
+                .unwrap(),
+            eur!(10.50)
+        );
+        assert!(columns
+            .amount(Value::from(dec!(1)), Value::from("XYZ"))
+            .is_err());
+        assert!(columns
+            .amount(Value::from("1"), Value::from("EUR"))
+            .is_err());
+    }
+}
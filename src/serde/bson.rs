@@ -0,0 +1,99 @@
+//! Serializes an [`Amount`] as a structure with a BSON `Decimal128` value
+//! and a currency code, e.g. `{"value": NumberDecimal("10.50"), "currency":
+//! "EUR"}`.
+//!
+//! The `bson` serializer used by the `mongodb` crate stores the default
+//! representation of an amount with its value as a string, which MongoDB
+//! can neither compare nor aggregate numerically. This adapter stores it as
+//! a `Decimal128` instead, without going through a lossy `f64`. It is only
+//! meant to be used with the `bson` serializer and deserializer.
+//!
+//! # Examples
+//!
+//! ```
+//! use oxydized_money::{Amount, Decimal};
+//! use oxydized_money_macros::eur;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Invoice {
+//!     #[serde(with = "oxydized_money::serde::bson")]
+//!     total: Amount,
+//! }
+//!
+//! let invoice = Invoice { total: eur!(10.50) };
+//! let document = bson::to_document(&invoice).unwrap();
+//! let total = document.get_document("total").unwrap();
+//! assert!(total.get_decimal128("value").is_ok());
+//! assert_eq!(bson::from_document::<Invoice>(document).unwrap(), invoice);
+//! ```
+
+use crate::{
+    mongodb::{from_decimal128, to_decimal128},
+    Amount, Currency,
+};
+use ::bson::Decimal128;
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct BsonRepr {
+    value: Decimal128,
+    currency: Currency,
+}
+
+/// Serializes `amount` with a `Decimal128` value.
+pub fn serialize<S: Serializer>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error> {
+    let value = to_decimal128(amount.value());
+    let currency = amount.currency();
+    BsonRepr { value, currency }.serialize(serializer)
+}
+
+/// Deserializes an amount with a `Decimal128` value.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+    let BsonRepr { value, currency } = BsonRepr::deserialize(deserializer)?;
+    let value = from_decimal128(&value).ok_or_else(|| de::Error::custom("invalid amount value"))?;
+    Ok(Amount(value, currency))
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use crate::mongodb::to_decimal128;
+    use bson::doc;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{dec, eur, jpy};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Payment {
+        #[serde(with = "oxydized_money::serde::bson")]
+        amount: Amount,
+    }
+
+    #[test]
+    fn test_serialize() {
+        let document = bson::to_document(&Payment {
+            amount: eur!(10.50),
+        })
+        .unwrap();
+        let expected = doc! {
+            "amount": { "value": to_decimal128(dec!(10.50)), "currency": "EUR" }
+        };
+        assert_eq!(document, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for amount in [eur!(10.50), jpy!(-1200), eur!(0.001)] {
+            let document = bson::to_document(&Payment { amount }).unwrap();
+            let payment: Payment = bson::from_document(document).unwrap();
+            assert_eq!(payment.amount, amount);
+        }
+    }
+
+    #[test]
+    fn test_deserialize_string_value() {
+        let document = doc! { "amount": { "value": "10.50", "currency": "EUR" } };
+        assert!(bson::from_document::<Payment>(document).is_err());
+    }
+}
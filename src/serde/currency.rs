@@ -0,0 +1,137 @@
+//! Serializes a [`Currency`] as its alphabetic ISO 4217 code, e.g.
+//! `"EUR"`, and deserializes it from either its alphabetic code or its
+//! numeric code, given as a number (`978`) or as a string (`"978"`), as
+//! used by some banking feeds. The [`numeric`] submodule serializes
+//! currencies as their numeric code instead.
+//!
+//! The other adapters of this module also accept numeric codes when
+//! deserializing the currency of an amount.
+//!
+//! # Examples
+//!
+//! ```
+//! use oxydized_money::Currency;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Account {
+//!     #[serde(with = "oxydized_money::serde::currency")]
+//!     currency: Currency,
+//!     #[serde(with = "oxydized_money::serde::currency::numeric")]
+//!     settlement: Currency,
+//! }
+//!
+//! let json = r#"{"currency":978,"settlement":"USD"}"#;
+//! let account: Account = serde_json::from_str(json).unwrap();
+//! assert_eq!(account, Account { currency: Currency::EUR, settlement: Currency::USD });
+//! assert_eq!(
+//!     serde_json::to_string(&account).unwrap(),
+//!     r#"{"currency":"EUR","settlement":840}"#
+//! );
+//! ```
+
+use crate::{currency::parse_code, Currency};
+use ::serde::{de, Deserializer, Serializer};
+use std::fmt;
+
+/// Serializes `currency` as its alphabetic code, e.g. `"EUR"`.
+pub fn serialize<S: Serializer>(currency: &Currency, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(currency.code())
+}
+
+/// Deserializes a currency from its alphabetic or numeric code.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Currency, D::Error> {
+    deserializer.deserialize_any(CurrencyVisitor)
+}
+
+/// Serializes a [`Currency`] as its numeric ISO 4217 code, e.g. `978`,
+/// and deserializes it from either its alphabetic or numeric code.
+pub mod numeric {
+    use crate::Currency;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serializes `currency` as its numeric code, e.g. `978`.
+    pub fn serialize<S: Serializer>(currency: &Currency, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(currency.numeric())
+    }
+
+    /// Deserializes a currency from its alphabetic or numeric code.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Currency, D::Error> {
+        super::deserialize(deserializer)
+    }
+}
+
+struct CurrencyVisitor;
+
+impl<'de> de::Visitor<'de> for CurrencyVisitor {
+    type Value = Currency;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an alphabetic or numeric ISO 4217 currency code")
+    }
+
+    fn visit_str<E: de::Error>(self, code: &str) -> Result<Currency, E> {
+        parse_code(code).ok_or_else(|| E::custom(format!("unknown currency '{}'", code)))
+    }
+
+    fn visit_u64<E: de::Error>(self, numeric: u64) -> Result<Currency, E> {
+        u16::try_from(numeric)
+            .ok()
+            .and_then(Currency::from_numeric)
+            .ok_or_else(|| E::custom(format!("unknown currency '{}'", numeric)))
+    }
+
+    fn visit_i64<E: de::Error>(self, numeric: i64) -> Result<Currency, E> {
+        match u64::try_from(numeric) {
+            Ok(numeric) => self.visit_u64(numeric),
+            Err(_) => Err(E::custom(format!("unknown currency '{}'", numeric))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::Currency::{self, *};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Account {
+        #[serde(with = "oxydized_money::serde::currency")]
+        alpha: Currency,
+        #[serde(with = "oxydized_money::serde::currency::numeric")]
+        numeric: Currency,
+    }
+
+    fn from_json(alpha: &str, numeric: &str) -> serde_json::Result<Account> {
+        let json = format!(r#"{{"alpha":{},"numeric":{}}}"#, alpha, numeric);
+        serde_json::from_str(&json)
+    }
+
+    #[test]
+    fn test_serialize() {
+        let account = Account {
+            alpha: JPY,
+            numeric: BHD,
+        };
+        let json = serde_json::to_string(&account).unwrap();
+        assert_eq!(json, r#"{"alpha":"JPY","numeric":48}"#);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let expected = Account {
+            alpha: EUR,
+            numeric: USD,
+        };
+        assert_eq!(from_json(r#""EUR""#, r#""USD""#).unwrap(), expected);
+        assert_eq!(from_json("978", "840").unwrap(), expected);
+        assert_eq!(from_json(r#""978""#, r#""840""#).unwrap(), expected);
+        assert_eq!(from_json(r#""EUR""#, "840").unwrap(), expected);
+        assert!(from_json("1", "840").is_err());
+        assert!(from_json("-978", "840").is_err());
+        assert!(from_json("100000", "840").is_err());
+        assert!(from_json(r#""XYZ""#, "840").is_err());
+        assert!(from_json("9.78", "840").is_err());
+    }
+}
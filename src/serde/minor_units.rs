@@ -0,0 +1,210 @@
+//! Serializes an [`Amount`] as an integer number of minor units of its
+//! currency (e.g. cents for `EUR`) along with its currency code, e.g.
+//! `{"minor":1050,"currency":"EUR"}`, as used by many payment APIs.
+//!
+//! Serializing an amount that has more decimal places than its currency
+//! fails, as it cannot be represented as an integer number of minor units.
+//!
+//! # Examples
+//!
+//! ```
+//! use oxydized_money::{Amount, Decimal};
+//! use oxydized_money_macros::eur;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Charge {
+//!     #[serde(with = "oxydized_money::serde::minor_units")]
+//!     amount: Amount,
+//! }
+//!
+//! let charge = Charge { amount: eur!(10.50) };
+//! let json = serde_json::to_string(&charge).unwrap();
+//! assert_eq!(json, r#"{"amount":{"minor":1050,"currency":"EUR"}}"#);
+//! assert_eq!(serde_json::from_str::<Charge>(&json).unwrap(), charge);
+//! assert!(serde_json::to_string(&Charge { amount: eur!(10.505) }).is_err());
+//! ```
+//!
+//! When the currency is implied, [`TypedAmount`](crate::TypedAmount)s can
+//! be serialized as a bare integer with the [`typed`] module.
+
+use crate::{Amount, Currency, Decimal};
+use ::serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct MinorUnitsRepr {
+    minor: i64,
+    #[serde(with = "super::currency")]
+    currency: Currency,
+}
+
+/// Serializes `amount` as `{"minor":1050,"currency":"EUR"}`.
+pub fn serialize<S: Serializer>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error> {
+    let minor = to_minor_units(amount).map_err(ser::Error::custom)?;
+    let currency = amount.currency();
+    MinorUnitsRepr { minor, currency }.serialize(serializer)
+}
+
+/// Deserializes an amount from `{"minor":1050,"currency":"EUR"}`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+    let MinorUnitsRepr { minor, currency } = MinorUnitsRepr::deserialize(deserializer)?;
+    from_minor_units(minor, currency).map_err(de::Error::custom)
+}
+
+pub(crate) fn to_minor_units(amount: &Amount) -> Result<i64, String> {
+    let minor_units = amount
+        .scaled_units(Amount::minor_scale(amount.currency()))
+        .map_err(|_| format!("{} cannot be expressed in minor units", amount))?;
+    i64::try_from(minor_units).map_err(|_| format!("{} is out of range", amount))
+}
+
+pub(crate) fn from_minor_units(minor: i64, currency: Currency) -> Result<Amount, String> {
+    match Decimal::try_from_i128_with_scale(minor.into(), Amount::minor_scale(currency)) {
+        Ok(value) => Ok(Amount(value, currency)),
+        Err(_) => Err(format!("{} minor units are out of range", minor)),
+    }
+}
+
+/// Serializes a [`TypedAmount`] as a bare integer number of minor units,
+/// e.g. `1050`, its currency being implied by its type.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{TypedAmount, Decimal, markers::EUR};
+/// use oxydized_money_macros::dec;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Charge {
+///     #[serde(with = "oxydized_money::serde::minor_units::typed")]
+///     amount: TypedAmount<EUR>,
+/// }
+///
+/// let charge = Charge { amount: TypedAmount::new(dec!(10.50)) };
+/// let json = serde_json::to_string(&charge).unwrap();
+/// assert_eq!(json, r#"{"amount":1050}"#);
+/// assert_eq!(serde_json::from_str::<Charge>(&json).unwrap(), charge);
+/// ```
+pub mod typed {
+    use super::{from_minor_units, to_minor_units};
+    use crate::{Amount, CurrencyMarker, TypedAmount};
+    use ::serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `amount` as a bare integer number of minor units.
+    pub fn serialize<C, S>(amount: &TypedAmount<C>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: CurrencyMarker,
+        S: Serializer,
+    {
+        let minor = to_minor_units(&Amount::from(*amount)).map_err(ser::Error::custom)?;
+        minor.serialize(serializer)
+    }
+
+    /// Deserializes an amount from a bare integer number of minor units.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<TypedAmount<C>, D::Error>
+    where
+        C: CurrencyMarker,
+        D: Deserializer<'de>,
+    {
+        let minor = i64::deserialize(deserializer)?;
+        let amount = from_minor_units(minor, C::CURRENCY).map_err(de::Error::custom)?;
+        Ok(TypedAmount::new(amount.value()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{markers, Amount, Decimal, TypedAmount};
+    use oxydized_money_macros::{bhd, dec, eur, jpy, xau};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Charge {
+        #[serde(with = "oxydized_money::serde::minor_units")]
+        amount: Amount,
+    }
+
+    fn to_json(amount: Amount) -> serde_json::Result<String> {
+        serde_json::to_string(&Charge { amount })
+    }
+
+    fn from_json(json: &str) -> serde_json::Result<Amount> {
+        serde_json::from_str::<Charge>(json).map(|charge| charge.amount)
+    }
+
+    #[test]
+    fn test_serialize() {
+        let json = |amount| to_json(amount).unwrap();
+        assert_eq!(
+            json(eur!(10.5)),
+            r#"{"amount":{"minor":1050,"currency":"EUR"}}"#
+        );
+        assert_eq!(
+            json(eur!(-0.01)),
+            r#"{"amount":{"minor":-1,"currency":"EUR"}}"#
+        );
+        assert_eq!(
+            json(jpy!(100)),
+            r#"{"amount":{"minor":100,"currency":"JPY"}}"#
+        );
+        assert_eq!(
+            json(bhd!(1.5)),
+            r#"{"amount":{"minor":1500,"currency":"BHD"}}"#
+        );
+        assert_eq!(json(xau!(2)), r#"{"amount":{"minor":2,"currency":"XAU"}}"#);
+        assert!(to_json(eur!(0.001)).is_err());
+        assert!(to_json(jpy!(0.5)).is_err());
+        assert!(to_json(eur!(92233720368547758.08)).is_err());
+    }
+
+    #[test]
+    fn test_typed() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Charge {
+            #[serde(with = "oxydized_money::serde::minor_units::typed")]
+            amount: TypedAmount<markers::JPY>,
+        }
+
+        let charge = Charge {
+            amount: TypedAmount::new(dec!(-250)),
+        };
+        assert_eq!(
+            serde_json::to_string(&charge).unwrap(),
+            r#"{"amount":-250}"#
+        );
+        let json = r#"{"amount":-250}"#;
+        assert_eq!(serde_json::from_str::<Charge>(json).unwrap(), charge);
+        let charge = Charge {
+            amount: TypedAmount::new(dec!(0.5)),
+        };
+        assert!(serde_json::to_string(&charge).is_err());
+        assert!(serde_json::from_str::<Charge>(r#"{"amount":2.5}"#).is_err());
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let amount = |json| from_json(json).unwrap();
+        assert_eq!(
+            amount(r#"{"amount":{"minor":1050,"currency":"EUR"}}"#),
+            eur!(10.50)
+        );
+        assert_eq!(
+            amount(r#"{"amount":{"minor":-7,"currency":"JPY"}}"#),
+            jpy!(-7)
+        );
+        assert_eq!(
+            amount(r#"{"amount":{"minor":1,"currency":"BHD"}}"#),
+            bhd!(0.001)
+        );
+        assert_eq!(
+            amount(r#"{"amount":{"minor":1050,"currency":978}}"#),
+            eur!(10.50)
+        );
+        assert!(from_json(r#"{"amount":{"minor":10.5,"currency":"EUR"}}"#).is_err());
+        assert!(from_json(r#"{"amount":{"minor":"1050","currency":"EUR"}}"#).is_err());
+        assert!(from_json(r#"{"amount":{"minor":1050,"currency":"XYZ"}}"#).is_err());
+        assert!(from_json(r#"{"amount":{"minor":1050}}"#).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+//! Alternative serde representations for [`Amount`](crate::Amount)s.
+//!
+//! By default, amounts are serialized as a structure with a `value` and a
+//! `currency` field, e.g. `{"value":"10.50","currency":"EUR"}`. The
+//! modules below provide other representations that can be selected for
+//! individual fields, without wrapping them in a newtype:
+//!
+//! | Module          | Representation                       | Usage                                       |
+//! |-----------------|--------------------------------------|---------------------------------------------|
+//! | [`string`]      | `"10.50 EUR"`                        | `#[serde(with = "...")]`                    |
+//! | [`minor_units`] | `{"minor":1050,"currency":"EUR"}`    | `#[serde(with = "...")]`                    |
+//! | [`value_only`]  | `"10.50"`, in a currency known ahead | `serialize_with` and `deserialize_with`     |
+//! | [`strict`]      | default, rejecting excess precision  | `#[serde(with = "...")]`                    |
+//! | `bson`          | `Decimal128` value, for MongoDB      | `#[serde(with = "...")]`                    |
+//!
+//! The [`currency`] module provides the same flexibility for
+//! [`Currency`](crate::Currency) fields, which can be deserialized from
+//! numeric ISO 4217 codes (e.g. `978` for `EUR`) and optionally serialized
+//! as such.
+//!
+//! Both [`minor_units`] and [`value_only`] have a `typed` submodule for
+//! [`TypedAmount`](crate::TypedAmount) fields, whose currency is implied
+//! by their type.
+//!
+//! The `bson` module is only available with the `bson` feature.
+//!
+//! This module is only available with the `with_serde` feature.
+
+#[cfg(feature = "bson")]
+pub mod bson;
+pub mod currency;
+pub mod minor_units;
+pub mod strict;
+pub mod string;
+pub mod value_only;
@@ -0,0 +1,149 @@
+//! Serializes an [`Amount`] in its default format, e.g.
+//! `{"value":"10.50","currency":"EUR"}`, but rejects amounts with more
+//! decimal places than their currency allows when deserializing, e.g.
+//! `{"value":"10.123","currency":"EUR"}`. Trailing zeros are accepted, so
+//! `"10.500"` is a valid amount of `EUR`. The [`string`] submodule does the
+//! same for the `"10.50 EUR"` format of [`serde::string`](super::string).
+//!
+//! Currencies without minor units (e.g. `XAU`) only accept whole amounts,
+//! as in [`serde::minor_units`](super::minor_units).
+//!
+//! # Examples
+//!
+//! ```
+//! use oxydized_money::{Amount, Decimal};
+//! use oxydized_money_macros::eur;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Invoice {
+//!     #[serde(with = "oxydized_money::serde::strict")]
+//!     total: Amount,
+//! }
+//!
+//! let invoice: Invoice =
+//!     serde_json::from_str(r#"{"total":{"value":"10.50","currency":"EUR"}}"#).unwrap();
+//! assert_eq!(invoice, Invoice { total: eur!(10.50) });
+//!
+//! let error = serde_json::from_str::<Invoice>(r#"{"total":{"value":"10.123","currency":"EUR"}}"#)
+//!     .unwrap_err();
+//! assert!(error.to_string().starts_with(
+//!     "invalid precision for currency 'EUR': 10.123 EUR has more than 2 decimal places"
+//! ));
+//! ```
+
+use crate::{Amount, CurrencyError::InvalidPrecision};
+use ::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes `amount` in its default format, e.g.
+/// `{"value":"10.50","currency":"EUR"}`.
+pub fn serialize<S: Serializer>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error> {
+    amount.serialize(serializer)
+}
+
+/// Deserializes an amount from its default format, rejecting amounts with
+/// more decimal places than their currency allows.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+    check(Amount::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+/// Serializes an [`Amount`] as a string, e.g. `"10.50 EUR"`, and rejects
+/// amounts with more decimal places than their currency allows when
+/// deserializing.
+pub mod string {
+    use crate::Amount;
+    use ::serde::{de::Error, Deserializer, Serializer};
+
+    /// Serializes `amount` as a string, e.g. `"10.50 EUR"`.
+    pub fn serialize<S: Serializer>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error> {
+        super::super::string::serialize(amount, serializer)
+    }
+
+    /// Deserializes an amount from a string, e.g. `"10.50 EUR"`, rejecting
+    /// amounts with more decimal places than their currency allows.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+        super::check(super::super::string::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+fn check(amount: Amount) -> Result<Amount, String> {
+    let exponent = amount.currency().exponent().unwrap_or_default();
+    let mut value = amount.value();
+    value.rescale(exponent.into());
+    if value == amount.value() {
+        Ok(amount)
+    } else {
+        Err(format!(
+            "{}: {} {} has more than {} decimal places",
+            InvalidPrecision(amount.currency()),
+            amount.value(),
+            amount.currency().code(),
+            exponent
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{bhd, eur, jpy, xau};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Payment {
+        #[serde(with = "oxydized_money::serde::strict")]
+        amount: Amount,
+        #[serde(with = "oxydized_money::serde::strict::string")]
+        fee: Amount,
+    }
+
+    fn from_json(amount: &str, fee: &str) -> Result<Payment, String> {
+        let json = format!(r#"{{"amount":{},"fee":"{}"}}"#, amount, fee);
+        serde_json::from_str(&json).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn test_serialize() {
+        let payment = Payment {
+            amount: eur!(10.50),
+            fee: jpy!(3),
+        };
+        assert_eq!(
+            serde_json::to_string(&payment).unwrap(),
+            r#"{"amount":{"value":"10.50","currency":"EUR"},"fee":"3 JPY"}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let amount = |json| from_json(json, "1 JPY").map(|payment| payment.amount);
+        assert_eq!(
+            amount(r#"{"value":"10.5","currency":"EUR"}"#),
+            Ok(eur!(10.5))
+        );
+        assert_eq!(
+            amount(r#"{"value":"10.500","currency":"EUR"}"#),
+            Ok(eur!(10.5))
+        );
+        assert_eq!(
+            amount(r#"{"value":"1.234","currency":"BHD"}"#),
+            Ok(bhd!(1.234))
+        );
+        assert_eq!(amount(r#"{"value":"2","currency":"XAU"}"#), Ok(xau!(2)));
+        assert!(amount(r#"{"value":"10.123","currency":"EUR"}"#)
+            .unwrap_err()
+            .starts_with(
+                "invalid precision for currency 'EUR': 10.123 EUR has more than 2 decimal places"
+            ));
+        assert!(amount(r#"{"value":"0.5","currency":"XAU"}"#).is_err());
+
+        let fee = |fee| from_json(r#"{"value":"1","currency":"EUR"}"#, fee).map(|p| p.fee);
+        assert_eq!(fee("12 JPY"), Ok(jpy!(12)));
+        assert_eq!(fee("1.10 EUR"), Ok(eur!(1.1)));
+        assert!(fee("1.5 JPY").unwrap_err().starts_with(
+            "invalid precision for currency 'JPY': 1.5 JPY has more than 0 decimal places"
+        ));
+        assert!(fee("1.5").is_err());
+    }
+}
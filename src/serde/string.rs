@@ -0,0 +1,78 @@
+//! Serializes an [`Amount`] as a single string made of its value and its
+//! currency code, separated by a space, e.g. `"10.50 EUR"`. Numeric
+//! currency codes (e.g. `"10.50 978"`) are also accepted when
+//! deserializing.
+//!
+//! # Examples
+//!
+//! ```
+//! use oxydized_money::{Amount, Decimal};
+//! use oxydized_money_macros::eur;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Invoice {
+//!     #[serde(with = "oxydized_money::serde::string")]
+//!     total: Amount,
+//! }
+//!
+//! let invoice = Invoice { total: eur!(10.50) };
+//! let json = serde_json::to_string(&invoice).unwrap();
+//! assert_eq!(json, r#"{"total":"10.50 EUR"}"#);
+//! assert_eq!(serde_json::from_str::<Invoice>(&json).unwrap(), invoice);
+//! ```
+
+use crate::Amount;
+use ::serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+/// Serializes `amount` as a string, e.g. `"10.50 EUR"`.
+pub fn serialize<S: Serializer>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&amount.canonical())
+}
+
+/// Deserializes an amount from a string, e.g. `"10.50 EUR"`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+    let string = String::deserialize(deserializer)?;
+    Amount::parse_canonical(&string)
+        .ok_or_else(|| D::Error::custom(format!("invalid amount '{}'", string)))
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{eur, jpy};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Payment {
+        #[serde(with = "oxydized_money::serde::string")]
+        amount: Amount,
+    }
+
+    fn from_json(amount: &str) -> serde_json::Result<Amount> {
+        let json = format!(r#"{{"amount":"{}"}}"#, amount);
+        serde_json::from_str::<Payment>(&json).map(|payment| payment.amount)
+    }
+
+    #[test]
+    fn test_serialize() {
+        let json = |amount| serde_json::to_string(&Payment { amount }).unwrap();
+        assert_eq!(json(eur!(10.50)), r#"{"amount":"10.50 EUR"}"#);
+        assert_eq!(json(eur!(-0.001)), r#"{"amount":"-0.001 EUR"}"#);
+        assert_eq!(json(jpy!(100)), r#"{"amount":"100 JPY"}"#);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        assert_eq!(from_json("10.50 EUR").unwrap(), eur!(10.50));
+        assert_eq!(from_json(" -3 JPY ").unwrap(), jpy!(-3));
+        assert_eq!(from_json("10.50 978").unwrap(), eur!(10.50));
+        assert!(from_json("10.50").is_err());
+        assert!(from_json("EUR 10.50").is_err());
+        assert!(from_json("10.50 XYZ").is_err());
+        assert!(from_json("ten EUR").is_err());
+        let error = from_json("10.50 XYZ").unwrap_err().to_string();
+        assert!(error.starts_with("invalid amount '10.50 XYZ'"));
+    }
+}
@@ -0,0 +1,142 @@
+//! Serializes an [`Amount`] as its value only, e.g. `"10.50"`, for
+//! schemas in which the currency is implied. The currency is given by a
+//! [`CurrencyMarker`] type parameter, so the functions of this module are
+//! used with `serialize_with` and `deserialize_with` rather than `with`.
+//! Serializing an amount in another currency fails.
+//!
+//! The value is serialized the same way as the `value` field of the
+//! default representation of [`Amount`].
+//!
+//! # Examples
+//!
+//! ```
+//! use oxydized_money::{Amount, Decimal, markers::EUR};
+//! use oxydized_money_macros::{eur, usd};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Invoice {
+//!     #[serde(
+//!         serialize_with = "oxydized_money::serde::value_only::serialize::<EUR, _>",
+//!         deserialize_with = "oxydized_money::serde::value_only::deserialize::<EUR, _>"
+//!     )]
+//!     total: Amount,
+//! }
+//!
+//! let invoice = Invoice { total: eur!(10.50) };
+//! let json = serde_json::to_string(&invoice).unwrap();
+//! assert_eq!(json, r#"{"total":"10.50"}"#);
+//! assert_eq!(serde_json::from_str::<Invoice>(&json).unwrap(), invoice);
+//! assert!(serde_json::to_string(&Invoice { total: usd!(10.50) }).is_err());
+//! ```
+//!
+//! [`TypedAmount`](crate::TypedAmount)s, whose currency is known from
+//! their type, can use the [`typed`] module with `with` instead.
+
+use crate::{Amount, CurrencyMarker, Decimal};
+use ::serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes `amount` as its value only, provided that it is in the
+/// currency `C`.
+pub fn serialize<C, S>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error>
+where
+    C: CurrencyMarker,
+    S: Serializer,
+{
+    if amount.currency() != C::CURRENCY {
+        let message = format!("{} is not in {}", amount, C::CODE);
+        return Err(ser::Error::custom(message));
+    }
+    Serialize::serialize(&amount.value(), serializer)
+}
+
+/// Deserializes an amount in the currency `C` from its value only.
+pub fn deserialize<'de, C, D>(deserializer: D) -> Result<Amount, D::Error>
+where
+    C: CurrencyMarker,
+    D: Deserializer<'de>,
+{
+    let value = <Decimal as Deserialize>::deserialize(deserializer)?;
+    Ok(Amount(value, C::CURRENCY))
+}
+
+/// Serializes a [`TypedAmount`] as its value only, e.g. `"10.50"`.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{TypedAmount, Decimal, markers::EUR};
+/// use oxydized_money_macros::dec;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Invoice {
+///     #[serde(with = "oxydized_money::serde::value_only::typed")]
+///     total: TypedAmount<EUR>,
+/// }
+///
+/// let invoice = Invoice { total: TypedAmount::new(dec!(10.50)) };
+/// let json = serde_json::to_string(&invoice).unwrap();
+/// assert_eq!(json, r#"{"total":"10.50"}"#);
+/// assert_eq!(serde_json::from_str::<Invoice>(&json).unwrap(), invoice);
+/// ```
+pub mod typed {
+    use crate::{CurrencyMarker, Decimal, TypedAmount};
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `amount` as its value only.
+    pub fn serialize<C, S>(amount: &TypedAmount<C>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: CurrencyMarker,
+        S: Serializer,
+    {
+        Serialize::serialize(&amount.value(), serializer)
+    }
+
+    /// Deserializes an amount from its value only.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<TypedAmount<C>, D::Error>
+    where
+        C: CurrencyMarker,
+        D: Deserializer<'de>,
+    {
+        <Decimal as Deserialize>::deserialize(deserializer).map(TypedAmount::new)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{markers, Amount, Decimal, TypedAmount};
+    use oxydized_money_macros::{dec, eur, jpy};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Invoice {
+        #[serde(
+            serialize_with = "oxydized_money::serde::value_only::serialize::<markers::JPY, _>",
+            deserialize_with = "oxydized_money::serde::value_only::deserialize::<markers::JPY, _>"
+        )]
+        total: Amount,
+        #[serde(with = "oxydized_money::serde::value_only::typed")]
+        tax: TypedAmount<markers::JPY>,
+    }
+
+    #[test]
+    fn test_value_only() {
+        let invoice = Invoice {
+            total: jpy!(1000),
+            tax: TypedAmount::new(dec!(100)),
+        };
+        let json = serde_json::to_string(&invoice).unwrap();
+        assert_eq!(json, r#"{"total":"1000","tax":"100"}"#);
+        assert_eq!(serde_json::from_str::<Invoice>(&json).unwrap(), invoice);
+
+        let invoice = Invoice {
+            total: eur!(1000),
+            tax: TypedAmount::new(dec!(100)),
+        };
+        let error = serde_json::to_string(&invoice).unwrap_err().to_string();
+        assert_eq!(error, "€ 1000.00 is not in JPY");
+        assert!(serde_json::from_str::<Invoice>(r#"{"total":"x","tax":"1"}"#).is_err());
+    }
+}
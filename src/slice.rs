@@ -0,0 +1,163 @@
+use crate::{
+    Amount, AmountResult, CurrencyError, CurrencyLike, Decimal, ExchangeRate, Operation, Result,
+};
+
+/// Sums a slice of amounts, as the [`Sum`](std::iter::Sum) of their
+/// iterator but checking their currency once up front, and then summing
+/// their values without going through an [`AmountResult`] for each of
+/// them. Returns a [`CurrencyError::Unknown`] if the slice is empty, a
+/// [`CurrencyError::Mismatch`] between the first currency and the first
+/// other one, or a [`CurrencyError::Overflow`] if a partial sum does not
+/// fit in a [`Decimal`].
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{sum_slice, Decimal};
+/// use oxydized_money_macros::{eur, usd};
+///
+/// assert_eq!(sum_slice(&[eur!(1), eur!(2.5), eur!(-0.5)]), eur!(3));
+/// assert!(sum_slice(&[eur!(1), usd!(2)]).is_mismatch());
+/// assert!(sum_slice::<oxydized_money::Currency>(&[]).is_unknown());
+/// ```
+pub fn sum_slice<C: CurrencyLike>(amounts: &[Amount<C>]) -> AmountResult<C> {
+    let Some(&first) = amounts.first() else {
+        return AmountResult::failed_in(Operation::Sum, CurrencyError::Unknown);
+    };
+    if let Some(&other) = amounts.iter().find(|a| a.currency() != first.currency()) {
+        return AmountResult::mismatch_in(Operation::Sum, first, other);
+    }
+    let total = amounts.iter().try_fold(Decimal::ZERO, |total, amount| {
+        total.checked_add(amount.value())
+    });
+    match total {
+        Some(total) => Amount(total, first.currency()).into(),
+        None => AmountResult::failed_in(Operation::Sum, CurrencyError::Overflow),
+    }
+}
+
+/// Multiplies the values of a slice of amounts by `factor` in place, e.g.
+/// to apply a discount to a price list. Fails with a
+/// [`CurrencyError::Overflow`], leaving the slice unchanged, if the value
+/// of an amount does not fit in a [`Decimal`] once multiplied.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{scale_slice, Decimal};
+/// use oxydized_money_macros::{dec, eur, usd};
+///
+/// let mut prices = [eur!(10), usd!(20)];
+/// scale_slice(&mut prices, dec!(0.9)).unwrap();
+/// assert_eq!(prices, [eur!(9), usd!(18)]);
+/// ```
+pub fn scale_slice<C: CurrencyLike>(amounts: &mut [Amount<C>], factor: Decimal) -> Result<(), C> {
+    check_mul(amounts, factor)?;
+    for amount in amounts {
+        amount.0 *= factor;
+    }
+    Ok(())
+}
+
+/// Converts a slice of amounts in place with an [`ExchangeRate`], checking
+/// once up front that they are all in its source currency. Fails with a
+/// [`CurrencyError::Mismatch`] between the source currency of `rate` and
+/// the first other currency, or with a [`CurrencyError::Overflow`] if the
+/// converted value of an amount does not fit in a [`Decimal`], leaving the
+/// slice unchanged in both cases.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{convert_slice, CurrencyError, Decimal, ExchangeRate, Currency::*};
+/// use oxydized_money_macros::{dec, eur, gbp, usd};
+///
+/// let eur_usd = ExchangeRate::new(EUR, USD, dec!(1.1));
+/// let mut prices = [eur!(10), eur!(20)];
+/// convert_slice(&mut prices, &eur_usd).unwrap();
+/// assert_eq!(prices, [usd!(11), usd!(22)]);
+///
+/// let mut prices = [eur!(10), gbp!(20)];
+/// assert_eq!(convert_slice(&mut prices, &eur_usd), Err(CurrencyError::Mismatch(EUR, GBP)));
+/// ```
+pub fn convert_slice(amounts: &mut [Amount], rate: &ExchangeRate) -> Result<()> {
+    if let Some(other) = amounts.iter().find(|a| a.currency() != rate.from()) {
+        return Err(CurrencyError::Mismatch(rate.from(), other.currency()));
+    }
+    check_mul(amounts, rate.rate())?;
+    for amount in amounts {
+        *amount = Amount(amount.value() * rate.rate(), rate.to());
+    }
+    Ok(())
+}
+
+/// Checks that the values of all the amounts can be multiplied by `factor`
+/// before any of them is modified.
+fn check_mul<C: CurrencyLike>(amounts: &[Amount<C>], factor: Decimal) -> Result<(), C> {
+    if amounts
+        .iter()
+        .all(|amount| amount.value().checked_mul(factor).is_some())
+    {
+        Ok(())
+    } else {
+        Err(CurrencyError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{convert_slice, scale_slice, sum_slice};
+    use crate as oxydized_money;
+    use oxydized_money::{
+        Amount, AmountResult, Currency::*, CurrencyError::*, Decimal, ExchangeRate, Operation,
+    };
+    use oxydized_money_macros::{dec, eur, jpy, usd};
+
+    #[test]
+    fn test_sum_slice() {
+        let amounts: Vec<Amount> = (1..=1000)
+            .map(|i| Amount(Decimal::new(i, 2), EUR))
+            .collect();
+        assert_eq!(sum_slice(&amounts), eur!(5005));
+        assert_eq!(sum_slice(&amounts), amounts.iter().sum::<AmountResult>());
+        assert_eq!(sum_slice(&[jpy!(7)]), jpy!(7));
+
+        let result = sum_slice(&[eur!(1), eur!(2), usd!(3), jpy!(4)]);
+        assert_eq!(result.into_inner(), Err(Mismatch(EUR, USD)));
+        assert_eq!(result.operation(), Some(Operation::Sum));
+
+        let amounts = [Amount(Decimal::MAX, EUR), eur!(1)];
+        assert!(sum_slice(&amounts).is_overflow());
+        let mut amounts = vec![eur!(1); 300];
+        amounts[299] = Amount(Decimal::MAX, EUR);
+        assert!(sum_slice(&amounts).is_overflow());
+    }
+
+    #[test]
+    fn test_scale_slice() {
+        let mut amounts = [eur!(10), jpy!(-3)];
+        assert_eq!(scale_slice(&mut amounts, dec!(1.5)), Ok(()));
+        assert_eq!(amounts, [eur!(15), jpy!(-4.5)]);
+
+        let mut amounts = [eur!(1), Amount(Decimal::MAX, EUR), eur!(3)];
+        assert_eq!(scale_slice(&mut amounts, dec!(2)), Err(Overflow));
+        assert_eq!(amounts, [eur!(1), Amount(Decimal::MAX, EUR), eur!(3)]);
+    }
+
+    #[test]
+    fn test_convert_slice() {
+        let rate = ExchangeRate::new(EUR, USD, dec!(2));
+        let mut amounts = [eur!(1), eur!(2.5)];
+        assert_eq!(convert_slice(&mut amounts, &rate), Ok(()));
+        assert_eq!(amounts, [usd!(2), usd!(5)]);
+
+        let mut amounts = [eur!(1), jpy!(2)];
+        assert_eq!(convert_slice(&mut amounts, &rate), Err(Mismatch(EUR, JPY)));
+        assert_eq!(amounts, [eur!(1), jpy!(2)]);
+
+        let mut amounts = [eur!(1), Amount(Decimal::MAX, EUR)];
+        assert_eq!(convert_slice(&mut amounts, &rate), Err(Overflow));
+        assert_eq!(amounts, [eur!(1), Amount(Decimal::MAX, EUR)]);
+        assert_eq!(convert_slice(&mut [], &rate), Ok(()));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Amount, CurrencyLike};
+
+/// Sorts amounts in any currencies, grouping them by currency code and
+/// sorting them by value within each currency, following
+/// [`Amount::total_cmp`]. The sort is stable.
+///
+/// Sorting amounts with [`PartialOrd`], e.g. with `sort_by` and
+/// `partial_cmp`, is not possible when they are in different currencies.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{sort_amounts, Decimal};
+/// use oxydized_money_macros::{eur, usd};
+///
+/// let mut amounts = [usd!(3), eur!(10), usd!(-5), eur!(2)];
+/// sort_amounts(&mut amounts);
+/// assert_eq!(amounts, [eur!(2), eur!(10), usd!(-5), usd!(3)]);
+/// ```
+pub fn sort_amounts<C: CurrencyLike>(amounts: &mut [Amount<C>]) {
+    amounts.sort_by(Amount::total_cmp);
+}
+
+/// Sorts amounts in any currencies, grouping them by currency code and
+/// sorting them by absolute value within each currency, e.g. to list the
+/// largest movements of an account whatever their direction. The sort is
+/// stable.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{sort_amounts_by_abs, Decimal};
+/// use oxydized_money_macros::{eur, usd};
+///
+/// let mut amounts = [usd!(3), eur!(10), usd!(-5), eur!(-2)];
+/// sort_amounts_by_abs(&mut amounts);
+/// assert_eq!(amounts, [eur!(-2), eur!(10), usd!(3), usd!(-5)]);
+/// ```
+pub fn sort_amounts_by_abs<C: CurrencyLike>(amounts: &mut [Amount<C>]) {
+    amounts.sort_by(|a, b| a.abs().total_cmp(&b.abs()));
+}
+
+#[cfg(test)]
+mod test {
+    use super::{sort_amounts, sort_amounts_by_abs};
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::{eur, jpy, usd};
+
+    #[test]
+    fn test_sort_amounts() {
+        let mut amounts = [jpy!(1), usd!(-1), eur!(3), jpy!(-7), eur!(1)];
+        sort_amounts(&mut amounts);
+        assert_eq!(amounts, [eur!(1), eur!(3), jpy!(-7), jpy!(1), usd!(-1)]);
+
+        let mut amounts: [Amount; 0] = [];
+        sort_amounts(&mut amounts);
+    }
+
+    #[test]
+    fn test_sort_amounts_by_abs() {
+        let mut amounts = [jpy!(1), eur!(-3), jpy!(-7), eur!(1), eur!(-1)];
+        sort_amounts_by_abs(&mut amounts);
+        assert_eq!(amounts, [eur!(1), eur!(-1), eur!(-3), jpy!(1), jpy!(-7)]);
+        assert_eq!(amounts[1].value(), Decimal::NEGATIVE_ONE);
+    }
+}
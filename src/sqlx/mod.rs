@@ -0,0 +1,155 @@
+//! Integration with `sqlx`, for the `sqlx-postgres`, `sqlx-mysql` and
+//! `sqlx-sqlite` features.
+
+#[cfg(feature = "sqlx-mysql")]
+mod mysql;
+#[cfg(feature = "sqlx-postgres")]
+mod postgres;
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlite;
+
+use crate::{
+    Amount, Currency,
+    CurrencyError::{InvalidPrecision, Overflow},
+    Decimal,
+};
+use ::sqlx::{error::BoxDynError, ColumnIndex, Decode, Row, Type};
+
+/// `ScaledValue` binds the value of an [`Amount`] to a column, rescaled to
+/// the number of decimal places of its currency, e.g. `10.5 EUR` is bound as
+/// `10.50`, so that values are stored at currency precision. Binding an
+/// amount with more decimal places than its currency allows fails with a
+/// [`CurrencyError::InvalidPrecision`](crate::CurrencyError::InvalidPrecision)
+/// rather than rounding it silently.
+///
+/// Amounts are stored in two columns by binding their value with
+/// `ScaledValue` and their currency with its code. They are read back from
+/// rows with a `value` and a `currency` column with [`sqlx::FromRow`],
+/// either with `query_as` or flattened in a structure deriving `FromRow`,
+/// renaming the columns in the query if needed:
+///
+/// ```ignore
+/// let total = eur!(10.5);
+/// sqlx::query("INSERT INTO invoices (id, total_value, total_currency) VALUES (?, ?, ?)")
+///     .bind(1)
+///     .bind(ScaledValue(total))
+///     .bind(total.currency().code())
+///     .execute(&pool)
+///     .await?;
+///
+/// #[derive(sqlx::FromRow)]
+/// struct Invoice {
+///     id: i64,
+///     #[sqlx(flatten)]
+///     total: Amount,
+/// }
+///
+/// let invoice: Invoice = sqlx::query_as(
+///     "SELECT id, total_value AS value, total_currency AS currency FROM invoices WHERE id = ?",
+/// )
+/// .bind(1)
+/// .fetch_one(&pool)
+/// .await?;
+/// ```
+///
+/// With PostgreSQL and MySQL, the value is bound as a decimal, e.g. for a
+/// `NUMERIC` or `DECIMAL(p,s)` column. With SQLite, which has no decimal
+/// type, it is bound as a `TEXT` decimal, or as an `INTEGER` number of
+/// minor units with [`ScaledValue::minor_units`].
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct ScaledValue(pub Amount);
+
+impl ScaledValue {
+    /// Returns the value of the amount, rescaled to the number of decimal
+    /// places of its currency.
+    pub fn value(&self) -> crate::Result<Decimal> {
+        let Amount(value, currency) = self.0;
+        let mut scaled = value;
+        scaled.rescale(Amount::minor_scale(currency));
+        if scaled == value {
+            Ok(scaled)
+        } else {
+            Err(InvalidPrecision(currency))
+        }
+    }
+
+    /// Returns the value of the amount as a number of minor units of its
+    /// currency (e.g. cents for `EUR`), to be stored in an integer column.
+    pub fn minor_units(&self) -> crate::Result<i64> {
+        let minor_units = self
+            .0
+            .scaled_units(Amount::minor_scale(self.0.currency()))?;
+        i64::try_from(minor_units).map_err(|_| Overflow)
+    }
+}
+
+/// Reads an amount from a row with a decimal `value` column and a string
+/// `currency` column.
+fn from_row<'r, R>(row: &'r R) -> Result<Amount, ::sqlx::Error>
+where
+    R: Row,
+    &'static str: ColumnIndex<R>,
+    Decimal: Decode<'r, R::Database> + Type<R::Database>,
+    &'r str: Decode<'r, R::Database> + Type<R::Database>,
+{
+    let value = row.try_get::<Decimal, _>("value")?;
+    let code = row.try_get::<&str, _>("currency")?;
+    Ok(Amount(value, currency_column(code)?))
+}
+
+fn currency_column(code: &str) -> Result<Currency, ::sqlx::Error> {
+    currency(code).map_err(|source| ::sqlx::Error::ColumnDecode {
+        index: "currency".to_owned(),
+        source,
+    })
+}
+
+fn currency(code: &str) -> Result<Currency, BoxDynError> {
+    Currency::from_code(code.trim()).ok_or_else(|| format!("unknown currency '{}'", code).into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{currency, ScaledValue};
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, CurrencyError::*, Decimal};
+    use oxydized_money_macros::{bhd, dec, eur, jpy};
+
+    #[test]
+    fn test_scaled_value() {
+        assert_eq!(ScaledValue(eur!(10.5)).value(), Ok(dec!(10.50)));
+        assert_eq!(ScaledValue(eur!(10.5)).value().unwrap().scale(), 2);
+        assert_eq!(ScaledValue(eur!(10.500)).value().unwrap().scale(), 2);
+        assert_eq!(ScaledValue(jpy!(12)).value(), Ok(dec!(12)));
+        assert_eq!(ScaledValue(eur!(1.001)).value(), Err(InvalidPrecision(EUR)));
+    }
+
+    #[test]
+    fn test_minor_units() {
+        assert_eq!(ScaledValue(eur!(10.5)).minor_units(), Ok(1050));
+        assert_eq!(ScaledValue(jpy!(-12)).minor_units(), Ok(-12));
+        assert_eq!(ScaledValue(bhd!(1.5)).minor_units(), Ok(1500));
+        assert_eq!(
+            ScaledValue(eur!(1.001)).minor_units(),
+            Err(InvalidPrecision(EUR))
+        );
+        assert_eq!(
+            ScaledValue(Amount(Decimal::MAX, EUR)).minor_units(),
+            Err(Overflow)
+        );
+        assert_eq!(
+            ScaledValue(eur!(92233720368547758.08)).minor_units(),
+            Err(Overflow)
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(currency("EUR").unwrap(), EUR);
+        assert_eq!(currency("JPY ").unwrap(), JPY);
+        assert_eq!(
+            currency("XYZ").unwrap_err().to_string(),
+            "unknown currency 'XYZ'"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use super::{from_row, ScaledValue};
+use crate::{Amount, Decimal};
+use ::sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    mysql::{MySqlRow, MySqlTypeInfo},
+    Database, Encode, FromRow, MySql, Type,
+};
+
+/// With the `sqlx-mysql` feature, amounts are stored in MySQL in a
+/// `DECIMAL(p,s)` and a `CHAR(3)` column, see [`ScaledValue`](crate::ScaledValue).
+impl<'r> FromRow<'r, MySqlRow> for Amount {
+    fn from_row(row: &'r MySqlRow) -> Result<Self, ::sqlx::Error> {
+        from_row(row)
+    }
+}
+
+impl Type<MySql> for ScaledValue {
+    fn type_info() -> MySqlTypeInfo {
+        <Decimal as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <Decimal as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, MySql> for ScaledValue {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <MySql as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        <Decimal as Encode<MySql>>::encode_by_ref(&self.value()?, buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ScaledValue;
+    use crate::Decimal;
+    use sqlx::{MySql, Type};
+
+    #[test]
+    fn test_type_info() {
+        assert_eq!(
+            <ScaledValue as Type<MySql>>::type_info(),
+            <Decimal as Type<MySql>>::type_info()
+        );
+    }
+}
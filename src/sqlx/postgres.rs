@@ -0,0 +1,136 @@
+use super::{currency, from_row, ScaledValue};
+use crate::{Amount, Decimal};
+use ::sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{
+        types::{Oid, PgRecordDecoder, PgRecordEncoder},
+        PgArgumentBuffer, PgRow, PgTypeInfo, PgValueRef,
+    },
+    Decode, Encode, FromRow, Postgres, Type,
+};
+
+/// Name of the PostgreSQL composite type amounts are mapped to.
+const TYPE_NAME: &str = "money_amount";
+
+/// OID of the PostgreSQL `CHAR(n)` type, i.e. `bpchar`.
+const BPCHAR_OID: Oid = Oid(1042);
+
+/// With the `sqlx-postgres` feature, amounts can be stored in a single
+/// PostgreSQL column of a composite type named `money_amount`, which must
+/// be created beforehand, with a `CHAR(3)` currency field as the fields of
+/// the records bound by sqlx must match the types of the composite type
+/// exactly:
+///
+/// ```sql
+/// CREATE TYPE money_amount AS (value NUMERIC, currency CHAR(3));
+/// CREATE TABLE invoices (id BIGINT PRIMARY KEY, total money_amount NOT NULL);
+/// ```
+///
+/// Amounts can then be bound and read like any other value:
+///
+/// ```ignore
+/// sqlx::query("INSERT INTO invoices (id, total) VALUES ($1, $2)")
+///     .bind(1)
+///     .bind(eur!(10.50))
+///     .execute(&pool)
+///     .await?;
+/// let total: Amount = sqlx::query_scalar("SELECT total FROM invoices WHERE id = $1")
+///     .bind(1)
+///     .fetch_one(&pool)
+///     .await?;
+/// ```
+///
+/// Amounts can also be stored in a `NUMERIC` and a `CHAR(3)` column, see
+/// [`ScaledValue`](crate::ScaledValue).
+impl Type<Postgres> for Amount {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name(TYPE_NAME)
+    }
+}
+
+impl<'q> Encode<'q, Postgres> for Amount {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let mut encoder = PgRecordEncoder::new(buf);
+        encoder.encode(self.value())?;
+        encoder.encode(CurrencyCode(self.currency().code()))?;
+        encoder.finish();
+        Ok(IsNull::No)
+    }
+}
+
+/// The code of a currency, encoded as the `CHAR(3)` field of the
+/// `money_amount` composite type rather than as a `TEXT` like a `&str`.
+struct CurrencyCode<'a>(&'a str);
+
+impl Type<Postgres> for CurrencyCode<'_> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_oid(BPCHAR_OID)
+    }
+}
+
+impl Encode<'_, Postgres> for CurrencyCode<'_> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<Postgres>>::encode_by_ref(&self.0, buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Amount {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let mut decoder = PgRecordDecoder::new(value)?;
+        let value = decoder.try_decode::<Decimal>()?;
+        let code = decoder.try_decode::<String>()?;
+        Ok(Amount(value, currency(&code)?))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for Amount {
+    fn from_row(row: &'r PgRow) -> Result<Self, ::sqlx::Error> {
+        from_row(row)
+    }
+}
+
+impl Type<Postgres> for ScaledValue {
+    fn type_info() -> PgTypeInfo {
+        <Decimal as Type<Postgres>>::type_info()
+    }
+}
+
+impl<'q> Encode<'q, Postgres> for ScaledValue {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <Decimal as Encode<Postgres>>::encode_by_ref(&self.value()?, buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BPCHAR_OID;
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Decimal};
+    use oxydized_money_macros::eur;
+    use sqlx::{
+        postgres::{types::Oid, PgArgumentBuffer},
+        Encode, Postgres, Type, TypeInfo,
+    };
+
+    #[test]
+    fn test_type_info() {
+        assert_eq!(
+            <Amount as Type<Postgres>>::type_info().name(),
+            "money_amount"
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        let mut buf = PgArgumentBuffer::default();
+        Encode::<Postgres>::encode_by_ref(&eur!(10.50), &mut buf).unwrap();
+        let int = |offset: usize| i32::from_be_bytes(buf[offset..offset + 4].try_into().unwrap());
+        assert_eq!(int(0), 2);
+        assert_eq!(Oid(int(4) as u32), Oid(1700));
+        let currency = 12 + int(8) as usize;
+        assert_eq!(Oid(int(currency) as u32), BPCHAR_OID);
+        assert_eq!(int(currency + 4), 3);
+        assert_eq!(&buf[currency + 8..], b"EUR");
+    }
+}
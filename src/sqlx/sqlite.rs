@@ -0,0 +1,108 @@
+use super::{currency_column, ScaledValue};
+use crate::{Amount, Decimal};
+use ::sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    sqlite::{SqliteRow, SqliteTypeInfo, SqliteValueRef},
+    Database, Decode, Encode, FromRow, Row, Sqlite, Type,
+};
+
+/// With the `sqlx-sqlite` feature, amounts can be stored in a single SQLite
+/// `TEXT` column in their canonical string form, e.g. `"10.50 EUR"`, which
+/// keeps their value exact:
+///
+/// ```ignore
+/// sqlx::query("INSERT INTO invoices (id, total) VALUES (?, ?)")
+///     .bind(1)
+///     .bind(eur!(10.50))
+///     .execute(&pool)
+///     .await?;
+/// let total: Amount = sqlx::query_scalar("SELECT total FROM invoices WHERE id = ?")
+///     .bind(1)
+///     .fetch_one(&pool)
+///     .await?;
+/// ```
+///
+/// They can also be stored in two columns, see [`ScaledValue`](crate::ScaledValue).
+/// When reading an amount from a row, its `value` column is either a `TEXT`
+/// decimal or an `INTEGER` number of minor units of its currency.
+impl Type<Sqlite> for Amount {
+    fn type_info() -> SqliteTypeInfo {
+        <&str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <&str as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for Amount {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Sqlite as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        <String as Encode<Sqlite>>::encode(self.canonical(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for Amount {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        let text = <&str as Decode<Sqlite>>::decode(value)?;
+        Amount::parse_canonical(text).ok_or_else(|| format!("invalid amount '{}'", text).into())
+    }
+}
+
+impl<'r> FromRow<'r, SqliteRow> for Amount {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, ::sqlx::Error> {
+        let currency = currency_column(row.try_get::<&str, _>("currency")?)?;
+        if let Ok(minor_units) = row.try_get::<i64, _>("value") {
+            return Ok(Amount(
+                Decimal::new(minor_units, Amount::minor_scale(currency)),
+                currency,
+            ));
+        }
+        let text = row.try_get::<&str, _>("value")?;
+        let value =
+            text.trim()
+                .parse::<Decimal>()
+                .map_err(|error| ::sqlx::Error::ColumnDecode {
+                    index: "value".to_owned(),
+                    source: error.into(),
+                })?;
+        Ok(Amount(value, currency))
+    }
+}
+
+impl Type<Sqlite> for ScaledValue {
+    fn type_info() -> SqliteTypeInfo {
+        <&str as Type<Sqlite>>::type_info()
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for ScaledValue {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Sqlite as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        <String as Encode<Sqlite>>::encode(self.value()?.to_string(), buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ScaledValue;
+    use crate::Amount;
+    use sqlx::{Sqlite, Type};
+
+    #[test]
+    fn test_type_info() {
+        assert_eq!(
+            <Amount as Type<Sqlite>>::type_info(),
+            <&str as Type<Sqlite>>::type_info()
+        );
+        assert_eq!(
+            <ScaledValue as Type<Sqlite>>::type_info(),
+            <&str as Type<Sqlite>>::type_info()
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{AmountResult, CurrencyError, CurrencyLike, Operation};
+use futures_util::{Stream, StreamExt};
+use std::pin::pin;
+
+impl<C: CurrencyLike> AmountResult<C> {
+    /// Asynchronous counterpart of the [`Sum`](std::iter::Sum) of
+    /// [`Amount`](crate::Amount)s or [`AmountResult`]s, summing the items of
+    /// a [`Stream`] as they arrive, without collecting them first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_util::Stream;
+    /// use oxydized_money::{Amount, AmountResult};
+    ///
+    /// async fn total(payments: impl Stream<Item = Amount>) -> AmountResult {
+    ///     AmountResult::sum_stream(payments).await
+    /// }
+    /// ```
+    pub async fn sum_stream<S>(stream: S) -> Self
+    where
+        S: Stream,
+        S::Item: Into<AmountResult<C>>,
+    {
+        let mut stream = pin!(stream);
+        let mut total: Option<Self> = None;
+        while let Some(item) = stream.next().await {
+            total = Some(match total {
+                Some(total) => [total, item.into()].into_iter().sum(),
+                None => item.into(),
+            });
+        }
+        total.unwrap_or_else(|| Self::failed_in(Operation::Sum, CurrencyError::Unknown))
+    }
+
+    /// Asynchronous counterpart of [`AmountResult::try_sum`], summing a
+    /// [`Stream`] of fallible amounts, such as rows fetched from a database.
+    /// Stops at the first error `E` and returns it, otherwise returns the
+    /// sum of the amounts as [`AmountResult::sum_stream`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures_util::Stream;
+    /// use oxydized_money::{Amount, AmountResult};
+    ///
+    /// async fn total(
+    ///     rows: impl Stream<Item = Result<Amount, std::io::Error>>,
+    /// ) -> Result<AmountResult, std::io::Error> {
+    ///     AmountResult::try_sum_stream(rows).await
+    /// }
+    /// ```
+    pub async fn try_sum_stream<S, T, E>(stream: S) -> Result<Self, E>
+    where
+        S: Stream<Item = Result<T, E>>,
+        T: Into<AmountResult<C>>,
+    {
+        let mut stream = pin!(stream);
+        let mut total: Option<Self> = None;
+        while let Some(item) = stream.next().await {
+            let item = item?.into();
+            total = Some(match total {
+                Some(total) => [total, item].into_iter().sum(),
+                None => item,
+            });
+        }
+        Ok(total.unwrap_or_else(|| Self::failed_in(Operation::Sum, CurrencyError::Unknown)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use futures_util::stream;
+    use oxydized_money::{Amount, AmountResult, Currency::*, CurrencyError::*, Decimal, Operation};
+    use oxydized_money_macros::{dec, eur, usd};
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_stream() {
+        let amounts = [eur!(1), eur!(2.5), eur!(-0.5)];
+        let total = block_on(AmountResult::sum_stream(stream::iter(amounts)));
+        assert_eq!(total, eur!(3));
+        assert_eq!(total, amounts.iter().sum::<AmountResult>());
+
+        let total = block_on(AmountResult::sum_stream(stream::iter([eur!(1), usd!(1)])));
+        assert_eq!(total.into_inner(), Err(Mismatch(EUR, USD)));
+        assert_eq!(total.operation(), Some(Operation::Sum));
+
+        let results = [AmountResult::unknown(), eur!(1).into(), eur!(1) / dec!(0)];
+        let total = block_on(AmountResult::sum_stream(stream::iter(results)));
+        assert!(total.is_divide_by_zero());
+
+        let total = block_on(AmountResult::sum_stream(stream::empty::<Amount>()));
+        assert!(total.is_unknown());
+        assert_eq!(total.operation(), Some(Operation::Sum));
+    }
+
+    #[test]
+    fn test_try_sum_stream() {
+        let rows: [Result<Amount, &str>; 2] = [Ok(eur!(1)), Ok(eur!(2))];
+        let total = block_on(AmountResult::try_sum_stream(stream::iter(rows)));
+        assert_eq!(total, Ok(AmountResult::from(eur!(3))));
+
+        let rows = [Ok(eur!(1)), Err("connection lost"), Ok(usd!(2))];
+        let total = block_on(AmountResult::try_sum_stream(stream::iter(rows)));
+        assert_eq!(total, Err("connection lost"));
+
+        let rows = stream::empty::<Result<Amount, &str>>();
+        assert!(block_on(AmountResult::try_sum_stream(rows))
+            .unwrap()
+            .is_unknown());
+    }
+}
@@ -0,0 +1,169 @@
+//! Support for the [`assert_amount_eq!`](crate::assert_amount_eq) macro,
+//! for the `testing` feature.
+
+use crate::{Amount, CurrencyLike, Decimal, Tolerance};
+use std::fmt::Arguments;
+
+/// Asserts that two amounts are equal, i.e. that they are in the same
+/// currency and that their values are equal, or within the given tolerance,
+/// expressed as an amount in the same currency (e.g.
+/// `tolerance = eur!(0.01)`). Unlike `assert_eq!`, the panic message shows
+/// both amounts, their difference, their currencies and the tolerance. A
+/// custom message may be provided, as with `assert_eq!`.
+///
+/// Available with the `testing` feature.
+///
+/// # Panics
+///
+/// Panics if the amounts are not equal, or if the tolerance is not in the
+/// currency of the expected amount.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{assert_amount_eq, Currency::USD, Decimal};
+/// use oxydized_money_macros::{dec, eur, usd};
+///
+/// assert_amount_eq!(eur!(10.50), eur!(10.5));
+/// assert_amount_eq!(
+///     eur!(10).converted_to(USD, dec!(1.0876)),
+///     usd!(10.88),
+///     tolerance = usd!(0.01),
+/// );
+/// assert_amount_eq!(eur!(1), eur!(1), "unexpected {}", "balance");
+/// ```
+///
+/// ```should_panic
+/// use oxydized_money::{assert_amount_eq, Decimal};
+/// use oxydized_money_macros::eur;
+///
+/// // assertion `actual == expected` failed
+/// //     actual: 10.02 EUR
+/// //   expected: 10.00 EUR
+/// // difference: 0.02 EUR
+/// //  tolerance: 0.01 EUR
+/// assert_amount_eq!(eur!(10.02), eur!(10.00), tolerance = eur!(0.01));
+/// ```
+#[macro_export]
+macro_rules! assert_amount_eq {
+    ($actual:expr, $expected:expr, tolerance = $tolerance:expr $(,)?) => {
+        $crate::testing::assert_amount_eq($actual, $expected, Some($tolerance), None)
+    };
+    ($actual:expr, $expected:expr, tolerance = $tolerance:expr, $($arg:tt)+) => {
+        $crate::testing::assert_amount_eq(
+            $actual,
+            $expected,
+            Some($tolerance),
+            Some(format_args!($($arg)+)),
+        )
+    };
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::testing::assert_amount_eq($actual, $expected, None, None)
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        $crate::testing::assert_amount_eq($actual, $expected, None, Some(format_args!($($arg)+)))
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_amount_eq<C: CurrencyLike>(
+    actual: Amount<C>,
+    expected: Amount<C>,
+    tolerance: Option<Amount<C>>,
+    message: Option<Arguments>,
+) {
+    if let Some(tolerance) = tolerance {
+        if tolerance.currency() != expected.currency() {
+            panic!(
+                "tolerance in {} while the expected amount is in {}",
+                tolerance.currency().code(),
+                expected.currency().code()
+            );
+        }
+    }
+    let max = tolerance.map_or(Decimal::ZERO, |tolerance| tolerance.value());
+    if actual.approx_eq(&expected, Tolerance::Absolute(max)) {
+        return;
+    }
+    let mut report = String::from("assertion `actual == expected` failed");
+    if let Some(message) = message {
+        report += &format!(": {}", message);
+    }
+    report += &format!("\n    actual: {}", canonical(&actual));
+    report += &format!("\n  expected: {}", canonical(&expected));
+    if actual.currency() == expected.currency() {
+        let difference = Amount(actual.value() - expected.value(), actual.currency());
+        report += &format!("\ndifference: {}", canonical(&difference));
+    } else {
+        report += &format!(
+            "\ndifference: currencies differ ({} vs {})",
+            actual.currency().code(),
+            expected.currency().code()
+        );
+    }
+    if let Some(tolerance) = tolerance {
+        report += &format!("\n tolerance: {}", canonical(&tolerance));
+    }
+    panic!("{}", report);
+}
+
+fn canonical<C: CurrencyLike>(amount: &Amount<C>) -> String {
+    format!("{} {}", amount.value(), amount.currency().code())
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::Decimal;
+    use oxydized_money_macros::{eur, usd};
+    use std::panic::catch_unwind;
+
+    fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let payload = catch_unwind(f).unwrap_err();
+        payload.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn test_equal() {
+        assert_amount_eq!(eur!(10.50), eur!(10.5));
+        assert_amount_eq!(eur!(10.50), eur!(10.5),);
+        assert_amount_eq!(eur!(10.504), eur!(10.5), tolerance = eur!(0.01));
+        assert_amount_eq!(eur!(10.49), eur!(10.5), tolerance = eur!(0.01), "rounding");
+        assert_amount_eq!(usd!(1), usd!(1), "{} {}", "with", "arguments");
+    }
+
+    #[test]
+    fn test_not_equal() {
+        assert_eq!(
+            panic_message(|| assert_amount_eq!(eur!(10.02), eur!(10.00), tolerance = eur!(0.01))),
+            "assertion `actual == expected` failed\
+            \n    actual: 10.02 EUR\
+            \n  expected: 10.00 EUR\
+            \ndifference: 0.02 EUR\
+            \n tolerance: 0.01 EUR"
+        );
+        assert_eq!(
+            panic_message(|| assert_amount_eq!(eur!(-1), eur!(1), "balance of {}", "alice")),
+            "assertion `actual == expected` failed: balance of alice\
+            \n    actual: -1 EUR\
+            \n  expected: 1 EUR\
+            \ndifference: -2 EUR"
+        );
+        assert_eq!(
+            panic_message(|| assert_amount_eq!(usd!(1), eur!(1))),
+            "assertion `actual == expected` failed\
+            \n    actual: 1 USD\
+            \n  expected: 1 EUR\
+            \ndifference: currencies differ (USD vs EUR)"
+        );
+    }
+
+    #[test]
+    fn test_tolerance_currency() {
+        assert_eq!(
+            panic_message(|| assert_amount_eq!(eur!(1), eur!(1), tolerance = usd!(0.01))),
+            "tolerance in USD while the expected amount is in EUR"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::Decimal;
+
+/// `Tolerance` specifies how far apart two amounts can be while still
+/// being considered equal by [`Amount::approx_eq`](crate::Amount::approx_eq),
+/// typically when reconciling amounts after a currency conversion.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Tolerance, Decimal};
+/// use oxydized_money_macros::{eur, dec};
+///
+/// assert!(eur!(100.004).approx_eq(&eur!(100), Tolerance::Absolute(dec!(0.01))));
+/// assert!(eur!(100.04).approx_eq(&eur!(100), Tolerance::BasisPoints(dec!(5))));
+/// assert!(!eur!(100.06).approx_eq(&eur!(100), Tolerance::BasisPoints(dec!(5))));
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub enum Tolerance {
+    /// The amounts may differ by at most the provided value, expressed in
+    /// the currency of the amounts (e.g. `0.01` for "within one cent").
+    Absolute(Decimal),
+    /// The amounts may differ by at most the provided number of basis
+    /// points (hundredths of a percent) of the largest of the two amounts,
+    /// in absolute value. Differences too large to be expressed in basis
+    /// points within a [`Decimal`] are never within the tolerance.
+    BasisPoints(Decimal),
+}
+
+impl Tolerance {
+    /// Returns `true` if `left` and `right` are equal within the tolerance.
+    pub(crate) fn accepts(&self, left: Decimal, right: Decimal) -> bool {
+        let Some(difference) = left.checked_sub(right).map(|d| d.abs()) else {
+            return false;
+        };
+        match self {
+            Tolerance::Absolute(max) => difference <= max.abs(),
+            Tolerance::BasisPoints(bps) => {
+                let reference = left.abs().max(right.abs());
+                match (
+                    difference.checked_mul(Decimal::from(10_000)),
+                    reference.checked_mul(bps.abs()),
+                ) {
+                    (Some(difference), Some(max)) => difference <= max,
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{Amount, Currency::*, Decimal, Tolerance::*};
+    use oxydized_money_macros::{dec, eur, jpy, usd};
+
+    #[test]
+    fn test_absolute() {
+        assert!(eur!(1.01).approx_eq(&eur!(1), Absolute(dec!(0.01))));
+        assert!(eur!(0.99).approx_eq(&eur!(1), Absolute(dec!(0.01))));
+        assert!(eur!(1).approx_eq(&eur!(1), Absolute(dec!(0))));
+        assert!(!eur!(1.011).approx_eq(&eur!(1), Absolute(dec!(0.01))));
+        assert!(!eur!(1).approx_eq(&eur!(1.011), Absolute(dec!(0.01))));
+        assert!(eur!(1).approx_eq(&eur!(1.011), Absolute(dec!(-0.02))));
+        assert!(!usd!(1).approx_eq(&eur!(1), Absolute(dec!(1))));
+    }
+
+    #[test]
+    fn test_basis_points() {
+        assert!(jpy!(10_000).approx_eq(&jpy!(10_001), BasisPoints(dec!(1))));
+        assert!(jpy!(10_001).approx_eq(&jpy!(10_000), BasisPoints(dec!(1))));
+        assert!(!jpy!(10_000).approx_eq(&jpy!(10_002), BasisPoints(dec!(1))));
+        assert!(jpy!(-10_000).approx_eq(&jpy!(-10_002), BasisPoints(dec!(2))));
+        assert!(jpy!(0).approx_eq(&jpy!(0), BasisPoints(dec!(0))));
+        assert!(!jpy!(0).approx_eq(&jpy!(1), BasisPoints(dec!(1))));
+        assert!(!eur!(1).approx_eq(&usd!(1), BasisPoints(dec!(10_000))));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = Amount::max_in(EUR);
+        let min = Amount::min_in(EUR);
+        assert!(max.approx_eq(&max, BasisPoints(dec!(1))));
+        assert!(max.approx_eq(&(max - eur!(1)).unwrap(), BasisPoints(dec!(1))));
+        assert!(!max.approx_eq(&eur!(0), BasisPoints(dec!(1))));
+        assert!(!max.approx_eq(&min, BasisPoints(dec!(20_000))));
+        assert!(!max.approx_eq(&min, Absolute(Decimal::MAX)));
+    }
+}
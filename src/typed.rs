@@ -0,0 +1,226 @@
+use crate::{Amount, Currency, CurrencyError, CurrencyMarker, Decimal, Result, RoundingStrategy};
+use core::{fmt::Display, iter::Sum, marker::PhantomData};
+
+/// `TypedAmount` represents an amount of money in a currency known at
+/// compile time, represented by a [`CurrencyMarker`] type parameter.
+///
+/// Unlike [`Amount`], trying to add amounts of different currencies is a
+/// compile time error rather than a [`CurrencyError::Mismatch`], so that
+/// additions and subtractions of typed amounts cannot fail on currencies
+/// and output typed amounts rather than [`AmountResult`](crate::AmountResult)s.
+/// Typed amounts can be converted to and from [`Amount`]s without loss.
+///
+/// # Panics
+///
+/// As for `Amount * Decimal`, the additions, subtractions and
+/// multiplications of typed amounts, as well as their [`Sum`], panic if
+/// the result does not fit in a [`Decimal`]. Use
+/// [`TypedAmount::checked_add`], [`TypedAmount::checked_sub`] and
+/// [`TypedAmount::checked_mul`] instead to get a
+/// [`CurrencyError::Overflow`].
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Amount, TypedAmount, Decimal, markers::EUR};
+/// use oxydized_money_macros::{dec, eur, usd};
+///
+/// let price = TypedAmount::<EUR>::new(dec!(10));
+/// let total: TypedAmount<EUR> = price + price * dec!(2);
+/// assert_eq!(Amount::from(total), eur!(30));
+/// assert_eq!(TypedAmount::<EUR>::try_from(eur!(5)), Ok(TypedAmount::new(dec!(5))));
+/// assert!(TypedAmount::<EUR>::try_from(usd!(5)).is_err());
+/// ```
+///
+/// ```compile_fail
+/// use oxydized_money::{TypedAmount, Decimal, markers::{EUR, USD}};
+/// use oxydized_money_macros::dec;
+///
+/// let oops = TypedAmount::<EUR>::new(dec!(10)) + TypedAmount::<USD>::new(dec!(10));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TypedAmount<C: CurrencyMarker> {
+    pub(crate) value: Decimal,
+    currency: PhantomData<C>,
+}
+
+impl<C: CurrencyMarker> TypedAmount<C> {
+    /// Creates a new [`TypedAmount`] holding `value`.
+    pub const fn new(value: Decimal) -> Self {
+        Self {
+            value,
+            currency: PhantomData,
+        }
+    }
+
+    /// Returns the quantity of money.
+    pub const fn value(&self) -> Decimal {
+        self.value
+    }
+
+    /// Returns the currency in which [`value`](TypedAmount::value) is
+    /// measured.
+    pub const fn currency(&self) -> Currency {
+        C::CURRENCY
+    }
+
+    /// Returns the absolute value of `self`.
+    pub const fn abs(&self) -> Self {
+        Self::new(Amount::new(self.value, C::CURRENCY).abs().value())
+    }
+
+    /// Returns the sum of `self` and `rhs`, or a [`CurrencyError::Overflow`]
+    /// if it does not fit in a [`Decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxydized_money::{CurrencyError, TypedAmount, Decimal, markers::EUR};
+    /// use oxydized_money_macros::dec;
+    ///
+    /// let price = TypedAmount::<EUR>::new(dec!(10));
+    /// assert_eq!(price.checked_add(price), Ok(TypedAmount::new(dec!(20))));
+    /// let max = TypedAmount::<EUR>::new(Decimal::MAX);
+    /// assert_eq!(max.checked_add(price), Err(CurrencyError::Overflow));
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Result<Self> {
+        let value = self.value.checked_add(rhs.value);
+        Ok(Self::new(value.ok_or(CurrencyError::Overflow)?))
+    }
+
+    /// Returns the difference of `self` and `rhs`, or a
+    /// [`CurrencyError::Overflow`] if it does not fit in a [`Decimal`].
+    pub fn checked_sub(self, rhs: Self) -> Result<Self> {
+        let value = self.value.checked_sub(rhs.value);
+        Ok(Self::new(value.ok_or(CurrencyError::Overflow)?))
+    }
+
+    /// Returns `self` multiplied by `rhs`, or a [`CurrencyError::Overflow`]
+    /// if the product does not fit in a [`Decimal`].
+    pub fn checked_mul(self, rhs: Decimal) -> Result<Self> {
+        let value = self.value.checked_mul(rhs);
+        Ok(Self::new(value.ok_or(CurrencyError::Overflow)?))
+    }
+
+    /// Returns `self` rounded to the number of decimal places of its
+    /// currency using the provided rounding strategy (see [`Amount::rounded`]).
+    pub fn rounded(&self, strategy: RoundingStrategy) -> Self {
+        Self::new(Amount::from(*self).rounded(strategy).value())
+    }
+}
+
+impl<C: CurrencyMarker> Display for TypedAmount<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&Amount::from(*self), f)
+    }
+}
+
+impl<C: CurrencyMarker> From<TypedAmount<C>> for Amount {
+    fn from(amount: TypedAmount<C>) -> Self {
+        Amount(amount.value(), amount.currency())
+    }
+}
+
+impl<C: CurrencyMarker> TryFrom<Amount> for TypedAmount<C> {
+    type Error = CurrencyError;
+
+    fn try_from(amount: Amount) -> Result<Self> {
+        if amount.currency() == C::CURRENCY {
+            Ok(Self::new(amount.value()))
+        } else {
+            Err(CurrencyError::Mismatch(C::CURRENCY, amount.currency()))
+        }
+    }
+}
+
+impl<C: CurrencyMarker> Sum<TypedAmount<C>> for TypedAmount<C> {
+    fn sum<I: Iterator<Item = TypedAmount<C>>>(iter: I) -> Self {
+        iter.fold(Self::default(), |a, b| a + b)
+    }
+}
+
+impl<'a, C: CurrencyMarker> Sum<&'a TypedAmount<C>> for TypedAmount<C> {
+    fn sum<I: Iterator<Item = &'a TypedAmount<C>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use oxydized_money::{
+        markers, Amount, Currency::*, CurrencyError::*, Decimal, RoundingStrategy, TypedAmount,
+    };
+    use oxydized_money_macros::{dec, eur, jpy, usd};
+
+    #[test]
+    fn test_conversions() {
+        let amount = TypedAmount::<markers::EUR>::new(dec!(1.5));
+        assert_eq!(amount.value(), dec!(1.5));
+        assert_eq!(amount.currency(), EUR);
+        assert_eq!(Amount::from(amount), eur!(1.5));
+        assert_eq!(TypedAmount::try_from(eur!(1.5)), Ok(amount));
+        assert_eq!(
+            TypedAmount::<markers::EUR>::try_from(usd!(1.5)),
+            Err(Mismatch(EUR, USD))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", TypedAmount::<markers::EUR>::new(dec!(2))),
+            "€ 2.00"
+        );
+        assert_eq!(
+            format!("{:.0}", TypedAmount::<markers::JPY>::new(dec!(2))),
+            "¥ 2"
+        );
+    }
+
+    #[test]
+    fn test_abs_rounded() {
+        let amount = TypedAmount::<markers::JPY>::new(dec!(-10.5));
+        assert_eq!(amount.abs(), TypedAmount::new(dec!(10.5)));
+        const LIMIT: TypedAmount<markers::JPY> = TypedAmount::new(Decimal::NEGATIVE_ONE).abs();
+        assert_eq!(LIMIT.value(), Decimal::ONE);
+        assert_eq!(
+            Amount::from(amount.rounded(RoundingStrategy::ToZero)),
+            jpy!(-10)
+        );
+    }
+
+    #[test]
+    fn test_sum() {
+        let amounts = [dec!(1), dec!(2.5), dec!(-0.5)].map(TypedAmount::<markers::EUR>::new);
+        assert_eq!(
+            amounts.iter().sum::<TypedAmount<markers::EUR>>(),
+            TypedAmount::new(dec!(3))
+        );
+        assert_eq!(
+            amounts[..0].iter().sum::<TypedAmount<markers::EUR>>(),
+            TypedAmount::new(dec!(0))
+        );
+        assert!(TypedAmount::<markers::EUR>::new(dec!(1)) < TypedAmount::new(dec!(2)));
+    }
+
+    #[test]
+    fn test_checked() {
+        let amount = TypedAmount::<markers::EUR>::new(dec!(1.5));
+        let max = TypedAmount::<markers::EUR>::new(Decimal::MAX);
+        assert_eq!(amount.checked_add(amount), Ok(TypedAmount::new(dec!(3))));
+        assert_eq!(amount.checked_sub(amount), Ok(TypedAmount::new(dec!(0))));
+        assert_eq!(amount.checked_mul(dec!(-2)), Ok(TypedAmount::new(dec!(-3))));
+        assert_eq!(max.checked_add(amount), Err(Overflow));
+        assert_eq!((-max).checked_sub(amount), Err(Overflow));
+        assert_eq!(max.checked_mul(dec!(2)), Err(Overflow));
+        assert_eq!(max.checked_sub(amount), Ok(max - amount));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_overflow() {
+        let max = TypedAmount::<markers::EUR>::new(Decimal::MAX);
+        let _ = max + max;
+    }
+}
@@ -0,0 +1,59 @@
+use crate::Amount;
+use ::utoipa::{
+    openapi::{
+        schema::{ObjectBuilder, Schema, Type},
+        RefOr,
+    },
+    PartialSchema, ToSchema,
+};
+use alloc::borrow::Cow;
+
+/// With the `utoipa` feature, amounts document their default serde format
+/// in OpenAPI specifications, e.g. `{"value":"10.50","currency":"EUR"}`.
+impl PartialSchema for Amount {
+    fn schema() -> RefOr<Schema> {
+        let value = ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("quantity of money, as a decimal number"))
+            .pattern(Some(r"^-?[0-9]+(\.[0-9]+)?$"))
+            .build();
+        let currency = ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("ISO 4217 currency code"))
+            .pattern(Some("^[A-Z]{3}$"))
+            .build();
+        ObjectBuilder::new()
+            .description(Some("amount of money in a specific currency"))
+            .property("value", value)
+            .required("value")
+            .property("currency", currency)
+            .required("currency")
+            .build()
+            .into()
+    }
+}
+
+impl ToSchema for Amount {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("Amount")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use ::utoipa::openapi::{schema::Schema, RefOr};
+    use ::utoipa::{PartialSchema, ToSchema};
+    use oxydized_money::Amount;
+
+    #[test]
+    fn test_schema() {
+        assert_eq!(Amount::name(), "Amount");
+        let RefOr::T(Schema::Object(schema)) = Amount::schema() else {
+            panic!("amount schema should be an object");
+        };
+        assert_eq!(schema.required, ["value", "currency"]);
+        assert!(schema.properties.contains_key("value"));
+        assert!(schema.properties.contains_key("currency"));
+    }
+}
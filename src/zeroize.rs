@@ -0,0 +1,131 @@
+#[cfg(feature = "std")]
+use crate::MoneyBag;
+use crate::{Amount, CurrencyLike, CurrencyMarker, Decimal, TypedAmount};
+use ::zeroize::Zeroize;
+#[cfg(feature = "std")]
+use ::zeroize::ZeroizeOnDrop;
+use core::sync::atomic::{compiler_fence, Ordering};
+
+/// Overwrites `value` with zero, in a way that is not optimized away, as
+/// [`Decimal`] does not implement [`Zeroize`] itself.
+fn zeroize_decimal(value: &mut Decimal) {
+    // SAFETY: `value` is a valid and aligned exclusive reference.
+    unsafe { core::ptr::write_volatile(value, Decimal::ZERO) };
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Zeroizes the value of the amount, with the `zeroize` feature. The
+/// currency is left untouched, as it is not sensitive on its own.
+///
+/// Amounts are [`Copy`], so that they cannot be zeroized when dropped: wrap
+/// them in a [`Zeroizing`](::zeroize::Zeroizing) instead.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Currency::EUR, Decimal};
+/// use oxydized_money_macros::eur;
+/// use zeroize::{Zeroize, Zeroizing};
+///
+/// let mut balance = eur!(1234.56);
+/// balance.zeroize();
+/// assert_eq!(balance, eur!(0));
+///
+/// let balance = Zeroizing::new(eur!(1234.56));
+/// assert_eq!(balance.currency(), EUR);
+/// ```
+impl<C: CurrencyLike> Zeroize for Amount<C> {
+    fn zeroize(&mut self) {
+        zeroize_decimal(&mut self.0);
+    }
+}
+
+/// Zeroizes the value of the typed amount, with the `zeroize` feature.
+impl<C: CurrencyMarker> Zeroize for TypedAmount<C> {
+    fn zeroize(&mut self) {
+        zeroize_decimal(&mut self.value);
+    }
+}
+
+/// Zeroizes the totals of the bag and empties it, with the `zeroize`
+/// feature. With this feature, bags are also zeroized when dropped.
+///
+/// Only the totals held by the bag are zeroized: the totals of the
+/// currencies removed from the bag beforehand (e.g. because they summed to
+/// zero) may remain in the memory of the underlying map.
+///
+/// # Examples
+///
+/// ```
+/// use oxydized_money::{Decimal, MoneyBag};
+/// use oxydized_money_macros::{eur, usd};
+/// use zeroize::Zeroize;
+///
+/// let mut bag = MoneyBag::from_iter([eur!(10), usd!(5)]);
+/// bag.zeroize();
+/// assert!(bag.is_empty());
+/// ```
+#[cfg(feature = "std")]
+impl Zeroize for MoneyBag {
+    fn zeroize(&mut self) {
+        self.totals.values_mut().for_each(zeroize_decimal);
+        self.totals.clear();
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for MoneyBag {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "std")]
+impl ZeroizeOnDrop for MoneyBag {}
+
+#[cfg(test)]
+mod test {
+    use crate as oxydized_money;
+    use ::zeroize::{Zeroize, Zeroizing};
+    use oxydized_money::{markers, Currency::*, Decimal, TypedAmount};
+    use oxydized_money_macros::{dec, eur, jpy, usd};
+
+    #[test]
+    fn test_amount() {
+        let mut amount = usd!(-1234.5678);
+        amount.zeroize();
+        assert_eq!(amount.value(), Decimal::ZERO);
+        assert_eq!(amount.value().scale(), 0);
+        assert_eq!(amount.currency(), USD);
+
+        let mut amounts = vec![eur!(1), jpy!(2)];
+        amounts.zeroize();
+        assert!(amounts.is_empty());
+
+        let amount = Zeroizing::new(eur!(3));
+        assert_eq!(*amount, eur!(3));
+    }
+
+    #[test]
+    fn test_typed_amount() {
+        let mut amount = TypedAmount::<markers::EUR>::new(dec!(10.5));
+        amount.zeroize();
+        assert_eq!(amount, TypedAmount::new(Decimal::ZERO));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_money_bag() {
+        use oxydized_money::MoneyBag;
+
+        let mut bag = MoneyBag::from_iter([eur!(10), usd!(5), eur!(2)]);
+        let mut other = bag.clone();
+        other.zeroize();
+        assert!(other.is_empty());
+        assert_eq!(bag.get(EUR), eur!(12));
+
+        bag += MoneyBag::from(usd!(1));
+        bag -= MoneyBag::from(eur!(12));
+        assert_eq!(bag, MoneyBag::from(usd!(6)));
+    }
+}